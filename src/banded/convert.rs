@@ -0,0 +1,61 @@
+impl<T> From<super::Tridiagonal<T>> for super::Banded<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Tridiagonal<T>) -> Self {
+        let n = m.diagonal.len();
+        let mut rv = Self::new(n, 1, 1);
+
+        (0..n).for_each(|j| {
+            rv.band[j * 3 + 1] = m.diagonal[j];
+            if j > 0 {
+                rv.band[j * 3] = m.lower[j - 1];
+            }
+            if j + 1 < n {
+                rv.band[j * 3 + 2] = m.upper[j];
+            }
+        });
+
+        rv
+    }
+}
+
+impl<T> From<super::Tridiagonal<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Tridiagonal<T>) -> Self {
+        let n = m.diagonal.len();
+        let mut dense = vec![T::zero(); n * n];
+
+        (0..n).for_each(|j| {
+            dense[j * n + j] = m.diagonal[j];
+            if j > 0 {
+                dense[j * n + j - 1] = m.lower[j - 1];
+            }
+            if j + 1 < n {
+                dense[j * n + j + 1] = m.upper[j];
+            }
+        });
+
+        Self::from_vec(dense, [n, n])
+    }
+}
+
+impl<T> From<super::Banded<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Banded<T>) -> Self {
+        let n = m.n;
+        let mut dense = vec![T::zero(); n * n];
+
+        (0..n).for_each(|j| {
+            m.band_columns(j).for_each(|i| {
+                dense[j * n + i] = m.band[j * (m.lower + m.upper + 1) + i + m.lower - j]
+            })
+        });
+
+        Self::from_vec(dense, [n, n])
+    }
+}
@@ -0,0 +1,121 @@
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Tridiagonal<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        let n = self.diagonal.len();
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of banded matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if n != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of banded matrix and vertex"),
+            ));
+        }
+
+        let rv: Vec<T> = crate::parallel::map_collect(n, |j| {
+            let mut rv_j = self.diagonal[j] * other[j];
+
+            if j > 0 {
+                rv_j += self.lower[j - 1] * other[j - 1];
+            }
+            if j + 1 < n {
+                rv_j += self.upper[j] * other[j + 1];
+            }
+
+            rv_j
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Banded<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of banded matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.n != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of banded matrix and vertex"),
+            ));
+        }
+
+        let width = self.lower + self.upper + 1;
+        let rv: Vec<T> = crate::parallel::map_collect(self.n, |j| {
+            self.band_columns(j)
+                .map(|i| self.band[j * width + i + self.lower - j] * other[i])
+                .sum()
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl crate::linear::LinearOperator for super::Tridiagonal<f64> {
+    fn rows(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    fn columns(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        let n = self.diagonal.len();
+
+        crate::parallel::for_each_mut(y, |j, y_j| {
+            *y_j = self.diagonal[j] * x[j];
+            if j > 0 {
+                *y_j += self.lower[j - 1] * x[j - 1];
+            }
+            if j + 1 < n {
+                *y_j += self.upper[j] * x[j + 1];
+            }
+        });
+    }
+}
+
+impl crate::linear::LinearOperator for super::Banded<f64> {
+    fn rows(&self) -> usize {
+        self.n
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        let width = self.lower + self.upper + 1;
+
+        crate::parallel::for_each_mut(y, |j, y_j| {
+            *y_j = self
+                .band_columns(j)
+                .map(|i| self.band[j * width + i + self.lower - j] * x[i])
+                .sum();
+        });
+    }
+}
@@ -0,0 +1,69 @@
+use super::{Banded, Tridiagonal};
+use crate::error::SlalError;
+use crate::linear::Dot;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+fn pentadiagonal() -> Banded<i32> {
+    let mut m = Banded::new(5, 2, 1);
+
+    (0..5).for_each(|j| {
+        (0..5).for_each(|i| {
+            let _ = m.set(j, i, (3 * j) as i32 - i as i32 + 1);
+        })
+    });
+
+    m
+}
+
+#[test]
+fn dot_vertex_tridiagonal() {
+    let m = Tridiagonal::new(&[1, 3], &[2, 4, 6], &[5, 7]).unwrap();
+    let v = Vertex::new_transposed(&[1, -1, 2]);
+
+    assert_eq!(m.dot(&v), Ok(Vertex::new_transposed(&[-3, 11, 9])));
+}
+
+#[test]
+fn dot_vertex_banded() {
+    let m = pentadiagonal();
+    let v = Vertex::new_transposed(&[1, 2, 3, 4, 5]);
+    let expected = Matrix::from(m.clone()).dot(&v).unwrap();
+
+    assert_eq!(m.dot(&v), Ok(expected));
+}
+
+#[test]
+fn dot_vertex_not_transposed() {
+    let m = Tridiagonal::new(&[1., 3.], &[2., 4., 6.], &[5., 7.]).unwrap();
+    let v = Vertex::new(&[1., -1., 2.]);
+
+    assert!(matches!(m.dot(&v), Err(SlalError::VertexStateError(_))));
+}
+
+#[test]
+fn dot_vertex_length_not_match() {
+    let m = pentadiagonal();
+    let v = Vertex::new_transposed(&[1, 2, 3, 4]);
+
+    assert!(matches!(
+        m.dot(&v),
+        Err(SlalError::VertexLengthAndMatrixWidthNotMatch(_, _, _))
+    ));
+}
+
+#[test]
+fn cg_with_tridiagonal() {
+    use crate::iterative::{cg, Config};
+
+    // 1D Poisson matrix
+    let n = 20;
+    let a = Tridiagonal::new(&vec![-1.; n - 1], &vec![2.; n], &vec![-1.; n - 1]).unwrap();
+    let b = Vertex::new_transposed(&vec![1.; n]);
+
+    let report = cg(&a, &b, &Config::default(), None).unwrap();
+    let r = a.dot(report.x()).unwrap();
+
+    assert!(report.converged());
+    assert!((0..n).all(|i| (r[i] - 1.).abs() < 1e-8));
+}
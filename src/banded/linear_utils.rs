@@ -0,0 +1,199 @@
+/**
+    Solves a tridiagonal system in place through the Thomas algorithm.
+    Returns `None` when a pivot is negligible relative to the largest entry,
+    as the algorithm does not pivot.
+*/
+fn thomas(lower: &[f64], diagonal: &[f64], upper: &[f64], b: &mut [f64]) -> Option<()> {
+    let n = diagonal.len();
+    let mut c = vec![0.; n];
+    let tol = crate::matrix::pivot_tolerance(
+        n,
+        lower
+            .iter()
+            .chain(diagonal)
+            .chain(upper)
+            .fold(0., |max: f64, a| max.max(a.abs())),
+    );
+
+    for j in 0..n {
+        let (l, c_prev, b_prev) = if j > 0 {
+            (lower[j - 1], c[j - 1], b[j - 1])
+        } else {
+            (0., 0., 0.)
+        };
+        let pivot = diagonal[j] - l * c_prev;
+
+        if pivot.abs() <= tol || !pivot.is_finite() {
+            return None;
+        }
+        if j + 1 < n {
+            c[j] = upper[j] / pivot;
+        }
+        b[j] = (b[j] - l * b_prev) / pivot;
+    }
+
+    for j in (0..n.saturating_sub(1)).rev() {
+        b[j] -= c[j] * b[j + 1];
+    }
+
+    Some(())
+}
+
+/**
+    Solves an n x n banded system in place through LU decomposition with partial pivoting,
+    where `entry(row, column)` returns the elements inside of the band.
+    Returns `None` when the matrix is singular, that is a pivot is negligible relative to the largest entry.
+*/
+fn banded_lu<F>(n: usize, lower: usize, upper: usize, entry: F, b: &mut [f64]) -> Option<()>
+where
+    F: Fn(usize, usize) -> f64,
+{
+    // Row swaps widen the upper bandwidth of U up to lower + upper
+    let width = 2 * lower + upper + 1;
+    let index = |j: usize, i: usize| j * width + i + lower - j;
+    let mut a = vec![0.; n * width];
+
+    (0..n).for_each(|j| {
+        (j.saturating_sub(lower)..(j + upper + 1).min(n)).for_each(|i| a[index(j, i)] = entry(j, i))
+    });
+
+    let tol = crate::matrix::pivot_tolerance(n, a.iter().fold(0., |max: f64, a| max.max(a.abs())));
+
+    for k in 0..n {
+        let last = (k + lower).min(n - 1);
+        let end = (k + lower + upper + 1).min(n);
+        let p = (k..=last).fold(k, |p, j| {
+            if a[index(j, k)].abs() > a[index(p, k)].abs() {
+                j
+            } else {
+                p
+            }
+        });
+
+        if a[index(p, k)].abs() <= tol {
+            return None;
+        }
+        if p != k {
+            (k..end).for_each(|i| a.swap(index(k, i), index(p, i)));
+            b.swap(k, p);
+        }
+        for j in (k + 1)..=last {
+            let l = a[index(j, k)] / a[index(k, k)];
+
+            if l == 0. {
+                continue;
+            }
+            a[index(j, k)] = 0.;
+            for i in (k + 1)..end {
+                a[index(j, i)] -= l * a[index(k, i)];
+            }
+            b[j] -= l * b[k];
+        }
+    }
+
+    for k in (0..n).rev() {
+        let end = (k + lower + upper + 1).min(n);
+        let s: f64 = ((k + 1)..end).map(|i| a[index(k, i)] * b[i]).sum();
+
+        b[k] = (b[k] - s) / a[index(k, k)];
+    }
+
+    Some(())
+}
+
+impl<T> crate::linear::Solve<crate::vertex::Vertex<T>> for super::Tridiagonal<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+
+    /**
+    Solves the linear system through the Thomas algorithm in O(n),
+    falling back to banded LU decomposition with partial pivoting when a pivot is negligible
+     */
+    fn solve(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        let n = self.diagonal.len();
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != n {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving linear system"),
+            ));
+        }
+
+        let lower: Vec<f64> = self.lower.iter().map(|v| v.to_f64()).collect();
+        let diagonal: Vec<f64> = self.diagonal.iter().map(|v| v.to_f64()).collect();
+        let upper: Vec<f64> = self.upper.iter().map(|v| v.to_f64()).collect();
+        let mut x = b.to_f64().to_vec();
+
+        if thomas(&lower, &diagonal, &upper, &mut x).is_none() {
+            x = b.to_f64().to_vec();
+
+            let entry = |j: usize, i: usize| {
+                if i == j {
+                    diagonal[j]
+                } else if i < j {
+                    lower[i]
+                } else {
+                    upper[j]
+                }
+            };
+
+            if banded_lu(n, 1, 1, entry, &mut x).is_none() {
+                return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+            }
+        }
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Solve<crate::vertex::Vertex<T>> for super::Banded<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+
+    /**
+    Solves the linear system through banded LU decomposition with partial pivoting
+    in O(n * lower * (lower + upper))
+     */
+    fn solve(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != self.n {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving linear system"),
+            ));
+        }
+
+        let width = self.lower + self.upper + 1;
+        let entry = |j: usize, i: usize| self.band[j * width + i + self.lower - j].to_f64();
+        let mut x = b.to_f64().to_vec();
+
+        if banded_lu(self.n, self.lower, self.upper, entry, &mut x).is_none() {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
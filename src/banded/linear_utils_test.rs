@@ -0,0 +1,117 @@
+use super::{Banded, Tridiagonal};
+use crate::error::SlalError;
+use crate::linear::{Dot, Solve};
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+fn residual(a: &Matrix<f64>, x: &Vertex<f64>, b: &Vertex<f64>) -> f64 {
+    let r = a.dot(x).unwrap();
+
+    (0..b.len()).map(|i| (r[i] - b[i]).abs()).fold(0., f64::max)
+}
+
+#[test]
+fn solve_tridiagonal() {
+    // 2x - y = 1, -x + 2y - z = 0, -y + 2z = 1
+    let a = Tridiagonal::<i32>::new(&[-1, -1], &[2, 2, 2], &[-1, -1]).unwrap();
+    let b = Vertex::new_transposed(&[1, 0, 1]);
+    let x: Vertex<f64> = a.solve(&b).unwrap();
+
+    assert!((0..3).all(|i| (x[i] - 1.).abs() < 1e-12));
+}
+
+#[test]
+fn solve_tridiagonal_zero_pivot() {
+    // Thomas algorithm breaks down on the first pivot and needs row interchanges
+    let a = Tridiagonal::<f64>::new(&[1., 1., 1.], &[0., 0., 2., 1.], &[1., 1., 1.]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2., 3., 4.]);
+    let x: Vertex<f64> = a.solve(&b).unwrap();
+
+    assert!(residual(&Matrix::from(a), &x, &b) < 1e-12);
+}
+
+#[test]
+fn solve_tridiagonal_singular() {
+    let a = Tridiagonal::<i32>::new(&[1, 0], &[1, 1, 1], &[1, 0]).unwrap();
+    let b = Vertex::new_transposed(&[1, 2, 3]);
+
+    assert!(matches!(a.solve(&b), Err(SlalError::SingularMatrix(_))));
+}
+
+#[test]
+fn solve_tridiagonal_nearly_singular() {
+    // The second pivot is 1 + EPSILON - 1, which is rounding noise relative to the entries
+    let a = Tridiagonal::new(&[1.], &[1., 1. + f64::EPSILON], &[1.]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+
+    assert!(matches!(a.solve(&b), Err(SlalError::SingularMatrix(_))));
+}
+
+#[test]
+fn solve_tridiagonal_large() {
+    let n = 1000;
+    let a = Tridiagonal::new(&vec![1.; n - 1], &vec![-4.; n], &vec![2.; n - 1]).unwrap();
+    let b = Vertex::new_transposed(&(0..n).map(|i| i as f64).collect::<Vec<f64>>());
+    let x: Vertex<f64> = a.solve(&b).unwrap();
+    let r = a.dot(&x).unwrap();
+
+    assert!((0..n).all(|i| (r[i] - b[i]).abs() < 1e-9));
+}
+
+#[test]
+fn solve_banded() {
+    let mut a = Banded::new(6, 2, 1);
+
+    (0..6).for_each(|j| {
+        (0..6).for_each(|i| {
+            let _ = a.set(j, i, ((7 * j + 3 * i) % 5) as f64 - 2.);
+        })
+    });
+
+    let b = Vertex::new_transposed(&[1., -2., 3., 0., 5., 1.]);
+    let x: Vertex<f64> = a.solve(&b).unwrap();
+    let dense = Matrix::from(a);
+
+    assert!(residual(&dense, &x, &b) < 1e-10);
+    assert!((0..6).all(|i| (x[i] - dense.solve(&b).unwrap()[i]).abs() < 1e-10));
+}
+
+#[test]
+fn solve_banded_singular() {
+    let mut a = Banded::<i32>::new(3, 1, 1);
+
+    a.set(0, 0, 1).unwrap();
+    a.set(0, 1, 1).unwrap();
+    a.set(1, 0, 1).unwrap();
+    a.set(1, 1, 1).unwrap();
+    a.set(2, 2, 1).unwrap();
+
+    let b = Vertex::new_transposed(&[1, 2, 3]);
+
+    assert!(matches!(a.solve(&b), Err(SlalError::SingularMatrix(_))));
+}
+
+#[test]
+fn solve_banded_nearly_singular() {
+    let mut a = Banded::new(3, 1, 1);
+
+    a.set(0, 0, 1e-20).unwrap();
+    a.set(0, 1, 1e-20).unwrap();
+    a.set(1, 0, 1e-20).unwrap();
+    a.set(1, 1, 1e-20 * (1. + f64::EPSILON)).unwrap();
+    a.set(2, 2, 1e-20).unwrap();
+
+    let b = Vertex::new_transposed(&[1., 2., 3.]);
+
+    assert!(matches!(a.solve(&b), Err(SlalError::SingularMatrix(_))));
+}
+
+#[test]
+fn solve_not_transposed() {
+    let a = Banded::<f64>::new(2, 0, 0);
+
+    assert!(matches!(
+        a.solve(&Vertex::new(&[1., 2.])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
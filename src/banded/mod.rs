@@ -0,0 +1,357 @@
+mod convert;
+mod linear_arithmatic;
+mod linear_utils;
+
+#[cfg(test)]
+mod linear_arithmatic_test;
+#[cfg(test)]
+mod linear_utils_test;
+
+pub use crate::linear::{Dot, Solve};
+
+/**
+    Square tridiagonal matrix.
+
+    Holds the subdiagonal, the diagonal and the superdiagonal of an n x n matrix,
+    where the subdiagonal and the superdiagonal have n - 1 elements.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<T> {
+    lower: Vec<T>,
+    diagonal: Vec<T>,
+    upper: Vec<T>,
+}
+
+/**
+    Square banded matrix.
+
+    Holds every row of an n x n matrix from `lower` elements left of the diagonal
+    to `upper` elements right of the diagonal, so that element (row, column) is stored
+    only when `row - lower <= column <= row + upper`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Banded<T> {
+    band: Vec<T>,
+    n: usize,
+    lower: usize,
+    upper: usize,
+}
+
+impl<T> Tridiagonal<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new tridiagonal matrix from its subdiagonal, diagonal and superdiagonal
+
+    # Example
+    ```
+    use slal::banded::Tridiagonal;
+
+    //  | 2 -1  0 |
+    //  | 1  2 -1 |
+    //  | 0  1  2 |
+    let m = Tridiagonal::new(&[1, 1], &[2, 2, 2], &[-1, -1]).unwrap();
+
+    assert!(m.size() == (3, 3));
+    assert!(Tridiagonal::new(&[1], &[2, 2, 2], &[-1, -1]).is_err());
+    ```
+     */
+    pub fn new(lower: &[T], diagonal: &[T], upper: &[T]) -> crate::error::SlalErr<Self, T> {
+        use crate::error::SlalError;
+
+        if diagonal.is_empty() {
+            return Err(SlalError::EmptyMatrix(String::from(
+                "Cannot create tridiagonal matrix with empty diagonal",
+            )));
+        } else if lower.len() + 1 != diagonal.len() || upper.len() + 1 != diagonal.len() {
+            return Err(SlalError::MatrixInitializationError(format!(
+                "Subdiagonal and superdiagonal must have {} elements but have {} and {} elements",
+                diagonal.len() - 1,
+                lower.len(),
+                upper.len(),
+            )));
+        }
+
+        Ok(Self {
+            lower: lower.to_vec(),
+            diagonal: diagonal.to_vec(),
+            upper: upper.to_vec(),
+        })
+    }
+
+    /**
+    Returns the size of tridiagonal matrix
+
+    # Example
+    ```
+    use slal::banded::Tridiagonal;
+
+    let m = Tridiagonal::new(&[1., 1.], &[2., 2., 2.], &[1., 1.]).unwrap();
+    let (m_hor, m_vert) = m.size();     // returns (3, 3)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.diagonal.len(), self.diagonal.len())
+    }
+
+    /**
+    Returns the subdiagonal, where `lower()[j]` is the element at (j + 1, j)
+
+    # Example
+    ```
+    use slal::banded::Tridiagonal;
+
+    let m = Tridiagonal::new(&[1, 3], &[2, 4, 6], &[5, 7]).unwrap();
+
+    assert!(m.lower() == &[1, 3]);
+    ```
+     */
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /**
+    Returns the diagonal
+
+    # Example
+    ```
+    use slal::banded::Tridiagonal;
+
+    let m = Tridiagonal::new(&[1, 3], &[2, 4, 6], &[5, 7]).unwrap();
+
+    assert!(m.diagonal() == &[2, 4, 6]);
+    ```
+     */
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    /**
+    Returns the superdiagonal, where `upper()[j]` is the element at (j, j + 1)
+
+    # Example
+    ```
+    use slal::banded::Tridiagonal;
+
+    let m = Tridiagonal::new(&[1, 3], &[2, 4, 6], &[5, 7]).unwrap();
+
+    assert!(m.upper() == &[5, 7]);
+    ```
+     */
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+}
+
+impl<T> Banded<T>
+where
+    T: crate::scalar::Scalar,
+{
+    /**
+    Creates new n x n banded matrix of zeros with `lower` subdiagonals and `upper` superdiagonals.
+    Bandwidths wider than the matrix are narrowed to n - 1.
+
+    # Example
+    ```
+    use slal::banded::Banded;
+
+    // pentadiagonal 5x5 matrix
+    let m = Banded::<f64>::new(5, 2, 2);
+
+    assert!(m.size() == (5, 5) && m.bandwidth() == (2, 2));
+    ```
+     */
+    pub fn new(n: usize, lower: usize, upper: usize) -> Self {
+        let (lower, upper) = (
+            lower.min(n.saturating_sub(1)),
+            upper.min(n.saturating_sub(1)),
+        );
+
+        Self {
+            band: vec![T::zero(); n * (lower + upper + 1)],
+            n,
+            lower,
+            upper,
+        }
+    }
+
+    /**
+    Returns the size of banded matrix
+
+    # Example
+    ```
+    use slal::banded::Banded;
+
+    let m = Banded::<i32>::new(4, 1, 2);
+    let (m_hor, m_vert) = m.size();     // returns (4, 4)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /**
+    Returns the number of subdiagonals and superdiagonals
+
+    # Example
+    ```
+    use slal::banded::Banded;
+
+    let m = Banded::<i32>::new(4, 1, 2);
+
+    assert!(m.bandwidth() == (1, 2));
+    ```
+     */
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /**
+    Returns the element at (row, column), or `None` if it is outside of the band
+
+    # Example
+    ```
+    use slal::banded::Banded;
+
+    let m = Banded::<i32>::new(4, 1, 0);
+
+    assert!(m.get(1, 0) == Some(&0));
+    assert!(m.get(0, 1) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        self.position(row, column).map(|k| &self.band[k])
+    }
+
+    /**
+    Overwrites the element at (row, column) inside of the band
+
+    # Example
+    ```
+    use slal::banded::Banded;
+
+    let mut m = Banded::new(3, 1, 1);
+
+    m.set(2, 1, 4).unwrap();
+
+    assert!(m.get(2, 1) == Some(&4));
+    assert!(m.set(2, 0, 1).is_err());
+    ```
+     */
+    pub fn set(&mut self, row: usize, column: usize, value: T) -> crate::error::SlalErr<(), T> {
+        use crate::error::SlalError;
+
+        match self.position(row, column) {
+            Some(k) => {
+                self.band[k] = value;
+
+                Ok(())
+            }
+            None => Err(SlalError::IndexOutOfBounds(
+                format!("({}, {})", row, column),
+                format!(
+                    "({}, {}) with bandwidth ({}, {})",
+                    self.n, self.n, self.lower, self.upper
+                ),
+            )),
+        }
+    }
+
+    /**
+    Index of element at (row, column) in the band storage
+    */
+    fn position(&self, row: usize, column: usize) -> Option<usize> {
+        if row >= self.n
+            || column >= self.n
+            || column + self.lower < row
+            || column > row + self.upper
+        {
+            return None;
+        }
+
+        Some(row * (self.lower + self.upper + 1) + column + self.lower - row)
+    }
+
+    /**
+    Range of columns stored in row `j` inside of the matrix
+    */
+    fn band_columns(&self, j: usize) -> std::ops::Range<usize> {
+        j.saturating_sub(self.lower)..(j + self.upper + 1).min(self.n)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Banded, Tridiagonal};
+    use crate::error::SlalError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn tridiagonal_length_not_match() {
+        assert!(matches!(
+            Tridiagonal::new(&[1, 2], &[3, 4, 5], &[6]),
+            Err(SlalError::MatrixInitializationError(_))
+        ));
+        assert!(matches!(
+            Tridiagonal::<i32>::new(&[], &[], &[]),
+            Err(SlalError::EmptyMatrix(_))
+        ));
+    }
+
+    #[test]
+    fn tridiagonal_to_dense() {
+        let m = Tridiagonal::new(&[1, 3], &[2, 4, 6], &[5, 7]).unwrap();
+
+        assert_eq!(
+            Matrix::from(m),
+            Matrix::new(&[&[2, 5, 0], &[1, 4, 7], &[0, 3, 6]]).unwrap()
+        );
+    }
+
+    #[test]
+    fn banded_to_dense() {
+        let mut m = Banded::new(4, 2, 1);
+
+        (0..4).for_each(|j| {
+            (0..4).for_each(|i| {
+                let _ = m.set(j, i, (4 * j + i + 1) as i64);
+            })
+        });
+
+        assert_eq!(
+            Matrix::from(m),
+            Matrix::new(&[
+                &[1, 2, 0, 0],
+                &[5, 6, 7, 0],
+                &[9, 10, 11, 12],
+                &[0, 14, 15, 16]
+            ])
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn banded_from_tridiagonal() {
+        let t = Tridiagonal::new(&[1., 3.], &[2., 4., 6.], &[5., 7.]).unwrap();
+        let b = Banded::from(t.clone());
+
+        assert_eq!(b.bandwidth(), (1, 1));
+        assert_eq!(Matrix::from(b), Matrix::from(t));
+    }
+
+    #[test]
+    fn banded_narrowed() {
+        let m = Banded::<u8>::new(3, 5, 0);
+
+        assert_eq!(m.bandwidth(), (2, 0));
+        assert_eq!(m.get(2, 0), Some(&0));
+        assert_eq!(
+            Banded::<u8>::new(3, 1, 1).set(0, 3, 1),
+            Err(SlalError::IndexOutOfBounds(
+                String::from("(0, 3)"),
+                String::from("(3, 3) with bandwidth (1, 1)")
+            ))
+        );
+    }
+}
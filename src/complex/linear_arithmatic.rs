@@ -0,0 +1,23 @@
+// Vertices and matrices of complex numbers share the generic arithmatic over `Scalar`,
+// only multiplication with the scalar on the left hand side is implemented here
+impl<T> std::ops::Mul<crate::vertex::Vertex<super::Complex<T>>> for super::Complex<T>
+where
+    super::Complex<T>: crate::scalar::Scalar,
+{
+    type Output = crate::vertex::Vertex<super::Complex<T>>;
+
+    fn mul(self, other: crate::vertex::Vertex<super::Complex<T>>) -> Self::Output {
+        other * self
+    }
+}
+
+impl<T> std::ops::Mul<crate::matrix::Matrix<super::Complex<T>>> for super::Complex<T>
+where
+    super::Complex<T>: crate::scalar::Scalar,
+{
+    type Output = crate::matrix::Matrix<super::Complex<T>>;
+
+    fn mul(self, other: crate::matrix::Matrix<super::Complex<T>>) -> Self::Output {
+        other * self
+    }
+}
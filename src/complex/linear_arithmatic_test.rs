@@ -0,0 +1,105 @@
+use super::Complex;
+use crate::error::SlalError;
+use crate::linear::Dot;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+fn c(re: i32, im: i32) -> Complex<i32> {
+    Complex::new(re, im)
+}
+
+fn a() -> Matrix<Complex<i32>> {
+    Matrix::new(&[
+        &[c(1, 2), c(2, -1), c(0, 0)],
+        &[c(0, 3), c(1, 0), c(1, 1)],
+        &[c(2, 0), c(0, -1), c(4, -2)],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn add_sub_vertex() {
+    let v = Vertex::new(&[c(1, 1), c(2, -3)]);
+    let w = Vertex::new(&[c(0, 2), c(-1, 1)]);
+
+    assert_eq!(v.clone() + w.clone(), Vertex::new(&[c(1, 3), c(1, -2)]));
+    assert_eq!(v - w, Vertex::new(&[c(1, -1), c(3, -4)]));
+}
+
+#[test]
+fn add_sub_matrix() {
+    let m = Matrix::new(&[&[c(1, 1), c(0, -1)]]).unwrap();
+    let n = Matrix::new(&[&[c(2, -1), c(3, 3)]]).unwrap();
+
+    assert_eq!(
+        m.clone() + n.clone(),
+        Matrix::new(&[&[c(3, 0), c(3, 2)]]).unwrap()
+    );
+    assert_eq!(m - n, Matrix::new(&[&[c(-1, 2), c(-3, -4)]]).unwrap());
+}
+
+#[test]
+fn mul_scala() {
+    let v = Vertex::new(&[c(1, 1), c(2, -3)]);
+    let m = Matrix::new(&[&[c(1, 1)], &[c(0, -1)]]).unwrap();
+
+    assert_eq!(v * c(0, 1), Vertex::new(&[c(-1, 1), c(3, 2)]));
+    assert_eq!(
+        c(2, 0) * m.clone(),
+        Matrix::new(&[&[c(2, 2)], &[c(0, -2)]]).unwrap()
+    );
+    assert_eq!(
+        m.dot(&c(0, 1)),
+        Matrix::new(&[&[c(-1, 1)], &[c(1, 0)]]).unwrap()
+    );
+}
+
+#[test]
+fn dot_vertex() {
+    let v = Vertex::new(&[c(1, 1), c(2, 0)]);
+    let w = Vertex::new_transposed(&[c(1, -1), c(0, 3)]);
+
+    assert_eq!(v.dot(&w), Ok(c(2, 6)));
+    assert!(matches!(w.dot(&v), Err(SlalError::VertexStateError(_))));
+}
+
+#[test]
+fn dot_matrix_vertex() {
+    let v = Vertex::new_transposed(&[c(0, 1), c(2, 0), c(1, -1)]);
+
+    assert_eq!(
+        a().dot(&v),
+        Ok(Vertex::new_transposed(&[c(2, -1), c(1, 0), c(2, -6)]))
+    );
+}
+
+#[test]
+fn dot_vertex_matrix() {
+    let v = Vertex::new(&[c(0, 1), c(2, 0), c(1, -1)]);
+
+    assert_eq!(v.dot(&a()), Ok(Vertex::new(&[c(0, 5), c(2, 1), c(4, -4)])));
+}
+
+#[test]
+fn dot_matrix() {
+    let b = Matrix::new(&[
+        &[c(1, -1), c(2, 0)],
+        &[c(0, 0), c(0, 1)],
+        &[c(3, 0), c(1, 1)],
+    ])
+    .unwrap();
+
+    assert_eq!(
+        a().dot(&b),
+        Ok(Matrix::new(&[
+            &[c(3, 1), c(3, 6)],
+            &[c(6, 6), c(0, 9)],
+            &[c(14, -8), c(11, 2)]
+        ])
+        .unwrap())
+    );
+    assert!(matches!(
+        b.dot(&b),
+        Err(SlalError::UnmatchingMatrixSize(_, _))
+    ));
+}
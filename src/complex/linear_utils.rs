@@ -0,0 +1,191 @@
+use super::Complex;
+use crate::matrix::Matrix;
+
+/**
+    LU decomposition with partial pivoting of an n x n complex matrix in row-major order.
+    Returns the combined factors, the row permutation and the sign of the permutation,
+    or `None` when the matrix is singular within the same tolerance as real matrices.
+*/
+fn lu(mut a: Vec<Complex<f64>>, n: usize) -> Option<(Vec<Complex<f64>>, Vec<usize>, f64)> {
+    let (p, sign) = crate::matrix::eliminate(&mut a, n, |z| z.abs());
+
+    if (0..n).any(|k| a[k * n + k] == Complex::default()) {
+        return None;
+    }
+
+    Some((a, p, sign))
+}
+
+/**
+    Determinant of an n x n complex matrix in row-major order
+*/
+fn det(a: Vec<Complex<f64>>, n: usize) -> Complex<f64> {
+    match lu(a, n) {
+        Some((a, _, sign)) => (0..n).fold(Complex::new(sign, 0.), |acc, k| acc * a[k * n + k]),
+        None => Complex::default(),
+    }
+}
+
+/**
+    Checks that complex matrix `m` is square and not empty
+*/
+fn check_square<T>(m: &Matrix<Complex<T>>) -> crate::error::SlalErr<usize, Complex<T>>
+where
+    T: crate::scalar::Scalar + Default,
+{
+    use crate::error::SlalError;
+
+    let (x, y) = m.size();
+
+    if m.is_empty() {
+        return Err(SlalError::EmptyMatrix(format!("{:?}", *m)));
+    } else if x != y {
+        return Err(SlalError::NotSquareMatrix(
+            format!("{:?}", *m),
+            format!("{}", x),
+            format!("{}", y),
+        ));
+    }
+
+    Ok(x)
+}
+
+impl<T> Matrix<Complex<T>>
+where
+    T: crate::scalar::RealScalar + Default,
+{
+    /**
+    Calculates the determinant of a complex matrix with size (n, n) through LU decomposition.
+    This is not an implementation of `Determinant`, since that trait returns a real `f64` determinant.
+    Nearly singular matrices have a zero determinant, with the same pivot tolerance as `LuDecomposition::lu` of real matrices.
+
+    # Example
+    ```
+    use slal::complex::Complex;
+    use slal::matrix::Matrix;
+
+    //  | 1 + i  2     |
+    //  | 3      4 - i |
+    let m = Matrix::new(&[
+        &[Complex::new(1, 1), Complex::new(2, 0)],
+        &[Complex::new(3, 0), Complex::new(4, -1)],
+    ])
+    .unwrap();
+    let det = m.det().unwrap();
+
+    // (1 + i)(4 - i) - 6 = -1 + 3i
+    assert!((det - Complex::new(-1., 3.)).abs() < 1e-12);
+    ```
+     */
+    pub fn det(&self) -> crate::error::SlalErr<Complex<f64>, Complex<T>> {
+        let n = check_square(self)?;
+
+        Ok(det(self.as_slice().iter().map(|z| z.to_f64()).collect(), n))
+    }
+}
+
+impl<T> crate::linear::ConjugateTranspose for crate::vertex::Vertex<Complex<T>>
+where
+    T: crate::scalar::Scalar + Default + std::ops::Neg<Output = T>,
+{
+    fn h(&self) -> Self {
+        let rv: Vec<Complex<T>> = self.to_vec().iter().map(|z| z.conj()).collect();
+
+        if self.is_transposed() {
+            Self::new(rv.as_slice())
+        } else {
+            Self::new_transposed(rv.as_slice())
+        }
+    }
+}
+
+impl<T> crate::linear::ConjugateTranspose for Matrix<Complex<T>>
+where
+    T: crate::scalar::Scalar + Default + std::ops::Neg<Output = T>,
+{
+    fn h(&self) -> Self {
+        let (x, y) = self.size();
+        let rv: Vec<Complex<T>> =
+            crate::parallel::map_collect(x * y, |idx| self[idx % y][idx / y].conj());
+
+        Self::from_vec(rv, [y, x])
+    }
+}
+
+impl<T> crate::linear::Magnitude for crate::vertex::Vertex<Complex<T>>
+where
+    T: crate::scalar::RealScalar + Default,
+{
+    type Output = f64;
+
+    fn magnitude(&self) -> Self::Output {
+        (0..self.len())
+            .map(|idx| self[idx].magnitude().powi(2))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl<T> crate::linear::Cofactor<Complex<T>> for Matrix<Complex<T>>
+where
+    T: crate::scalar::RealScalar + Default,
+{
+    type Output = Matrix<Complex<f64>>;
+
+    fn cofactor(&self) -> crate::error::SlalErr<Self::Output, Complex<T>> {
+        let n = check_square(self)?;
+        let a: Vec<Complex<f64>> = self.as_slice().iter().map(|z| z.to_f64()).collect();
+
+        // Signed determinant of the minor without row j and column i
+        let rv: Vec<Complex<f64>> = crate::parallel::map_collect(n * n, |idx| {
+            let (j, i) = (idx / n, idx % n);
+            let minor: Vec<Complex<f64>> = (0..n * n)
+                .filter(|k| k / n != j && k % n != i)
+                .map(|k| a[k])
+                .collect();
+            let sign = if (i + j) % 2 == 0 { 1. } else { -1. };
+
+            det(minor, n - 1) * sign
+        });
+
+        Ok(Matrix::from_vec(rv, [n, n]))
+    }
+}
+
+impl<T> crate::linear::Inverse<Complex<T>> for Matrix<Complex<T>>
+where
+    T: crate::scalar::RealScalar + Default,
+{
+    fn inverse(&self) -> crate::error::SlalErr<Self::Output, Complex<T>> {
+        use crate::error::SlalError;
+
+        let n = check_square(self)?;
+        let a: Vec<Complex<f64>> = self.as_slice().iter().map(|z| z.to_f64()).collect();
+        let (lu, p, _) = match lu(a, n) {
+            Some(factors) => factors,
+            None => return Err(SlalError::DeterminantZero(self.clone())),
+        };
+
+        // Solve LU x = P e_i for every column of the identity
+        let mut rv = vec![Complex::default(); n * n];
+        for i in 0..n {
+            let mut x: Vec<Complex<f64>> = (0..n)
+                .map(|j| Complex::new(if p[j] == i { 1. } else { 0. }, 0.))
+                .collect();
+
+            for j in 0..n {
+                let s: Complex<f64> = (0..j).map(|k| lu[j * n + k] * x[k]).sum();
+
+                x[j] -= s;
+            }
+            for j in (0..n).rev() {
+                let s: Complex<f64> = ((j + 1)..n).map(|k| lu[j * n + k] * x[k]).sum();
+
+                x[j] = (x[j] - s) / lu[j * n + j];
+            }
+            (0..n).for_each(|j| rv[j * n + i] = x[j]);
+        }
+
+        Ok(Matrix::from_vec(rv, [n, n]))
+    }
+}
@@ -0,0 +1,156 @@
+use super::Complex;
+use crate::error::SlalError;
+use crate::linear::{
+    Cofactor, ConjugateTranspose, Determinant, Dot, Inverse, LuDecomposition, Magnitude,
+};
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+fn a() -> Matrix<Complex<f64>> {
+    let c = Complex::new;
+
+    Matrix::new(&[
+        &[c(1., 2.), c(2., -1.), c(0., 0.)],
+        &[c(0., 3.), c(1., 0.), c(1., 1.)],
+        &[c(2., 0.), c(0., -1.), c(4., -2.)],
+    ])
+    .unwrap()
+}
+
+#[test]
+fn conjugate_transpose() {
+    let v = Vertex::new_transposed(&[Complex::new(1, 1), Complex::new(0, -2)]);
+    let h = a().h();
+
+    assert_eq!(
+        v.h(),
+        Vertex::new(&[Complex::new(1, -1), Complex::new(0, 2)])
+    );
+    assert_eq!(h[0][1], Complex::new(0., -3.));
+    assert_eq!(h[2][1], Complex::new(1., -1.));
+    assert_eq!(h.h(), a());
+}
+
+#[test]
+fn conjugate_transpose_not_square() {
+    let m = Matrix::new(&[&[Complex::new(1, 1), Complex::new(2, 0), Complex::new(0, 3)]]).unwrap();
+
+    assert_eq!(
+        m.h(),
+        Matrix::new(&[
+            &[Complex::new(1, -1)],
+            &[Complex::new(2, 0)],
+            &[Complex::new(0, -3)]
+        ])
+        .unwrap()
+    );
+}
+
+#[test]
+fn inner_product() {
+    let v = Vertex::new_transposed(&[Complex::new(3, 4), Complex::new(0, 1)]);
+
+    assert_eq!(v.h().dot(&v), Ok(Complex::new(26, 0)));
+    assert_eq!(v.magnitude(), 26f64.sqrt());
+}
+
+#[test]
+fn det() {
+    let det = a().det().unwrap();
+
+    assert!((det - Complex::new(-13., -11.)).abs() < 1e-12);
+    assert!(matches!(
+        Matrix::new(&[&[Complex::new(1, 0), Complex::new(0, 1)]])
+            .unwrap()
+            .det(),
+        Err(SlalError::NotSquareMatrix(_, _, _))
+    ));
+}
+
+#[test]
+fn inverse() {
+    let inv = a().inverse().unwrap();
+    let identity = a().dot(&inv).unwrap();
+
+    (0..3).for_each(|j| {
+        (0..3).for_each(|i| {
+            let expected = if i == j { 1. } else { 0. };
+
+            assert!((identity[j][i] - Complex::new(expected, 0.)).abs() < 1e-12);
+        })
+    });
+}
+
+#[test]
+fn inverse_from_cofactor() {
+    let det = a().det().unwrap();
+    let mut adjugate = a().cofactor().unwrap();
+    let inv = a().inverse().unwrap();
+
+    adjugate.t();
+
+    (0..3).for_each(|j| {
+        (0..3).for_each(|i| assert!((adjugate[j][i] / det - inv[j][i]).abs() < 1e-12))
+    });
+}
+
+#[test]
+fn inverse_singular() {
+    let m = Matrix::new(&[
+        &[Complex::new(1, 1), Complex::new(2, 2)],
+        &[Complex::new(0, 1), Complex::new(0, 2)],
+    ])
+    .unwrap();
+
+    assert!(matches!(m.inverse(), Err(SlalError::DeterminantZero(_))));
+    assert_eq!(m.det(), Ok(Complex::new(0., 0.)));
+}
+
+#[test]
+fn inverse_nearly_singular() {
+    let c = |re: f64| Complex::new(re, re);
+    let near = 1. + f64::EPSILON;
+    let real = Matrix::new(&[
+        &[1., 1., 0., 0.],
+        &[1., near, 0., 0.],
+        &[0., 0., 1., 0.],
+        &[0., 0., 0., 1.],
+    ])
+    .unwrap();
+    let complex = Matrix::new(&[
+        &[c(1.), c(1.), c(0.), c(0.)],
+        &[c(1.), c(near), c(0.), c(0.)],
+        &[c(0.), c(0.), c(1.), c(0.)],
+        &[c(0.), c(0.), c(0.), c(1.)],
+    ])
+    .unwrap();
+
+    // Real and complex matrices share one pivot tolerance
+    assert!(real.lu().unwrap().is_singular());
+    assert_eq!(real.det(), Ok(0.));
+    assert!(matches!(
+        complex.inverse(),
+        Err(SlalError::DeterminantZero(_))
+    ));
+    assert_eq!(complex.det(), Ok(Complex::new(0., 0.)));
+}
+
+#[test]
+fn inverse_small_entries() {
+    let scaled = Matrix::from_vec(
+        a().as_slice()
+            .iter()
+            .map(|z| *z * Complex::new(1e-11, 0.))
+            .collect(),
+        [3, 3],
+    );
+    let identity = scaled.dot(&scaled.inverse().unwrap()).unwrap();
+
+    (0..3).for_each(|j| {
+        (0..3).for_each(|i| {
+            let expected = if i == j { 1. } else { 0. };
+
+            assert!((identity[j][i] - Complex::new(expected, 0.)).abs() < 1e-12);
+        })
+    });
+}
@@ -0,0 +1,154 @@
+impl<T> std::ops::Add for super::Complex<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl<T> std::ops::Sub for super::Complex<T>
+where
+    T: Copy + std::ops::Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl<T> std::ops::Mul for super::Complex<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl<T> std::ops::Div for super::Complex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    /**
+    Divides through `Scalar::complex_div`. Floating point numbers use Smith's algorithm,
+    so the quotient neither overflows nor underflows when the parts of the denominator are large or small.
+    Integer division truncates both parts of the quotient.
+     */
+    fn div(self, other: Self) -> Self::Output {
+        let (re, im) = T::complex_div((self.re, self.im), (other.re, other.im));
+
+        Self { re, im }
+    }
+}
+
+impl<T> std::ops::Neg for super::Complex<T>
+where
+    T: Copy + std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<T> std::ops::Mul<T> for super::Complex<T>
+where
+    T: Copy + std::ops::Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            re: self.re * other,
+            im: self.im * other,
+        }
+    }
+}
+
+impl<T> std::ops::Div<T> for super::Complex<T>
+where
+    T: Copy + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        Self {
+            re: self.re / other,
+            im: self.im / other,
+        }
+    }
+}
+
+impl<T> std::ops::AddAssign for super::Complex<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T> std::ops::SubAssign for super::Complex<T>
+where
+    T: Copy + std::ops::Sub<Output = T>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> std::ops::MulAssign for super::Complex<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> std::iter::Sum for super::Complex<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::default(), |acc, z| acc + z)
+    }
+}
+
+macro_rules! impl_mul_real {
+    ($($t:ty)*) => ($(
+        impl std::ops::Mul<super::Complex<$t>> for $t {
+            type Output = super::Complex<$t>;
+
+            fn mul(self, other: super::Complex<$t>) -> Self::Output {
+                other * self
+            }
+        }
+    )*)
+}
+
+impl_mul_real! { i8 i16 i32 i64 i128 isize f32 f64 }
@@ -0,0 +1,80 @@
+use super::Complex;
+use crate::linear::Magnitude;
+
+#[test]
+fn add_sub() {
+    let z = Complex::new(1, 2);
+    let w = Complex::new(-3, 5);
+
+    assert_eq!(z + w, Complex::new(-2, 7));
+    assert_eq!(z - w, Complex::new(4, -3));
+    assert_eq!(-z, Complex::new(-1, -2));
+}
+
+#[test]
+fn mul() {
+    // (1 + j) * (2 - j) = 3 + j
+    assert_eq!(Complex::new(1, 1) * Complex::new(2, -1), Complex::new(3, 1));
+    assert_eq!(Complex::new(1., -2.) * 3., Complex::new(3., -6.));
+    assert_eq!(2 * Complex::new(4, 5), Complex::new(8, 10));
+}
+
+#[test]
+fn div() {
+    let z = Complex::new(3., 1.) / Complex::new(1., 1.);
+
+    assert_eq!(z, Complex::new(2., -1.));
+    assert_eq!(Complex::new(4., -2.) / 2., Complex::new(2., -1.));
+}
+
+#[test]
+fn div_extreme_magnitudes() {
+    // Squaring the parts of the denominator overflows or underflows for these magnitudes
+    let z = Complex::new(1e200_f64, 1e200) / Complex::new(1e200, 1e200);
+    let w = Complex::new(3e-200_f64, 1e-200) / Complex::new(1e-200, 1e-200);
+
+    assert!((z.re() - 1.).abs() < 1e-15 && z.im().abs() < 1e-15);
+    assert!((w.re() - 2.).abs() < 1e-15 && (w.im() + 1.).abs() < 1e-15);
+    assert_eq!(Complex::new(7, 1) / Complex::new(1, 1), Complex::new(4, -3));
+}
+
+#[test]
+fn assign() {
+    let mut z = Complex::new(1, 1);
+
+    z += Complex::new(1, 0);
+    z -= Complex::new(0, 2);
+    z *= Complex::new(0, 1);
+
+    assert_eq!(z, Complex::new(1, 2));
+}
+
+#[test]
+fn sum() {
+    let z: Complex<i32> = (1..=4).map(|k| Complex::new(k, -k)).sum();
+
+    assert_eq!(z, Complex::new(10, -10));
+}
+
+#[test]
+fn conj_and_norm() {
+    let z = Complex::new(3, -4);
+
+    assert_eq!(z.conj(), Complex::new(3, 4));
+    assert_eq!(z.norm_sqr(), 25);
+    assert_eq!(z * z.conj(), Complex::new(25, 0));
+    assert_eq!(z.magnitude(), 5.);
+}
+
+#[test]
+fn polar() {
+    let z = Complex::<f64>::from_polar(2., -std::f64::consts::FRAC_PI_3);
+
+    assert!((z.abs() - 2.).abs() < 1e-15);
+    assert!((z.arg() + std::f64::consts::FRAC_PI_3).abs() < 1e-15);
+    assert_eq!(Complex::from(1.5f32), Complex::new(1.5, 0.));
+    assert_eq!(
+        Complex::<f64>::from(Complex::new(1i8, -1)),
+        Complex::new(1., -1.)
+    );
+}
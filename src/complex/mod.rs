@@ -0,0 +1,233 @@
+mod linear_arithmatic;
+mod linear_utils;
+mod math;
+
+#[cfg(test)]
+mod linear_arithmatic_test;
+#[cfg(test)]
+mod linear_utils_test;
+#[cfg(test)]
+mod math_test;
+
+pub use crate::linear::{ConjugateTranspose, Dot, Magnitude};
+
+/**
+    Complex number `re + im * i`.
+
+    `Complex<T>` is a `Scalar` for any scalar `T`, so vertices and matrices of complex numbers share
+    the generic arithmatic. Conjugation needs negation, thus conjugate transpose is only available for
+    signed integers and floating point numbers.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T> {
+    re: T,
+    im: T,
+}
+
+impl<T> Complex<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new complex number from its real and imaginary part
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    // 1 + 2i
+    let z = Complex::new(1, 2);
+    ```
+     */
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    /**
+    Returns the real part
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    let z = Complex::new(1., 2.);
+
+    assert!(z.re() == 1.);
+    ```
+     */
+    pub fn re(&self) -> T {
+        self.re
+    }
+
+    /**
+    Returns the imaginary part
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    let z = Complex::new(1., 2.);
+
+    assert!(z.im() == 2.);
+    ```
+     */
+    pub fn im(&self) -> T {
+        self.im
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + std::ops::Neg<Output = T>,
+{
+    /**
+    Returns the complex conjugate `re - im * i`
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    assert!(Complex::new(1, 2).conj() == Complex::new(1, -2));
+    ```
+     */
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    /**
+    Returns the squared absolute value `re^2 + im^2`
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    assert!(Complex::new(3, -4).norm_sqr() == 25);
+    ```
+     */
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+macro_rules! impl_complex_float {
+    ($($t:ty)*) => ($(
+        impl Complex<$t> {
+            /**
+            Returns the absolute value without intermediate overflow
+
+            # Example
+            ```
+            use slal::complex::Complex;
+
+            assert!(Complex::new(3_f64, -4.).abs() == 5.);
+            ```
+             */
+            pub fn abs(&self) -> $t {
+                self.re.hypot(self.im)
+            }
+
+            /**
+            Returns the argument in (-π, π]
+
+            # Example
+            ```
+            use slal::complex::Complex;
+
+            assert!(Complex::new(0_f64, 2.).arg() == std::f64::consts::FRAC_PI_2);
+            ```
+             */
+            pub fn arg(&self) -> $t {
+                self.im.atan2(self.re)
+            }
+
+            /**
+            Creates new complex number `r * (cos(theta) + sin(theta) * i)` from polar coordinates
+
+            # Example
+            ```
+            use slal::complex::Complex;
+
+            let z = Complex::<f64>::from_polar(2., std::f64::consts::PI);
+
+            assert!((z.re() + 2.).abs() < 1e-15 && z.im().abs() < 1e-15);
+            ```
+             */
+            pub fn from_polar(r: $t, theta: $t) -> Self {
+                Self {
+                    re: r * theta.cos(),
+                    im: r * theta.sin(),
+                }
+            }
+        }
+    )*)
+}
+
+impl_complex_float! { f32 f64 }
+
+impl<T> From<T> for Complex<T>
+where
+    T: Copy + Default,
+{
+    fn from(re: T) -> Self {
+        Self {
+            re,
+            im: T::default(),
+        }
+    }
+}
+
+macro_rules! impl_from_complex {
+    ($($t:ty)*) => ($(
+        impl From<Complex<$t>> for Complex<f64> {
+            fn from(z: Complex<$t>) -> Self {
+                Self {
+                    re: z.re as f64,
+                    im: z.im as f64,
+                }
+            }
+        }
+    )*)
+}
+
+impl_from_complex! { i8 i16 i32 f32 }
+
+impl<T> Complex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts both parts of the complex number to f64
+
+    # Example
+    ```
+    use slal::complex::Complex;
+
+    assert!(Complex::<i64>::new(1, -2).to_f64() == Complex::new(1., -2.));
+    ```
+     */
+    pub fn to_f64(&self) -> Complex<f64> {
+        Complex {
+            re: self.re.to_f64(),
+            im: self.im.to_f64(),
+        }
+    }
+}
+
+impl<T> crate::linear::Magnitude for Complex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn magnitude(&self) -> Self::Output {
+        self.re.to_f64().hypot(self.im.to_f64())
+    }
+}
@@ -25,6 +25,38 @@ pub enum SlalError<T> {
     TriangularMatrixNotExist(crate::matrix::Matrix<T>),
     #[error("Value of determinant is zero. {:?}", 1)]
     DeterminantZero(crate::matrix::Matrix<T>),
+    #[error("Matrix {0} is singular. Linear system does not have a unique solution.")]
+    SingularMatrix(String),
+    #[error("Matrix {0} is not a symmetric matrix.")]
+    NotSymmetricMatrix(String),
+    #[error("Matrix {0} is not positive-definite.")]
+    NotPositiveDefinite(String),
+    #[error("Computation for matrix {0} did not converge within {1} iterations.")]
+    NotConverged(String, String),
+    #[error("Arithmetic overflow occurred {0}.")]
+    ArithmeticOverflow(String),
+    #[error("Matrix {0} is not diagonally dominant.")]
+    NotDiagonallyDominant(String),
+    #[error("Order {0} of p-norm is out of range [1, inf].")]
+    InvalidNormOrder(String),
+    #[error("Relaxation factor {0} is out of range (0, 2).")]
+    InvalidRelaxationFactor(String),
+    #[error("Matrix {0} has no real principal logarithm since it has eigenvalues on the closed negative real axis.")]
+    NoRealLogarithm(String),
+    #[error("Matrix {0} has no real principal square root since it has negative real eigenvalues or a defective zero eigenvalue.")]
+    NoRealSquareRoot(String),
+    #[error("Matrix {0} is not diagonalizable.")]
+    NotDiagonalizable(String),
+    #[error("Matrix {0} has no real power {1} since it has negative real eigenvalues.")]
+    NoRealPower(String, String),
+    #[error("Index {0} is out of bounds for matrix of size {1}.")]
+    IndexOutOfBounds(String, String),
+    #[error("Matrix {0} is not a rotation matrix.")]
+    NotRotationMatrix(String),
+    #[error("Invalid projection {0}.")]
+    InvalidProjection(String),
+    #[error("Point {0} is projected to infinity.")]
+    PointAtInfinity(String),
 }
 
 pub type SlalErr<V, T> = Result<V, SlalError<T>>;
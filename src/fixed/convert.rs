@@ -0,0 +1,74 @@
+impl<T, const N: usize> From<[T; N]> for super::VertexN<T, N> {
+    fn from(v: [T; N]) -> Self {
+        Self { v }
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<[[T; N]; M]> for super::MatrixMN<T, M, N> {
+    fn from(m: [[T; N]; M]) -> Self {
+        Self { m }
+    }
+}
+
+impl<T, const N: usize> From<super::VertexN<T, N>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(v: super::VertexN<T, N>) -> Self {
+        crate::vertex::Vertex::new_transposed(&v.v)
+    }
+}
+
+impl<T, const M: usize, const N: usize> From<super::MatrixMN<T, M, N>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::MatrixMN<T, M, N>) -> Self {
+        crate::matrix::Matrix::from_vec(m.m.concat(), [N, M])
+    }
+}
+
+impl<T, const N: usize> TryFrom<&crate::vertex::Vertex<T>> for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Error = crate::error::SlalError<T>;
+
+    fn try_from(v: &crate::vertex::Vertex<T>) -> Result<Self, Self::Error> {
+        use crate::error::SlalError;
+
+        if v.len() != N {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex {:?} must have length {}",
+                *v, N
+            )));
+        }
+
+        Ok(Self {
+            v: std::array::from_fn(|i| v[i]),
+        })
+    }
+}
+
+impl<T, const M: usize, const N: usize> TryFrom<&crate::matrix::Matrix<T>>
+    for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Error = crate::error::SlalError<T>;
+
+    fn try_from(m: &crate::matrix::Matrix<T>) -> Result<Self, Self::Error> {
+        use crate::error::SlalError;
+
+        if m.size() != (N, M) {
+            return Err(SlalError::UnmatchingMatrixSize(
+                format!("{:?}", *m),
+                format!("({}, {})", N, M),
+            ));
+        }
+
+        Ok(Self {
+            m: std::array::from_fn(|j| std::array::from_fn(|i| m[j][i])),
+        })
+    }
+}
@@ -0,0 +1,56 @@
+use super::{MatrixMN, VertexN};
+use crate::error::SlalError;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+#[test]
+fn vertex_to_dynamic() {
+    let v = Vertex::from(VertexN::<i32, 3>::new([1, 2, 3]));
+
+    assert!(v.is_transposed());
+    assert_eq!(v, Vertex::new_transposed(&[1, 2, 3]));
+}
+
+#[test]
+fn vertex_from_dynamic() {
+    let v: VertexN<i32, 3> = VertexN::try_from(&Vertex::new(&[1, 2, 3])).unwrap();
+
+    assert_eq!(v, VertexN::from([1, 2, 3]));
+    assert!(matches!(
+        VertexN::<i32, 2>::try_from(&Vertex::new(&[1, 2, 3])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn matrix_to_dynamic() {
+    let m = Matrix::from(MatrixMN::<i32, 2, 3>::new([[1, 2, 3], [4, 5, 6]]));
+
+    assert_eq!(m.size(), (3, 2));
+    assert_eq!(m, Matrix::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap());
+}
+
+#[test]
+fn matrix_from_dynamic() {
+    let m = Matrix::new(&[&[1., 2.], &[3., 4.], &[5., 6.]]).unwrap();
+    let n: MatrixMN<f64, 3, 2> = MatrixMN::try_from(&m).unwrap();
+
+    assert_eq!(n, MatrixMN::from([[1., 2.], [3., 4.], [5., 6.]]));
+    assert_eq!(Matrix::from(n), m);
+    assert!(matches!(
+        MatrixMN::<f64, 2, 3>::try_from(&m),
+        Err(SlalError::UnmatchingMatrixSize(_, _))
+    ));
+}
+
+#[test]
+fn to_f64() {
+    assert_eq!(
+        VertexN::<u8, 2>::new([1, 2]).to_f64(),
+        VertexN::new([1., 2.])
+    );
+    assert_eq!(
+        MatrixMN::<i16, 1, 2>::new([[-1, 2]]).to_f64(),
+        MatrixMN::new([[-1., 2.]])
+    );
+}
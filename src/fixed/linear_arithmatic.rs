@@ -0,0 +1,102 @@
+impl<T, const N: usize> crate::linear::Dot<super::VertexN<T, N>> for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = T;
+
+    fn dot(&self, other: &Self) -> Self::Output {
+        (0..N).map(|i| self.v[i] * other.v[i]).sum()
+    }
+}
+
+impl<T> crate::linear::Cross<super::VertexN<T, 3>> for super::VertexN<T, 3>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn cross(&self, other: &Self) -> Self::Output {
+        let (a, b) = (&self.v, &other.v);
+
+        Self {
+            v: [
+                a[1] * b[2] - a[2] * b[1],
+                a[2] * b[0] - a[0] * b[2],
+                a[0] * b[1] - a[1] * b[0],
+            ],
+        }
+    }
+}
+
+impl<T, const N: usize> crate::linear::Magnitude for super::VertexN<T, N>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn magnitude(&self) -> Self::Output {
+        self.v
+            .iter()
+            .map(|v_i| (*v_i * *v_i).to_f64())
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+impl<T, const M: usize, const N: usize> crate::linear::Dot<super::VertexN<T, N>>
+    for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::VertexN<T, M>;
+
+    fn dot(&self, other: &super::VertexN<T, N>) -> Self::Output {
+        super::VertexN {
+            v: std::array::from_fn(|j| (0..N).map(|k| self.m[j][k] * other.v[k]).sum()),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize> crate::linear::Dot<super::MatrixMN<T, N, P>>
+    for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::MatrixMN<T, M, P>;
+
+    fn dot(&self, other: &super::MatrixMN<T, N, P>) -> Self::Output {
+        super::MatrixMN {
+            m: std::array::from_fn(|j| {
+                std::array::from_fn(|i| (0..N).map(|k| self.m[j][k] * other.m[k][i]).sum())
+            }),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Mul<super::VertexN<T, N>>
+    for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::VertexN<T, M>;
+
+    fn mul(self, other: super::VertexN<T, N>) -> Self::Output {
+        use crate::linear::Dot;
+
+        self.dot(&other)
+    }
+}
+
+impl<T, const M: usize, const N: usize, const P: usize> std::ops::Mul<super::MatrixMN<T, N, P>>
+    for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::MatrixMN<T, M, P>;
+
+    fn mul(self, other: super::MatrixMN<T, N, P>) -> Self::Output {
+        use crate::linear::Dot;
+
+        self.dot(&other)
+    }
+}
@@ -0,0 +1,71 @@
+use super::{Cross, Dot, Magnitude, MatrixMN, VertexN};
+
+#[test]
+fn dot_vertex() {
+    let v = VertexN::<i32, 3>::new([1, 2, 3]);
+    let w = VertexN::new([1, 4, 9]);
+
+    assert_eq!(v.dot(&w), 36);
+}
+
+#[test]
+fn cross_vertex() {
+    let v = VertexN::<i32, 3>::new([1, 2, 3]);
+    let w = VertexN::new([1, 4, 9]);
+
+    assert_eq!(v.cross(&w), VertexN::new([2 * 9 - 3 * 4, 3 - 9, 4 - 2]));
+    assert_eq!(v.cross(&w).dot(&v), 0);
+}
+
+#[test]
+fn magnitude_vertex() {
+    assert_eq!(VertexN::<i32, 2>::new([3, 4]).magnitude(), 5.);
+    assert_eq!(VertexN::<f64, 3>::new([1., 2., 2.]).magnitude(), 3.);
+}
+
+#[test]
+fn dot_matrix_vertex() {
+    let m = MatrixMN::<i32, 2, 3>::new([[1, 2, 3], [4, 5, 6]]);
+    let v = VertexN::new([1, 0, -1]);
+
+    assert_eq!(m.dot(&v), VertexN::new([-2, -2]));
+    assert_eq!(m * v, VertexN::new([-2, -2]));
+}
+
+#[test]
+fn dot_matrix_matrix() {
+    let m = MatrixMN::<i32, 2, 3>::new([[1, 2, 3], [4, 5, 6]]);
+    let n = MatrixMN::new([[1, 0], [0, 1], [1, 1]]);
+
+    assert_eq!(m.dot(&n), MatrixMN::new([[4, 5], [10, 11]]));
+    assert_eq!(n * m, MatrixMN::new([[1, 2, 3], [4, 5, 6], [5, 7, 9]]));
+}
+
+#[test]
+fn dot_matches_dynamic() {
+    use crate::matrix::Matrix;
+
+    let m = MatrixMN::<f64, 3, 3>::new([[1., 2., 0.], [0.5, -1., 3.], [2., 2., 2.]]);
+    let n = MatrixMN::new([[0., 1., 4.], [-2., 3., 1.], [1., 1., 1.]]);
+
+    let expected = Matrix::from(m).dot(&Matrix::from(n)).unwrap();
+
+    assert_eq!(Matrix::from(m * n), expected);
+}
+
+#[test]
+fn identity_and_trace() {
+    let m = MatrixMN::<i64, 3, 3>::new([[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+    assert_eq!(MatrixMN::identity() * m, m);
+    assert_eq!(m * MatrixMN::identity(), m);
+    assert_eq!(m.trace(), 15);
+}
+
+#[test]
+fn transpose() {
+    let m = MatrixMN::<i32, 2, 3>::new([[1, 2, 3], [4, 5, 6]]);
+
+    assert_eq!(m.transpose(), MatrixMN::new([[1, 4], [2, 5], [3, 6]]));
+    assert_eq!(m.transpose().transpose(), m);
+}
@@ -0,0 +1,139 @@
+impl<T, const N: usize> std::ops::Add for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            v: std::array::from_fn(|i| self.v[i] + other.v[i]),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Sub for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            v: std::array::from_fn(|i| self.v[i] - other.v[i]),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Neg for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar + std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            v: self.v.map(|v_i| -v_i),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Mul<T> for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            v: self.v.map(|v_i| v_i * other),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::AddAssign for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    fn add_assign(&mut self, other: Self) {
+        (0..N).for_each(|i| self.v[i] += other.v[i]);
+    }
+}
+
+impl<T, const N: usize> std::ops::SubAssign for super::VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    fn sub_assign(&mut self, other: Self) {
+        (0..N).for_each(|i| self.v[i] -= other.v[i]);
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Add for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            m: std::array::from_fn(|j| std::array::from_fn(|i| self.m[j][i] + other.m[j][i])),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Sub for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            m: std::array::from_fn(|j| std::array::from_fn(|i| self.m[j][i] - other.m[j][i])),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Neg for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar + std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            m: self.m.map(|row| row.map(|m_ji| -m_ji)),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Mul<T> for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            m: self.m.map(|row| row.map(|m_ji| m_ji * other)),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::AddAssign for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    fn add_assign(&mut self, other: Self) {
+        (0..M).for_each(|j| (0..N).for_each(|i| self.m[j][i] += other.m[j][i]));
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::SubAssign for super::MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    fn sub_assign(&mut self, other: Self) {
+        (0..M).for_each(|j| (0..N).for_each(|i| self.m[j][i] -= other.m[j][i]));
+    }
+}
@@ -0,0 +1,84 @@
+use super::{MatrixMN, VertexN};
+
+#[test]
+fn add_vertex() {
+    let v = VertexN::<i32, 3>::new([1, 2, 3]);
+    let w = VertexN::new([4, 5, 6]);
+
+    assert_eq!(v + w, VertexN::new([5, 7, 9]));
+}
+
+#[test]
+fn sub_vertex() {
+    let v = VertexN::<f64, 3>::new([1., 2., 3.]);
+    let w = VertexN::new([4., 6., 8.]);
+
+    assert_eq!(w - v, VertexN::new([3., 4., 5.]));
+}
+
+#[test]
+fn neg_and_scale_vertex() {
+    let v = VertexN::<i64, 2>::new([1, -2]);
+
+    assert_eq!(-v, VertexN::new([-1, 2]));
+    assert_eq!(v * 3, VertexN::new([3, -6]));
+}
+
+#[test]
+fn assign_vertex() {
+    let mut v = VertexN::<u8, 2>::new([1, 2]);
+
+    v += VertexN::new([3, 4]);
+    assert_eq!(v, VertexN::new([4, 6]));
+
+    v -= VertexN::new([1, 1]);
+    assert_eq!(v, VertexN::new([3, 5]));
+}
+
+#[test]
+fn index_vertex() {
+    let mut v = VertexN::<i32, 3>::zeros();
+
+    v[1] = 5;
+    assert_eq!(v.to_array(), [0, 5, 0]);
+    assert_eq!(v.len(), 3);
+    assert!(!v.is_empty());
+}
+
+#[test]
+fn add_matrix() {
+    let m = MatrixMN::<i32, 2, 3>::new([[1, 2, 3], [4, 5, 6]]);
+    let n = MatrixMN::new([[6, 5, 4], [3, 2, 1]]);
+
+    assert_eq!(m + n, MatrixMN::new([[7, 7, 7], [7, 7, 7]]));
+    assert_eq!(m - m, MatrixMN::zeros());
+}
+
+#[test]
+fn neg_and_scale_matrix() {
+    let m = MatrixMN::<f32, 2, 2>::new([[1., -2.], [0.5, 4.]]);
+
+    assert_eq!(-m, MatrixMN::new([[-1., 2.], [-0.5, -4.]]));
+    assert_eq!(m * 2., MatrixMN::new([[2., -4.], [1., 8.]]));
+}
+
+#[test]
+fn assign_matrix() {
+    let mut m = MatrixMN::<i32, 2, 2>::identity();
+
+    m += MatrixMN::new([[1, 2], [3, 4]]);
+    assert_eq!(m, MatrixMN::new([[2, 2], [3, 5]]));
+
+    m -= MatrixMN::identity();
+    assert_eq!(m, MatrixMN::new([[1, 2], [3, 4]]));
+}
+
+#[test]
+fn index_matrix() {
+    let mut m = MatrixMN::<i32, 3, 2>::zeros();
+
+    m[2][1] = 7;
+    assert_eq!(m[2], [0, 7]);
+    assert_eq!(m.size(), (2, 3));
+    assert_eq!(m.to_array(), [[0, 0], [0, 0], [0, 7]]);
+}
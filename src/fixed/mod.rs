@@ -0,0 +1,316 @@
+mod convert;
+mod linear_arithmatic;
+mod math;
+
+#[cfg(test)]
+mod convert_test;
+#[cfg(test)]
+mod linear_arithmatic_test;
+#[cfg(test)]
+mod math_test;
+
+pub use crate::linear::{Cross, Dot, Magnitude};
+
+/**
+    Vertex of length N stored in an array on the stack.
+
+    The length is part of the type, so mismatching lengths are caught at compile time
+    and no operation allocates or spawns threads. Fixed size vertices are column vectors,
+    they convert into transposed `Vertex`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VertexN<T, const N: usize> {
+    v: [T; N],
+}
+
+/**
+    Matrix of M rows and N columns stored in an array on the stack.
+
+    The size is part of the type, so mismatching sizes are caught at compile time
+    and no operation allocates or spawns threads.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixMN<T, const M: usize, const N: usize> {
+    m: [[T; N]; M],
+}
+
+impl<T, const N: usize> VertexN<T, N>
+where
+    T: Copy,
+{
+    /**
+    Creates new vertex from array
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    // VertexN<f32, 2>
+    //  | 1.0 |
+    //  | 1.1 |
+    let v = VertexN::new([1_f32, 1.1]);
+    ```
+     */
+    pub fn new(v: [T; N]) -> Self {
+        Self { v }
+    }
+
+    /**
+    Returns the length of vertex, which is always N
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    assert!(VertexN::new([1, 2, 3]).len() == 3);
+    ```
+     */
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /**
+    Checks if vertex is empty, which is only the case for N = 0
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    assert!(!VertexN::new([1, 2, 3]).is_empty());
+    ```
+     */
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /**
+    Copies the elements of vertex into an array
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    assert!(VertexN::new([1, 2, 3]).to_array() == [1, 2, 3]);
+    ```
+     */
+    pub fn to_array(&self) -> [T; N] {
+        self.v
+    }
+}
+
+impl<T, const N: usize> VertexN<T, N>
+where
+    T: crate::scalar::Scalar,
+{
+    /**
+    Creates vertex of zeros
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    assert!(VertexN::<i32, 3>::zeros() == VertexN::new([0, 0, 0]));
+    ```
+     */
+    pub fn zeros() -> Self {
+        Self { v: [T::zero(); N] }
+    }
+}
+
+impl<T, const N: usize> VertexN<T, N>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts every element of the vertex to f64
+
+    # Example
+    ```
+    use slal::fixed::VertexN;
+
+    assert!(VertexN::<u64, 3>::new([1, 2, 3]).to_f64() == VertexN::new([1., 2., 3.]));
+    ```
+     */
+    pub fn to_f64(&self) -> VertexN<f64, N> {
+        VertexN {
+            v: self.v.map(|v_i| v_i.to_f64()),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> MatrixMN<T, M, N>
+where
+    T: Copy,
+{
+    /**
+    Creates new matrix from array of M rows with N elements each
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    // MatrixMN<f32, 3, 2>
+    //  | 1.0 1.1 |
+    //  | 2.0 2.1 |
+    //  | 3.0 3.1 |
+    let m = MatrixMN::new([[1_f32, 1.1], [2.0, 2.1], [3.0, 3.1]]);
+    ```
+     */
+    pub fn new(m: [[T; N]; M]) -> Self {
+        Self { m }
+    }
+
+    /**
+    Returns the size of matrix as (N, M), in the same order as `Matrix::size`
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    let m = MatrixMN::new([[1.0, 2.0]]);
+    let (m_hor, m_vert) = m.size();     // returns (2, 1)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (N, M)
+    }
+
+    /**
+    Copies the rows of matrix into an array
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    assert!(MatrixMN::new([[1, 2], [3, 4]]).to_array() == [[1, 2], [3, 4]]);
+    ```
+     */
+    pub fn to_array(&self) -> [[T; N]; M] {
+        self.m
+    }
+
+    /**
+    Returns the transposed matrix of N rows and M columns
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    let m = MatrixMN::new([[1, 2, 3], [2, 3, 4]]);
+
+    assert!(m.transpose() == MatrixMN::new([[1, 2], [2, 3], [3, 4]]));
+    ```
+     */
+    pub fn transpose(&self) -> MatrixMN<T, N, M> {
+        MatrixMN {
+            m: std::array::from_fn(|i| std::array::from_fn(|j| self.m[j][i])),
+        }
+    }
+}
+
+impl<T, const M: usize, const N: usize> MatrixMN<T, M, N>
+where
+    T: crate::scalar::Scalar,
+{
+    /**
+    Creates matrix of zeros
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    assert!(MatrixMN::<i32, 2, 3>::zeros() == MatrixMN::new([[0, 0, 0], [0, 0, 0]]));
+    ```
+     */
+    pub fn zeros() -> Self {
+        Self {
+            m: [[T::zero(); N]; M],
+        }
+    }
+}
+
+impl<T, const N: usize> MatrixMN<T, N, N>
+where
+    T: crate::scalar::Scalar,
+{
+    /**
+    Creates identity matrix
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    assert!(MatrixMN::<i32, 2, 2>::identity() == MatrixMN::new([[1, 0], [0, 1]]));
+    ```
+     */
+    pub fn identity() -> Self {
+        let mut m = [[T::zero(); N]; N];
+
+        (0..N).for_each(|i| m[i][i] = T::one());
+
+        Self { m }
+    }
+
+    /**
+    Returns the sum of the diagonal elements
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    assert!(MatrixMN::new([[1, 2], [3, 4]]).trace() == 5);
+    ```
+     */
+    pub fn trace(&self) -> T {
+        (0..N).map(|i| self.m[i][i]).sum()
+    }
+}
+
+impl<T, const M: usize, const N: usize> MatrixMN<T, M, N>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts every element of the matrix to f64
+
+    # Example
+    ```
+    use slal::fixed::MatrixMN;
+
+    assert!(MatrixMN::<u64, 1, 2>::new([[1, 2]]).to_f64() == MatrixMN::new([[1., 2.]]));
+    ```
+     */
+    pub fn to_f64(&self) -> MatrixMN<f64, M, N> {
+        MatrixMN {
+            m: self.m.map(|row| row.map(|m_ji| m_ji.to_f64())),
+        }
+    }
+}
+
+impl<T, const N: usize> std::ops::Index<usize> for VertexN<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.v[index]
+    }
+}
+
+impl<T, const N: usize> std::ops::IndexMut<usize> for VertexN<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.v[index]
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::Index<usize> for MatrixMN<T, M, N> {
+    type Output = [T; N];
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.m[index]
+    }
+}
+
+impl<T, const M: usize, const N: usize> std::ops::IndexMut<usize> for MatrixMN<T, M, N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.m[index]
+    }
+}
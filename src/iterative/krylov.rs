@@ -0,0 +1,324 @@
+use super::{check_system, dot, norm, precondition, Config, Preconditioner, Report};
+
+/**
+    Solves `Ax = b` for a symmetric positive-definite operator with the (preconditioned) conjugate gradient method.
+    The preconditioner must be symmetric positive-definite as well.
+    Iteration stops early without convergence if the operator turns out not to be positive-definite.
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[1., 3.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+    let report = cg(&a, &b, &Config::default(), None).unwrap();
+
+    assert!(report.converged());
+    assert!((report.x()[0] - 1. / 11.).abs() < 1e-10);
+    assert!((report.x()[1] - 7. / 11.).abs() < 1e-10);
+    ```
+*/
+pub fn cg<A>(
+    a: &A,
+    b: &crate::vertex::Vertex<f64>,
+    config: &Config,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> crate::error::SlalErr<Report, f64>
+where
+    A: crate::linear::LinearOperator + ?Sized,
+{
+    let b = check_system(a, b)?;
+    let n = b.len();
+    let b_norm = norm(&b);
+    let mut x = vec![0.; n];
+    let mut history: Vec<f64> = Vec::new();
+
+    if b_norm == 0. {
+        return Ok(Report::new(x, true, history));
+    }
+
+    let mut r = b;
+    let mut z = vec![0.; n];
+    precondition(preconditioner, &r, &mut z);
+
+    let mut p = z.clone();
+    let mut a_p = vec![0.; n];
+    let mut r_z = dot(&r, &z);
+
+    while history.len() < config.max_iteration() {
+        a.apply(&p, &mut a_p);
+
+        let p_a_p = dot(&p, &a_p);
+        if p_a_p <= 0. {
+            // Operator is not positive-definite
+            break;
+        }
+
+        let alpha = r_z / p_a_p;
+        x.iter_mut()
+            .zip(p.iter())
+            .for_each(|(x_i, p_i)| *x_i += alpha * p_i);
+        r.iter_mut()
+            .zip(a_p.iter())
+            .for_each(|(r_i, a_p_i)| *r_i -= alpha * a_p_i);
+
+        let residual = norm(&r) / b_norm;
+        history.push(residual);
+        if residual <= config.tolerance() {
+            return Ok(Report::new(x, true, history));
+        }
+
+        precondition(preconditioner, &r, &mut z);
+
+        let r_z_next = dot(&r, &z);
+        let beta = r_z_next / r_z;
+        p.iter_mut()
+            .zip(z.iter())
+            .for_each(|(p_i, z_i)| *p_i = z_i + beta * *p_i);
+        r_z = r_z_next;
+    }
+
+    Ok(Report::new(x, false, history))
+}
+
+/**
+    Solves `Ax = b` for a general square operator with the restarted GMRES method.
+    The Krylov subspace is rebuilt after every `restart` iterations and
+    the preconditioner is applied from the right so residuals are those of the original system.
+
+    # Example
+    ```
+    use slal::iterative::{gmres, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[3., 1., 0.], &[-1., 4., 2.], &[0., 1., 5.]]).unwrap();
+    let b = Vertex::new_transposed(&[4., 5., 6.]);
+    let report = gmres(&a, &b, 3, &Config::default(), None).unwrap();
+
+    assert!(report.converged());
+    (0..3).for_each(|idx| assert!((report.x()[idx] - 1.).abs() < 1e-8));
+    ```
+*/
+pub fn gmres<A>(
+    a: &A,
+    b: &crate::vertex::Vertex<f64>,
+    restart: usize,
+    config: &Config,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> crate::error::SlalErr<Report, f64>
+where
+    A: crate::linear::LinearOperator + ?Sized,
+{
+    let b = check_system(a, b)?;
+    let n = b.len();
+    let m = restart.clamp(1, n.max(1));
+    let b_norm = norm(&b);
+    let mut x = vec![0.; n];
+    let mut history: Vec<f64> = Vec::new();
+
+    if b_norm == 0. {
+        return Ok(Report::new(x, true, history));
+    }
+
+    let mut r = vec![0.; n];
+    while history.len() < config.max_iteration() {
+        // r = b - Ax
+        a.apply(&x, &mut r);
+        r.iter_mut()
+            .zip(b.iter())
+            .for_each(|(r_i, b_i)| *r_i = b_i - *r_i);
+
+        let beta = norm(&r);
+        if beta / b_norm <= config.tolerance() {
+            return Ok(Report::new(x, true, history));
+        }
+
+        // Arnoldi basis V, preconditioned basis Z, Hessenberg matrix H stored by columns
+        let mut v: Vec<Vec<f64>> = vec![r.iter().map(|r_i| r_i / beta).collect()];
+        let mut z: Vec<Vec<f64>> = Vec::with_capacity(m);
+        let mut h: Vec<Vec<f64>> = Vec::with_capacity(m);
+        let (mut cs, mut sn) = (vec![0.; m], vec![0.; m]);
+        let mut g = vec![0.; m + 1];
+        g[0] = beta;
+
+        for j in 0..m {
+            let mut z_j = vec![0.; n];
+            let mut w = vec![0.; n];
+            precondition(preconditioner, &v[j], &mut z_j);
+            a.apply(&z_j, &mut w);
+            z.push(z_j);
+
+            // Modified Gram-Schmidt
+            let mut h_j = vec![0.; j + 2];
+            for (i, v_i) in v.iter().enumerate() {
+                h_j[i] = dot(&w, v_i);
+                w.iter_mut()
+                    .zip(v_i.iter())
+                    .for_each(|(w_k, v_k)| *w_k -= h_j[i] * v_k);
+            }
+            h_j[j + 1] = norm(&w);
+
+            // Apply previous Givens rotations to the new column
+            for i in 0..j {
+                let tmp = cs[i] * h_j[i] + sn[i] * h_j[i + 1];
+
+                h_j[i + 1] = -sn[i] * h_j[i] + cs[i] * h_j[i + 1];
+                h_j[i] = tmp;
+            }
+
+            // New Givens rotation eliminating H[j + 1][j]
+            let rho = h_j[j].hypot(h_j[j + 1]);
+            let subdiagonal = h_j[j + 1];
+            (cs[j], sn[j]) = if rho == 0. {
+                (1., 0.)
+            } else {
+                (h_j[j] / rho, h_j[j + 1] / rho)
+            };
+            h_j[j] = rho;
+            h_j[j + 1] = 0.;
+            g[j + 1] = -sn[j] * g[j];
+            g[j] *= cs[j];
+            h.push(h_j);
+
+            let residual = g[j + 1].abs() / b_norm;
+            history.push(residual);
+
+            if residual <= config.tolerance()
+                || subdiagonal == 0.
+                || history.len() >= config.max_iteration()
+            {
+                break;
+            }
+
+            v.push(w.iter().map(|w_k| w_k / subdiagonal).collect());
+        }
+
+        // Solve Hy = g by back substitution and update x += Zy
+        let k = h.len();
+        let mut y = g[..k].to_vec();
+        for j in (0..k).rev() {
+            y[j] = (y[j] - ((j + 1)..k).map(|i| h[i][j] * y[i]).sum::<f64>()) / h[j][j];
+        }
+        z.iter().zip(y.iter()).for_each(|(z_j, y_j)| {
+            x.iter_mut()
+                .zip(z_j.iter())
+                .for_each(|(x_i, z_ji)| *x_i += y_j * z_ji)
+        });
+
+        if history
+            .last()
+            .is_some_and(|residual| *residual <= config.tolerance())
+        {
+            return Ok(Report::new(x, true, history));
+        }
+    }
+
+    Ok(Report::new(x, false, history))
+}
+
+/**
+    Solves `Ax = b` for a general square operator with the (right preconditioned) BiCGSTAB method.
+    Iteration stops early without convergence on breakdown.
+
+    # Example
+    ```
+    use slal::iterative::{bicgstab, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[3., 1., 0.], &[-1., 4., 2.], &[0., 1., 5.]]).unwrap();
+    let b = Vertex::new_transposed(&[4., 5., 6.]);
+    let report = bicgstab(&a, &b, &Config::default(), None).unwrap();
+
+    assert!(report.converged());
+    (0..3).for_each(|idx| assert!((report.x()[idx] - 1.).abs() < 1e-8));
+    ```
+*/
+pub fn bicgstab<A>(
+    a: &A,
+    b: &crate::vertex::Vertex<f64>,
+    config: &Config,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> crate::error::SlalErr<Report, f64>
+where
+    A: crate::linear::LinearOperator + ?Sized,
+{
+    let b = check_system(a, b)?;
+    let n = b.len();
+    let b_norm = norm(&b);
+    let mut x = vec![0.; n];
+    let mut history: Vec<f64> = Vec::new();
+
+    if b_norm == 0. {
+        return Ok(Report::new(x, true, history));
+    }
+
+    let mut r = b;
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (1., 1., 1.);
+    let (mut p, mut v) = (vec![0.; n], vec![0.; n]);
+    let (mut p_hat, mut s_hat, mut t) = (vec![0.; n], vec![0.; n], vec![0.; n]);
+
+    while history.len() < config.max_iteration() {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == 0. {
+            break;
+        }
+
+        let beta = (rho_next / rho) * (alpha / omega);
+        p.iter_mut()
+            .zip(r.iter().zip(v.iter()))
+            .for_each(|(p_i, (r_i, v_i))| *p_i = r_i + beta * (*p_i - omega * v_i));
+
+        precondition(preconditioner, &p, &mut p_hat);
+        a.apply(&p_hat, &mut v);
+
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == 0. {
+            break;
+        }
+        alpha = rho_next / r_hat_v;
+
+        // s = r - alpha v is stored in r
+        r.iter_mut()
+            .zip(v.iter())
+            .for_each(|(r_i, v_i)| *r_i -= alpha * v_i);
+        x.iter_mut()
+            .zip(p_hat.iter())
+            .for_each(|(x_i, p_i)| *x_i += alpha * p_i);
+
+        let residual = norm(&r) / b_norm;
+        if residual <= config.tolerance() {
+            history.push(residual);
+            return Ok(Report::new(x, true, history));
+        }
+
+        precondition(preconditioner, &r, &mut s_hat);
+        a.apply(&s_hat, &mut t);
+
+        let t_t = dot(&t, &t);
+        omega = if t_t == 0. { 0. } else { dot(&t, &r) / t_t };
+        x.iter_mut()
+            .zip(s_hat.iter())
+            .for_each(|(x_i, s_i)| *x_i += omega * s_i);
+        r.iter_mut()
+            .zip(t.iter())
+            .for_each(|(r_i, t_i)| *r_i -= omega * t_i);
+
+        let residual = norm(&r) / b_norm;
+        history.push(residual);
+        if residual <= config.tolerance() {
+            return Ok(Report::new(x, true, history));
+        }
+
+        if omega == 0. {
+            break;
+        }
+        rho = rho_next;
+    }
+
+    Ok(Report::new(x, false, history))
+}
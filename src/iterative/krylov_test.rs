@@ -0,0 +1,228 @@
+use super::{bicgstab, cg, gmres, Config, DiagonalPreconditioner};
+use crate::error::{SlalErr, SlalError};
+use crate::linear::LinearOperator;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+// Matrix-free operator of the 1D Poisson equation (tridiagonal -1, 2, -1)
+struct Poisson {
+    n: usize,
+}
+
+impl LinearOperator for Poisson {
+    fn rows(&self) -> usize {
+        self.n
+    }
+
+    fn columns(&self) -> usize {
+        self.n
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        (0..self.n).for_each(|i| {
+            y[i] = 2. * x[i]
+                - if i > 0 { x[i - 1] } else { 0. }
+                - if i + 1 < self.n { x[i + 1] } else { 0. };
+        });
+    }
+}
+
+// Convection-diffusion matrix, which is not symmetric
+fn convection_diffusion(n: usize) -> Matrix<f64> {
+    let mut m = vec![0.; n * n];
+    (0..n).for_each(|j| {
+        m[j * n + j] = 4.;
+        if j > 0 {
+            m[j * n + j - 1] = -1.5;
+        }
+        if j + 1 < n {
+            m[j * n + j + 1] = -0.5;
+        }
+    });
+
+    let rows: Vec<&[f64]> = m.chunks(n).collect();
+
+    Matrix::new(&rows).unwrap()
+}
+
+// Relative residual |b - Ax| / |b|
+fn relative_residual<A: LinearOperator>(a: &A, x: &Vertex<f64>, b: &Vertex<f64>) -> f64 {
+    let mut a_x = vec![0.; b.len()];
+    a.apply(&x.to_vec(), &mut a_x);
+
+    let b = b.to_vec();
+    let residual: f64 = a_x
+        .iter()
+        .zip(b.iter())
+        .map(|(a_x_i, b_i)| (b_i - a_x_i).powi(2))
+        .sum();
+
+    (residual / b.iter().map(|b_i| b_i.powi(2)).sum::<f64>()).sqrt()
+}
+
+#[test]
+fn cg_matrix_free() -> SlalErr<(), f64> {
+    let a = Poisson { n: 50 };
+    let b = Vertex::new_transposed(&[1.; 50]);
+    let report = cg(&a, &b, &Config::new(1e-10, 100), None)?;
+
+    assert!(report.converged());
+    assert!(report.iterations() <= 50);
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn cg_preconditioned() -> SlalErr<(), f64> {
+    // Badly scaled diagonal is solved in one step with the diagonal preconditioner
+    let a = Matrix::<f64>::new(&[&[1e4, 1., 0.], &[1., 1., 0.], &[0., 0., 1e-2]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2., 3.]);
+    let preconditioner = DiagonalPreconditioner::new(&a);
+
+    let plain = cg(&a, &b, &Config::default(), None)?;
+    let preconditioned = cg(&a, &b, &Config::default(), Some(&preconditioner))?;
+
+    assert!(plain.converged() && preconditioned.converged());
+    assert!(preconditioned.iterations() <= plain.iterations());
+    assert!(relative_residual(&a, preconditioned.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn cg_not_positive_definite() -> SlalErr<(), f64> {
+    let a = Matrix::<f64>::new(&[&[1., 0.], &[0., -1.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 1.]);
+    let report = cg(&a, &b, &Config::default(), None)?;
+
+    assert!(!report.converged());
+
+    Ok(())
+}
+
+#[test]
+fn cg_max_iteration() -> SlalErr<(), f64> {
+    let a = Poisson { n: 50 };
+    let b = Vertex::new_transposed(&[1.; 50]);
+    let report = cg(&a, &b, &Config::new(1e-10, 5), None)?;
+
+    assert!(!report.converged());
+    assert!(report.iterations() == 5);
+    assert!(report.history().len() == 5);
+
+    Ok(())
+}
+
+#[test]
+fn cg_zero_rhs() -> SlalErr<(), f64> {
+    let a = Poisson { n: 3 };
+    let b = Vertex::new_transposed(&[0., 0., 0.]);
+    let report = cg(&a, &b, &Config::default(), None)?;
+
+    assert!(report.converged());
+    assert!(report.iterations() == 0);
+    assert!(report.x() == &b);
+
+    Ok(())
+}
+
+#[test]
+fn gmres_nonsymmetric() -> SlalErr<(), f64> {
+    let a = convection_diffusion(40);
+    let b = Vertex::new_transposed(&[1.; 40]);
+    let report = gmres(&a, &b, 40, &Config::default(), None)?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn gmres_restarted() -> SlalErr<(), f64> {
+    let a = convection_diffusion(40);
+    let b = Vertex::new_transposed(&[1.; 40]);
+    let report = gmres(&a, &b, 5, &Config::new(1e-10, 500), None)?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+    // Residual never increases within GMRES
+    assert!(report
+        .history()
+        .windows(2)
+        .all(|pair| pair[1] <= pair[0] * (1. + 1e-10)));
+
+    Ok(())
+}
+
+#[test]
+fn gmres_preconditioned() -> SlalErr<(), f64> {
+    let a = convection_diffusion(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+    let preconditioner = DiagonalPreconditioner::new(&a);
+    let report = gmres(&a, &b, 10, &Config::default(), Some(&preconditioner))?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn bicgstab_nonsymmetric() -> SlalErr<(), f64> {
+    let a = convection_diffusion(40);
+    let b = Vertex::new_transposed(&[1.; 40]);
+    let report = bicgstab(&a, &b, &Config::default(), None)?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn bicgstab_preconditioned() -> SlalErr<(), f64> {
+    let a = convection_diffusion(40);
+    let b = Vertex::new_transposed(&[1.; 40]);
+    let preconditioner = DiagonalPreconditioner::new(&a);
+    let report = bicgstab(&a, &b, &Config::default(), Some(&preconditioner))?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn vertex_not_transposed() {
+    let a = Poisson { n: 2 };
+    let b = Vertex::new(&[1., 1.]);
+
+    match bicgstab(&a, &b, &Config::default(), None) {
+        Err(SlalError::VertexStateError(_)) => (),
+        _ => panic!("Expected VertexStateError"),
+    }
+}
+
+#[test]
+fn unmatching_size() {
+    let a = Poisson { n: 2 };
+    let b = Vertex::new_transposed(&[1., 1., 1.]);
+
+    match gmres(&a, &b, 2, &Config::default(), None) {
+        Err(SlalError::VertexLengthAndMatrixHeightNotMatch(_, _, _)) => (),
+        _ => panic!("Expected VertexLengthAndMatrixHeightNotMatch"),
+    }
+}
+
+#[test]
+fn not_square() {
+    let a = Matrix::<f64>::new(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 1.]);
+
+    match cg(&a, &b, &Config::default(), None) {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
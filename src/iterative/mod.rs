@@ -0,0 +1,322 @@
+mod krylov;
+mod stationary;
+
+#[cfg(test)]
+mod krylov_test;
+#[cfg(test)]
+mod stationary_test;
+
+pub use krylov::*;
+pub use stationary::*;
+
+/**
+    Stopping criteria of iterative solvers.
+
+    Iteration stops when the relative residual `|b - Ax| / |b|` is not above `tolerance`
+    or after `max_iteration` iterations.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    tolerance: f64,
+    max_iteration: usize,
+}
+
+impl Config {
+    /**
+    Creates stopping criteria with relative residual tolerance and iteration limit
+
+    # Example
+    ```
+    use slal::iterative::Config;
+
+    let config = Config::new(1e-8, 500);
+
+    assert!(config.tolerance() == 1e-8);
+    assert!(config.max_iteration() == 500);
+    ```
+     */
+    pub fn new(tolerance: f64, max_iteration: usize) -> Self {
+        Self {
+            tolerance,
+            max_iteration,
+        }
+    }
+
+    /**
+    Returns the relative residual tolerance
+
+    # Example
+    ```
+    use slal::iterative::Config;
+
+    assert!(Config::default().tolerance() == 1e-10);
+    ```
+     */
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /**
+    Returns the maximum number of iterations
+
+    # Example
+    ```
+    use slal::iterative::Config;
+
+    assert!(Config::default().max_iteration() == 1000);
+    ```
+     */
+    pub fn max_iteration(&self) -> usize {
+        self.max_iteration
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new(1e-10, 1000)
+    }
+}
+
+/**
+    Convergence report of an iterative solver.
+
+    Holds the last iterate, whether the stopping tolerance was reached,
+    the number of iterations and the relative residual after every iteration.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    x: crate::vertex::Vertex<f64>,
+    converged: bool,
+    iterations: usize,
+    history: Vec<f64>,
+}
+
+impl Report {
+    pub(crate) fn new(x: Vec<f64>, converged: bool, history: Vec<f64>) -> Self {
+        Self {
+            x: crate::vertex::Vertex::new_transposed(x.as_slice()),
+            converged,
+            iterations: history.len(),
+            history,
+        }
+    }
+
+    /**
+    Returns the solution (last iterate) as a transposed vertex
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[2., 0.], &[0., 4.]]).unwrap();
+    let b = Vertex::new_transposed(&[2., 4.]);
+    let report = cg(&a, &b, &Config::default(), None).unwrap();
+
+    assert!(report.x() == &Vertex::new_transposed(&[1., 1.]));
+    ```
+     */
+    pub fn x(&self) -> &crate::vertex::Vertex<f64> {
+        &self.x
+    }
+
+    /**
+    Checks if the solver reached the tolerance within the iteration limit
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[1., 3.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+
+    assert!(cg(&a, &b, &Config::default(), None).unwrap().converged());
+    assert!(!cg(&a, &b, &Config::new(1e-10, 1), None).unwrap().converged());
+    ```
+     */
+    pub fn converged(&self) -> bool {
+        self.converged
+    }
+
+    /**
+    Returns the number of iterations performed
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[1., 3.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+
+    // Conjugate gradient converges within n iterations in exact arithmetic
+    assert!(cg(&a, &b, &Config::default(), None).unwrap().iterations() <= 3);
+    ```
+     */
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /**
+    Returns the relative residual after the last iteration
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[1., 3.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+
+    assert!(cg(&a, &b, &Config::default(), None).unwrap().residual() <= 1e-10);
+    ```
+     */
+    pub fn residual(&self) -> f64 {
+        self.history.last().copied().unwrap_or(0.)
+    }
+
+    /**
+    Returns the relative residual after every iteration
+
+    # Example
+    ```
+    use slal::iterative::{cg, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[1., 3.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+    let report = cg(&a, &b, &Config::default(), None).unwrap();
+
+    assert!(report.history().len() == report.iterations());
+    ```
+     */
+    pub fn history(&self) -> &[f64] {
+        &self.history
+    }
+}
+
+/**
+    Preconditioner `M` approximating the operator of a linear system
+*/
+pub trait Preconditioner {
+    /**
+    Solves `Mz = r` for `z`
+
+    # Example
+    ```
+    use slal::iterative::{DiagonalPreconditioner, Preconditioner};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<f64>::new(&[&[2., 1.], &[1., 4.]]).unwrap();
+    let mut z = vec![0.; 2];
+
+    DiagonalPreconditioner::new(&m).apply(&[2., 2.], &mut z);
+
+    assert!(z == vec![1., 0.5]);
+    ```
+     */
+    fn apply(&self, r: &[f64], z: &mut [f64]);
+}
+
+/**
+    Jacobi (diagonal) preconditioner `M = diag(A)`
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiagonalPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl DiagonalPreconditioner {
+    /**
+    Creates a preconditioner from the diagonal of a square matrix.
+    Zero diagonal elements are left unscaled.
+
+    # Example
+    ```
+    use slal::iterative::{DiagonalPreconditioner, Preconditioner};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<f64>::new(&[&[4., 1.], &[1., 0.]]).unwrap();
+    let mut z = vec![0.; 2];
+
+    DiagonalPreconditioner::new(&m).apply(&[2., 2.], &mut z);
+
+    assert!(z == vec![0.5, 2.]);
+    ```
+     */
+    pub fn new(a: &crate::matrix::Matrix<f64>) -> Self {
+        let (n, m) = a.size();
+
+        Self {
+            inverse_diagonal: (0..n.min(m))
+                .map(|ij| if a[ij][ij] == 0. { 1. } else { 1. / a[ij][ij] })
+                .collect(),
+        }
+    }
+}
+
+impl Preconditioner for DiagonalPreconditioner {
+    fn apply(&self, r: &[f64], z: &mut [f64]) {
+        z.iter_mut()
+            .zip(r.iter().zip(self.inverse_diagonal.iter()))
+            .for_each(|(z_i, (r_i, d_i))| *z_i = r_i * d_i);
+    }
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y.iter()).map(|(x_i, y_i)| x_i * y_i).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/**
+    Applies the preconditioner, or copies `r` into `z` when there is none
+*/
+fn precondition(preconditioner: Option<&dyn Preconditioner>, r: &[f64], z: &mut [f64]) {
+    match preconditioner {
+        Some(m) => m.apply(r, z),
+        None => z.copy_from_slice(r),
+    }
+}
+
+/**
+    Checks that the operator is square and `b` is a transposed vertex of matching length.
+    Returns `b` as a vector.
+*/
+fn check_system<A>(a: &A, b: &crate::vertex::Vertex<f64>) -> crate::error::SlalErr<Vec<f64>, f64>
+where
+    A: crate::linear::LinearOperator + ?Sized,
+{
+    use crate::error::SlalError;
+
+    if a.rows() != a.columns() {
+        return Err(SlalError::NotSquareMatrix(
+            String::from("linear operator"),
+            format!("{}", a.columns()),
+            format!("{}", a.rows()),
+        ));
+    }
+
+    if !b.is_transposed() {
+        return Err(SlalError::VertexStateError(format!(
+            "Vertex must be transposed when solving linear system with vertex {:?}",
+            *b,
+        )));
+    } else if b.len() != a.rows() {
+        return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+            format!("{:?}", *b),
+            format!("linear operator of size ({}, {})", a.columns(), a.rows()),
+            String::from("while solving linear system"),
+        ));
+    }
+
+    Ok(b.to_vec())
+}
@@ -0,0 +1,212 @@
+use super::{check_system, norm, Config, Report};
+
+/**
+    Checks that the matrix is weakly chained diagonally dominant:
+    every row satisfies `|a_jj| >= sum |a_ji| (i != j)` with nonzero `a_jj`,
+    and every row reaches a row with strict inequality through a chain of nonzero entries `a_ji`.
+    Strictly and irreducibly diagonally dominant matrices pass, and every passing matrix is non-singular
+    with a Jacobi iteration matrix of spectral radius below 1.
+*/
+fn check_diagonal_dominance(a: &crate::matrix::Matrix<f64>) -> crate::error::SlalErr<(), f64> {
+    use crate::error::SlalError;
+
+    let n = a.size().1;
+    let margins: Vec<(bool, f64)> = crate::parallel::map_collect(n, |j| {
+        let off_diagonal: f64 = (0..n).filter(|i| *i != j).map(|i| a[j][i].abs()).sum();
+
+        (a[j][j] != 0., a[j][j].abs() - off_diagonal)
+    });
+
+    if !margins
+        .iter()
+        .all(|(nonzero, margin)| *nonzero && *margin >= 0.)
+    {
+        return Err(SlalError::NotDiagonallyDominant(format!("{:?}", *a)));
+    }
+
+    // Walk backwards from strictly dominant rows along nonzero entries
+    let mut reached: Vec<bool> = margins.iter().map(|(_, margin)| *margin > 0.).collect();
+    let mut stack: Vec<usize> = (0..n).filter(|i| reached[*i]).collect();
+    while let Some(i) = stack.pop() {
+        for j in 0..n {
+            if !reached[j] && a[j][i] != 0. {
+                reached[j] = true;
+                stack.push(j);
+            }
+        }
+    }
+
+    if reached.iter().all(|reached_j| *reached_j) {
+        Ok(())
+    } else {
+        Err(SlalError::NotDiagonallyDominant(format!("{:?}", *a)))
+    }
+}
+
+/**
+    Relative residual `|b - Ax| / |b|`
+*/
+fn relative_residual(a: &crate::matrix::Matrix<f64>, x: &[f64], b: &[f64], b_norm: f64) -> f64 {
+    use crate::linear::LinearOperator;
+
+    let mut r = vec![0.; b.len()];
+    a.apply(x, &mut r);
+    r.iter_mut()
+        .zip(b.iter())
+        .for_each(|(r_i, b_i)| *r_i -= b_i);
+
+    norm(&r) / b_norm
+}
+
+/**
+    Checks the linear system and runs `step` until the relative residual reaches the tolerance
+*/
+fn iterate<F>(
+    a: &crate::matrix::Matrix<f64>,
+    b: &crate::vertex::Vertex<f64>,
+    config: &Config,
+    step: F,
+) -> crate::error::SlalErr<Report, f64>
+where
+    F: Fn(&[f64], &mut Vec<f64>),
+{
+    let b = check_system(a, b)?;
+    check_diagonal_dominance(a)?;
+
+    let b_norm = norm(&b);
+    let mut x = vec![0.; b.len()];
+    let mut history: Vec<f64> = Vec::new();
+
+    if b_norm == 0. {
+        return Ok(Report::new(x, true, history));
+    }
+
+    while history.len() < config.max_iteration() {
+        step(&b, &mut x);
+
+        let residual = relative_residual(a, &x, &b, b_norm);
+        history.push(residual);
+        if residual <= config.tolerance() {
+            return Ok(Report::new(x, true, history));
+        }
+    }
+
+    Ok(Report::new(x, false, history))
+}
+
+/**
+    Solves `Ax = b` for a diagonally dominant matrix with the Jacobi method.
+    Returns `NotDiagonallyDominant` unless every row is weakly diagonally dominant
+    and reaches a strictly dominant row through nonzero entries, which guarantees convergence.
+    Every iteration updates all rows in parallel from the previous iterate.
+
+    # Example
+    ```
+    use slal::iterative::{jacobi, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[2., 5.]]).unwrap();
+    let b = Vertex::new_transposed(&[5., 7.]);
+    let report = jacobi(&a, &b, &Config::default()).unwrap();
+
+    assert!(report.converged());
+    assert!((report.x()[0] - 1.).abs() < 1e-9 && (report.x()[1] - 1.).abs() < 1e-9);
+    ```
+*/
+pub fn jacobi(
+    a: &crate::matrix::Matrix<f64>,
+    b: &crate::vertex::Vertex<f64>,
+    config: &Config,
+) -> crate::error::SlalErr<Report, f64> {
+    iterate(a, b, config, |b, x| {
+        let previous = x.clone();
+
+        crate::parallel::for_each_mut(x, |j, x_j| {
+            let row = &a[j];
+            let sum: f64 = row
+                .iter()
+                .zip(previous.iter())
+                .enumerate()
+                .filter(|(i, _)| *i != j)
+                .map(|(_, (a_ji, x_i))| a_ji * x_i)
+                .sum();
+
+            *x_j = (b[j] - sum) / row[j];
+        });
+    })
+}
+
+/**
+    Solves `Ax = b` for a diagonally dominant matrix with the Gauss-Seidel method.
+    Accepts the same matrices as `jacobi`, for which the iteration always converges.
+
+    # Example
+    ```
+    use slal::iterative::{gauss_seidel, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., 1.], &[2., 5.]]).unwrap();
+    let b = Vertex::new_transposed(&[5., 7.]);
+    let report = gauss_seidel(&a, &b, &Config::default()).unwrap();
+
+    assert!(report.converged());
+    assert!((report.x()[0] - 1.).abs() < 1e-9 && (report.x()[1] - 1.).abs() < 1e-9);
+    ```
+*/
+pub fn gauss_seidel(
+    a: &crate::matrix::Matrix<f64>,
+    b: &crate::vertex::Vertex<f64>,
+    config: &Config,
+) -> crate::error::SlalErr<Report, f64> {
+    sor(a, b, 1., config)
+}
+
+/**
+    Solves `Ax = b` for a diagonally dominant matrix with successive over-relaxation.
+    The relaxation factor `omega` must be in (0, 2), and `omega = 1` is the Gauss-Seidel method.
+    Accepts the same matrices as `jacobi`, but convergence is only guaranteed for `omega <= 1`
+    or if the matrix is also symmetric positive-definite.
+
+    # Example
+    ```
+    use slal::iterative::{sor, Config};
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<f64>::new(&[&[4., -1., 0.], &[-1., 4., -1.], &[0., -1., 4.]]).unwrap();
+    let b = Vertex::new_transposed(&[3., 2., 3.]);
+    let report = sor(&a, &b, 1.05, &Config::default()).unwrap();
+
+    assert!(report.converged());
+    (0..3).for_each(|idx| assert!((report.x()[idx] - 1.).abs() < 1e-9));
+    ```
+*/
+pub fn sor(
+    a: &crate::matrix::Matrix<f64>,
+    b: &crate::vertex::Vertex<f64>,
+    omega: f64,
+    config: &Config,
+) -> crate::error::SlalErr<Report, f64> {
+    use crate::error::SlalError;
+
+    if !(omega > 0. && omega < 2.) {
+        return Err(SlalError::InvalidRelaxationFactor(format!("{}", omega)));
+    }
+
+    iterate(a, b, config, |b, x| {
+        for j in 0..x.len() {
+            let row = &a[j];
+            let sum: f64 = row
+                .iter()
+                .zip(x.iter())
+                .enumerate()
+                .filter(|(i, _)| *i != j)
+                .map(|(_, (a_ji, x_i))| a_ji * x_i)
+                .sum();
+
+            x[j] += omega * ((b[j] - sum) / row[j] - x[j]);
+        }
+    })
+}
@@ -0,0 +1,204 @@
+use super::{gauss_seidel, jacobi, sor, Config};
+use crate::error::{SlalErr, SlalError};
+use crate::linear::LinearOperator;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+// Strictly diagonally dominant tridiagonal matrix (-1, 4, -1)
+fn dominant(n: usize) -> Matrix<f64> {
+    let mut m = vec![0.; n * n];
+    (0..n).for_each(|j| {
+        m[j * n + j] = 4.;
+        if j > 0 {
+            m[j * n + j - 1] = -1.;
+        }
+        if j + 1 < n {
+            m[j * n + j + 1] = -1.;
+        }
+    });
+
+    let rows: Vec<&[f64]> = m.chunks(n).collect();
+
+    Matrix::new(&rows).unwrap()
+}
+
+// Relative residual |b - Ax| / |b|
+fn relative_residual(a: &Matrix<f64>, x: &Vertex<f64>, b: &Vertex<f64>) -> f64 {
+    let mut a_x = vec![0.; b.len()];
+    a.apply(&x.to_vec(), &mut a_x);
+
+    let b = b.to_vec();
+    let residual: f64 = a_x
+        .iter()
+        .zip(b.iter())
+        .map(|(a_x_i, b_i)| (b_i - a_x_i).powi(2))
+        .sum();
+
+    (residual / b.iter().map(|b_i| b_i.powi(2)).sum::<f64>()).sqrt()
+}
+
+#[test]
+fn jacobi_dominant() -> SlalErr<(), f64> {
+    let a = dominant(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+    let report = jacobi(&a, &b, &Config::default())?;
+
+    assert!(report.converged());
+    assert!(report.history().len() == report.iterations());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn gauss_seidel_dominant() -> SlalErr<(), f64> {
+    let a = dominant(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+    let report = gauss_seidel(&a, &b, &Config::default())?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn gauss_seidel_faster_than_jacobi() -> SlalErr<(), f64> {
+    let a = dominant(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+
+    let jacobi = jacobi(&a, &b, &Config::default())?;
+    let gauss_seidel = gauss_seidel(&a, &b, &Config::default())?;
+
+    assert!(gauss_seidel.iterations() < jacobi.iterations());
+
+    Ok(())
+}
+
+#[test]
+fn sor_dominant() -> SlalErr<(), f64> {
+    let a = dominant(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+    let report = sor(&a, &b, 1.1, &Config::default())?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+    // Residual of a contraction keeps decreasing
+    assert!(report.history().windows(2).all(|pair| pair[1] < pair[0]));
+
+    Ok(())
+}
+
+#[test]
+fn sor_equals_gauss_seidel() -> SlalErr<(), f64> {
+    let a = dominant(10);
+    let b = Vertex::new_transposed(&[1.; 10]);
+
+    assert!(sor(&a, &b, 1., &Config::default())? == gauss_seidel(&a, &b, &Config::default())?);
+
+    Ok(())
+}
+
+#[test]
+fn sor_invalid_relaxation_factor() {
+    let a = dominant(3);
+    let b = Vertex::new_transposed(&[1.; 3]);
+
+    [0., 2., -0.5, f64::NAN].iter().for_each(|omega| {
+        match sor(&a, &b, *omega, &Config::default()) {
+            Err(SlalError::InvalidRelaxationFactor(_)) => (),
+            _ => panic!("Expected InvalidRelaxationFactor"),
+        }
+    });
+}
+
+#[test]
+fn max_iteration() -> SlalErr<(), f64> {
+    let a = dominant(30);
+    let b = Vertex::new_transposed(&[1.; 30]);
+    let report = jacobi(&a, &b, &Config::new(1e-10, 3))?;
+
+    assert!(!report.converged());
+    assert!(report.iterations() == 3);
+
+    Ok(())
+}
+
+#[test]
+fn zero_rhs() -> SlalErr<(), f64> {
+    let a = dominant(3);
+    let b = Vertex::new_transposed(&[0.; 3]);
+    let report = gauss_seidel(&a, &b, &Config::default())?;
+
+    assert!(report.converged());
+    assert!(report.iterations() == 0);
+    assert!(report.x() == &b);
+
+    Ok(())
+}
+
+#[test]
+fn not_diagonally_dominant() {
+    let a = Matrix::<f64>::new(&[&[1., 2.], &[3., 4.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 1.]);
+
+    match jacobi(&a, &b, &Config::default()) {
+        Err(SlalError::NotDiagonallyDominant(_)) => (),
+        _ => panic!("Expected NotDiagonallyDominant"),
+    }
+    match gauss_seidel(&a, &b, &Config::default()) {
+        Err(SlalError::NotDiagonallyDominant(_)) => (),
+        _ => panic!("Expected NotDiagonallyDominant"),
+    }
+}
+
+#[test]
+fn weakly_diagonally_dominant() -> SlalErr<(), f64> {
+    // Irreducible with strict dominance only in the first and last rows
+    let a = Matrix::<f64>::new(&[
+        &[2., -1., 0., 0.],
+        &[-1., 2., -1., 0.],
+        &[0., -1., 2., -1.],
+        &[0., 0., -1., 2.],
+    ])?;
+    let b = Vertex::new_transposed(&[1., 0., 0., 1.]);
+    let report = gauss_seidel(&a, &b, &Config::default())?;
+
+    assert!(report.converged());
+    assert!(relative_residual(&a, report.x(), &b) < 1e-9);
+
+    Ok(())
+}
+
+#[test]
+fn reducible_singular_block() {
+    // Singular block [[1, -1], [-1, 1]] never reaches the strictly dominant block
+    let a = Matrix::<f64>::new(&[
+        &[1., -1., 0., 0.],
+        &[-1., 1., 0., 0.],
+        &[0., 0., 4., 1.],
+        &[0., 0., 1., 4.],
+    ])
+    .unwrap();
+    let b = Vertex::new_transposed(&[1., 1., 1., 1.]);
+
+    match jacobi(&a, &b, &Config::default()) {
+        Err(SlalError::NotDiagonallyDominant(_)) => (),
+        _ => panic!("Expected NotDiagonallyDominant"),
+    }
+    match sor(&a, &b, 1.2, &Config::default()) {
+        Err(SlalError::NotDiagonallyDominant(_)) => (),
+        _ => panic!("Expected NotDiagonallyDominant"),
+    }
+}
+
+#[test]
+fn not_square() {
+    let a = Matrix::<f64>::new(&[&[4., 1., 1.], &[1., 4., 1.]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 1.]);
+
+    match jacobi(&a, &b, &Config::default()) {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
@@ -1,5 +1,17 @@
+pub mod banded;
+pub mod complex;
 pub mod error;
+pub mod fixed;
+pub mod iterative;
 pub mod linear;
 pub mod matrix;
+pub mod packed;
+pub mod parallel;
+pub mod quaternion;
+pub mod rational;
+pub mod rotation;
+pub mod scalar;
+pub mod sparse;
+pub mod transform;
 pub mod utils;
 pub mod vertex;
@@ -26,6 +26,32 @@ where
     fn dot(&self, other: &T) -> Self::Output;
 }
 
+/**
+    Calculation of integer powers for square matrices.
+    Elements stay in the element type, so integer overflow panics in debug builds and wraps in release builds.
+    Real powers of `f64` matrices are computed by `MatrixFunction::powf`, which returns `NotDiagonalizable`
+    for defective matrices and `NoRealPower` for fractional powers of matrices with negative real eigenvalues.
+*/
+pub trait Power<T> {
+    /**
+    Raises a square matrix to a non-negative integer power by repeated squaring.
+    `pow(0)` is the identity matrix.
+
+    # Example
+    ```
+    use slal::linear::Power;
+    use slal::matrix::Matrix;
+
+    // Fibonacci numbers F(n + 1), F(n), F(n - 1)
+    let m = Matrix::<u64>::new(&[&[1, 1], &[1, 0]]).unwrap();
+
+    assert!(m.pow(10) == Matrix::new(&[&[89, 55], &[55, 34]]));
+    assert!(m.pow(0) == Matrix::new(&[&[1, 0], &[0, 1]]));
+    ```
+     */
+    fn pow(&self, n: u32) -> crate::error::SlalErr<crate::matrix::Matrix<T>, T>;
+}
+
 /**
     Calculation of cross product for vertices/matrices
 */
@@ -80,6 +106,136 @@ pub trait Magnitude {
     fn magnitude(&self) -> Self::Output;
 }
 
+/**
+    Conjugate transpose (Hermitian adjoint) of complex vertex/matrix
+*/
+pub trait ConjugateTranspose {
+    /**
+    Returns the conjugate transpose, which transposes and conjugates every element
+
+    # Example
+    ```
+    use slal::complex::Complex;
+    use slal::linear::ConjugateTranspose;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[Complex::new(1, 2), Complex::new(3, -1)]]).unwrap();
+
+    assert!(m.h() == Matrix::new(&[&[Complex::new(1, -2)], &[Complex::new(3, 1)]]).unwrap());
+    ```
+     */
+    fn h(&self) -> Self;
+}
+
+/**
+    Calculation of norms for vertices/matrices
+*/
+pub trait Norm<T> {
+    type Output;
+
+    /**
+    Computes the 1-norm (sum of absolute values for vertex, maximum absolute column sum for matrix)
+
+    # Example
+    ```
+    use slal::linear::Norm;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, -2], &[-3, 4]]).unwrap();
+
+    assert!(m.norm_1() == Ok(6.));
+    ```
+     */
+    fn norm_1(&self) -> Self::Output;
+
+    /**
+    Computes the 2-norm (Euclidean norm for vertex, spectral norm for matrix)
+
+    # Example
+    ```
+    use slal::linear::Norm;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[3, 0], &[0, -4]]).unwrap();
+
+    assert!(m.norm_2() == Ok(4.));
+    ```
+     */
+    fn norm_2(&self) -> Self::Output;
+
+    /**
+    Computes the infinity norm (maximum absolute value for vertex, maximum absolute row sum for matrix)
+
+    # Example
+    ```
+    use slal::linear::Norm;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, -2], &[-3, 4]]).unwrap();
+
+    assert!(m.norm_inf() == Ok(7.));
+    ```
+     */
+    fn norm_inf(&self) -> Self::Output;
+
+    /**
+    Computes the Frobenius norm (square root of sum of squared elements)
+
+    # Example
+    ```
+    use slal::linear::Norm;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, -2], &[-2, 4]]).unwrap();
+
+    assert!(m.norm_fro() == Ok(5.));
+    ```
+     */
+    fn norm_fro(&self) -> Self::Output;
+
+    /**
+    Computes the max norm (maximum absolute value of elements)
+
+    # Example
+    ```
+    use slal::linear::Norm;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, -2], &[-3, 2]]).unwrap();
+
+    assert!(m.norm_max() == Ok(3.));
+    ```
+     */
+    fn norm_max(&self) -> Self::Output;
+}
+
+/**
+    Calculation of p-norm for vertex
+*/
+pub trait PNorm {
+    type Output;
+
+    /**
+    Computes the p-norm `(sum |v_i|^p)^(1/p)` for `p >= 1`.
+    `p = f64::INFINITY` computes the maximum absolute value.
+    Returns `InvalidNormOrder` if `p` is below 1 or NaN.
+
+    # Example
+    ```
+    use slal::linear::PNorm;
+    use slal::vertex::Vertex;
+
+    let v = Vertex::<i32>::new(&[3, -4]);
+
+    assert!(v.norm_p(1.) == Ok(7.));
+    assert!(v.norm_p(2.) == Ok(5.));
+    assert!(v.norm_p(f64::INFINITY) == Ok(4.));
+    assert!(v.norm_p(0.5).is_err());
+    ```
+     */
+    fn norm_p(&self, p: f64) -> Self::Output;
+}
+
 /**
     Checks if matrix is triangular matrix
 */
@@ -200,7 +356,8 @@ pub trait Determinant<T>: TriangularMatrix {
 
 pub trait Inverse<T>: Cofactor<T> {
     /**
-    Calculates the inverse of a matrix
+    Calculates the inverse of a matrix.
+    Check `Condition::rcond` first, the inverse of an ill-conditioned matrix is inaccurate.
 
     # Example
     ```
@@ -214,6 +371,43 @@ pub trait Inverse<T>: Cofactor<T> {
     fn inverse(&self) -> crate::error::SlalErr<Self::Output, T>;
 }
 
+pub trait Condition<T> {
+    /**
+    Computes the condition number of a matrix in the 2-norm (ratio of largest to smallest singular value).
+    Returns infinity for rank-deficient matrices.
+
+    # Example
+    ```
+    use slal::linear::Condition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[2, 0], &[0, 8]]).unwrap();
+
+    assert!((m.cond().unwrap() - 4.).abs() < 1e-10);
+    ```
+     */
+    fn cond(&self) -> crate::error::SlalErr<f64, T>;
+
+    /**
+    Estimates the reciprocal condition number of a square matrix in the 1-norm from its LU decomposition.
+    Values close to 0 indicate an ill-conditioned matrix whose inverse is unreliable,
+    and 0 is returned for singular matrices.
+
+    # Example
+    ```
+    use slal::linear::Condition;
+    use slal::matrix::Matrix;
+
+    let well = Matrix::<f64>::new(&[&[2., 1.], &[1., 3.]]).unwrap();
+    let ill = Matrix::<f64>::new(&[&[1., 1.], &[1., 1. + 1e-12]]).unwrap();
+
+    assert!(well.rcond().unwrap() > 0.1);
+    assert!(ill.rcond().unwrap() < 1e-10);
+    ```
+     */
+    fn rcond(&self) -> crate::error::SlalErr<f64, T>;
+}
+
 pub trait Cofactor<T> {
     type Output;
 
@@ -232,6 +426,546 @@ pub trait Cofactor<T> {
     fn cofactor(&self) -> crate::error::SlalErr<Self::Output, T>;
 }
 
+pub trait LuDecomposition<T> {
+    /**
+    Computes the LU decomposition with partial pivoting (PA = LU) of a square matrix
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[0, 1], &[1, 0]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert!(lu.p() == &[1, 0]);
+    assert!(lu.sign() == -1.);
+    assert!(lu.det() == -1.);
+    ```
+     */
+    fn lu(&self) -> crate::error::SlalErr<crate::matrix::Lu, T>;
+}
+
+pub trait QrDecomposition<T> {
+    /**
+    Computes the full QR decomposition (A = QR) of a matrix with size (n, m)
+    with Householder reflections.
+    `Q` is an orthogonal matrix with size (m, m) and `R` is an upper triangular matrix with size (n, m).
+
+    # Example
+    ```
+    use slal::linear::QrDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 2], &[3, 4], &[5, 6]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert!(qr.q().size() == (3, 3));
+    assert!(qr.r().size() == (2, 3));
+    ```
+     */
+    fn qr(&self) -> crate::error::SlalErr<crate::matrix::Qr, T>;
+
+    /**
+    Computes the thin QR decomposition (A = QR) of a matrix with size (n, m)
+    with Householder reflections.
+    With k = min(n, m), `Q` has orthonormal columns with size (k, m) and `R` is an upper triangular
+    matrix with size (n, k).
+
+    # Example
+    ```
+    use slal::linear::QrDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 2], &[3, 4], &[5, 6]]).unwrap();
+    let qr = m.qr_thin().unwrap();
+
+    assert!(qr.q().size() == (2, 3));
+    assert!(qr.r().size() == (2, 2));
+    ```
+     */
+    fn qr_thin(&self) -> crate::error::SlalErr<crate::matrix::Qr, T>;
+}
+
+pub trait CholeskyDecomposition<T> {
+    /**
+    Computes the Cholesky decomposition (A = LL^T) of a symmetric positive-definite matrix
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, 5]]).unwrap();
+    let cholesky = m.cholesky().unwrap();
+
+    assert!(cholesky.l() == &Matrix::new(&[&[2., 0.], &[1., 2.]]).unwrap());
+    ```
+     */
+    fn cholesky(&self) -> crate::error::SlalErr<crate::matrix::Cholesky, T>;
+
+    /**
+    Computes the LDL^T decomposition of a symmetric matrix,
+    where `L` is a unit lower triangular matrix and `D` is a diagonal matrix.
+    Rows are not pivoted, so a zero pivot returns `SingularMatrix`
+    even for some non-singular matrices such as `[[0, 1], [1, 0]]`.
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[4, 2], &[2, -1]]).unwrap();
+    let ldlt = m.ldlt().unwrap();
+
+    assert!(ldlt.l() == &Matrix::new(&[&[1., 0.], &[0.5, 1.]]).unwrap());
+    assert!(ldlt.d() == &Vertex::new(&[4., -2.]));
+    ```
+     */
+    fn ldlt(&self) -> crate::error::SlalErr<crate::matrix::Ldlt, T>;
+}
+
+pub trait SingularValueDecomposition<T> {
+    /**
+    Computes the thin singular value decomposition (A = U diag(s) V^T) of a matrix with size (n, m).
+    With k = min(n, m), `U` has size (k, m), singular values `s` are sorted in descending order
+    and `V^T` has size (n, k).
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 2, 2], &[2, 3, -2]]).unwrap();
+    let svd = m.svd().unwrap();
+
+    assert!(svd.u().size() == (2, 2));
+    assert!(svd.v_t().size() == (3, 2));
+    assert!((svd.s()[0] - 5.).abs() < 1e-10);
+    assert!((svd.s()[1] - 3.).abs() < 1e-10);
+    ```
+     */
+    fn svd(&self) -> crate::error::SlalErr<crate::matrix::Svd, T>;
+
+    /**
+    Computes only the singular values of a matrix in descending order
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 2, 2], &[2, 3, -2]]).unwrap();
+    let s = m.singular_values().unwrap();
+
+    assert!((s[0] - 5.).abs() < 1e-10);
+    assert!((s[1] - 3.).abs() < 1e-10);
+    ```
+     */
+    fn singular_values(&self) -> crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+}
+
+pub trait EigenDecomposition<T> {
+    /**
+    Computes all eigenvalues and eigenvectors of a square matrix
+    through Hessenberg reduction and the shifted QR algorithm.
+    Complex eigenvalues and eigenvectors are returned as their real and imaginary parts.
+    A defective matrix such as `[[1, 1], [0, 1]]` has fewer independent eigenvectors than its size,
+    so its computed eigenvectors are numerically parallel and the eigenvector matrix is close to singular.
+    Check `Condition::rcond` of the eigenvectors before inverting them.
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 0, 0], &[0, 1, -1], &[0, 1, 1]]).unwrap();
+    let eig = m.eig().unwrap();
+
+    // Eigenvalues: 2, 1 + i, 1 - i
+    assert!((eig.values_re()[0] - 2.).abs() < 1e-10);
+    assert!(eig.values_im()[0] == 0.);
+    assert!((eig.values_re()[1] - 1.).abs() < 1e-10);
+    assert!((eig.values_im()[1] - 1.).abs() < 1e-10);
+    assert!((eig.values_re()[2] - 1.).abs() < 1e-10);
+    assert!((eig.values_im()[2] + 1.).abs() < 1e-10);
+    ```
+     */
+    fn eig(&self) -> crate::error::SlalErr<crate::matrix::Eig, T>;
+
+    /**
+    Computes only the eigenvalues of a square matrix as a pair of real and imaginary parts
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+    let (re, im) = m.eigenvalues().unwrap();
+
+    assert!(re == Vertex::new(&[0., 0.]));
+    assert!(im == Vertex::new(&[1., -1.]));
+    ```
+     */
+    fn eigenvalues(
+        &self,
+    ) -> crate::error::SlalErr<(crate::vertex::Vertex<f64>, crate::vertex::Vertex<f64>), T>;
+}
+
+pub trait SymmetricEigen<T> {
+    /**
+    Computes the eigendecomposition of a symmetric matrix
+    through tridiagonalization and the implicit QL algorithm.
+    Eigenvalues are real and sorted in ascending order, eigenvectors are orthonormal.
+
+    # Example
+    ```
+    use slal::linear::SymmetricEigen;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[1, 2]]).unwrap();
+    let eig = m.symmetric_eigen().unwrap();
+
+    assert!((eig.values()[0] - 1.).abs() < 1e-10);
+    assert!((eig.values()[1] - 3.).abs() < 1e-10);
+    ```
+     */
+    fn symmetric_eigen(&self) -> crate::error::SlalErr<crate::matrix::SymmetricEig, T>;
+}
+
+pub trait MatrixFunction<T> {
+    /**
+    Computes the matrix exponential `e^A` with the scaling and squaring method
+    using Padé approximants of degree 3 to 13
+
+    # Example
+    ```
+    use slal::linear::MatrixFunction;
+    use slal::matrix::Matrix;
+
+    // Rotation by 1 radian is the exponential of the rotation generator
+    let m = Matrix::new(&[&[0., -1.], &[1., 0.]]).unwrap();
+    let e = m.expm().unwrap();
+
+    assert!((e[0][0] - 1f64.cos()).abs() < 1e-14 && (e[0][1] + 1f64.sin()).abs() < 1e-14);
+    assert!((e[1][0] - 1f64.sin()).abs() < 1e-14 && (e[1][1] - 1f64.cos()).abs() < 1e-14);
+    ```
+     */
+    fn expm(&self) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+
+    /**
+    Computes the principal matrix logarithm with the inverse scaling and squaring method on the real Schur form.
+    Fails if the matrix has eigenvalues on the closed negative real axis,
+    in which case no real principal logarithm exists.
+
+    # Example
+    ```
+    use slal::linear::MatrixFunction;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1., 1.], &[0., 1.]]).unwrap();
+    let l = m.logm().unwrap();
+
+    assert!(l[0][0].abs() < 1e-14 && (l[0][1] - 1.).abs() < 1e-14);
+    assert!(l[1][0].abs() < 1e-14 && l[1][1].abs() < 1e-14);
+    assert!(Matrix::new(&[&[-1., 0.], &[0., 1.]]).unwrap().logm().is_err());
+    ```
+     */
+    fn logm(&self) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+
+    /**
+    Computes the principal matrix square root with the real Schur method.
+    Fails if the matrix has negative real eigenvalues or a repeated zero eigenvalue without a square root,
+    in which case no real principal square root exists.
+
+    # Example
+    ```
+    use slal::linear::MatrixFunction;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 0], &[0, 9]]).unwrap();
+
+    assert!(m.sqrtm().unwrap() == Matrix::new(&[&[2., 0.], &[0., 3.]]).unwrap());
+    assert!(Matrix::new(&[&[-4, 0], &[0, 9]]).unwrap().sqrtm().is_err());
+    ```
+     */
+    fn sqrtm(&self) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+
+    /**
+    Raises a diagonalizable matrix to a real power through its eigendecomposition (A^p = V D^p V^-1)
+    with principal powers of the eigenvalues.
+    Fails if the matrix is not diagonalizable, if it has negative real eigenvalues for a non-integer power
+    or if it is singular for a negative power.
+    A matrix whose eigenvectors have a reciprocal condition number below the square root of machine epsilon,
+    such as the defective `[[1, 1], [0, 1]]`, is treated as not diagonalizable even for integer powers.
+
+    # Example
+    ```
+    use slal::linear::MatrixFunction;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2., 1.], &[0., 3.]]).unwrap();
+    let p = m.powf(0.5).unwrap();
+
+    // Square of the result is the original matrix
+    assert!((p[0][0].powi(2) - 2.).abs() < 1e-12);
+    assert!((p[0][0] * p[0][1] + p[0][1] * p[1][1] - 1.).abs() < 1e-12);
+    assert!((p[1][1].powi(2) - 3.).abs() < 1e-12);
+    ```
+     */
+    fn powf(&self, p: f64) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+}
+
+pub trait Solve<T> {
+    type Output;
+
+    /**
+    Solves the linear system `Ax = b` for `x` through LU decomposition with partial pivoting
+
+    # Example
+    ```
+    use slal::linear::Solve;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    // 2x + y = 3
+    // x + 3y = 5
+    let a = Matrix::new(&[&[2, 1], &[1, 3]]).unwrap();
+    let b = Vertex::new_transposed(&[3, 5]);
+
+    assert!(a.solve(&b) == Ok(Vertex::new_transposed(&[0.8, 1.4])));
+    ```
+     */
+    fn solve(&self, b: &T) -> Self::Output;
+}
+
+pub trait PseudoInverse<T> {
+    /**
+    Computes the Moore-Penrose pseudo-inverse of a matrix through singular value decomposition.
+    Singular values not above `tol` are treated as 0.
+
+    # Example
+    ```
+    use slal::linear::PseudoInverse;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 0], &[0, 1], &[0, 0]]).unwrap();
+
+    assert!(m.pinv(1e-10) == Ok(Matrix::new(&[&[1., 0., 0.], &[0., 1., 0.]]).unwrap()));
+    ```
+     */
+    fn pinv(&self, tol: f64) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+}
+
+pub trait LeastSquares<T> {
+    type Output;
+
+    /**
+    Solves the linear system `Ax = b` in the least squares sense.
+    Returns the minimum-norm solution, the residual and the effective rank of `A`.
+    Works for over- and under-determined systems and rank-deficient matrices.
+
+    # Example
+    ```
+    use slal::linear::LeastSquares;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    // Fit y = c0 + c1 * x to points (0, 1), (1, 3), (2, 5)
+    let a = Matrix::<i32>::new(&[&[1, 0], &[1, 1], &[1, 2]]).unwrap();
+    let b = Vertex::new_transposed(&[1, 3, 5]);
+    let lstsq = a.lstsq(&b).unwrap();
+
+    assert!((lstsq.x()[0] - 1.).abs() < 1e-10);
+    assert!((lstsq.x()[1] - 2.).abs() < 1e-10);
+    assert!(lstsq.residual() < 1e-10);
+    assert!(lstsq.rank() == 2);
+    ```
+     */
+    fn lstsq(&self, b: &T) -> Self::Output;
+}
+
+pub trait Rank<T> {
+    /**
+    Computes the rank of a matrix as the number of singular values above `tol`
+
+    # Example
+    ```
+    use slal::linear::Rank;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]).unwrap();
+
+    assert!(m.rank(1e-10) == Ok(2));
+    ```
+     */
+    fn rank(&self, tol: f64) -> crate::error::SlalErr<usize, T>;
+
+    /**
+    Computes an orthonormal basis of the null space (kernel) of a matrix.
+    Basis vectors are columns of the returned matrix and singular values not above `tol` are treated as 0.
+    Returns an empty matrix if the null space is trivial.
+
+    # Example
+    ```
+    use slal::linear::Rank;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, 1], &[1, 1]]).unwrap();
+    let null_space = m.null_space(1e-10).unwrap();
+
+    assert!(null_space.size() == (1, 2));
+    assert!((null_space[0][0] + null_space[1][0]).abs() < 1e-10);
+    ```
+     */
+    fn null_space(&self, tol: f64) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+
+    /**
+    Computes an orthonormal basis of the column space (range) of a matrix.
+    Basis vectors are columns of the returned matrix and singular values not above `tol` are treated as 0.
+    Returns an empty matrix if the matrix is zero.
+
+    # Example
+    ```
+    use slal::linear::Rank;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[1, 2], &[0, 0]]).unwrap();
+    let column_space = m.column_space(1e-10).unwrap();
+
+    assert!(column_space.size() == (1, 2));
+    assert!((column_space[0][0].abs() - 1.).abs() < 1e-10);
+    ```
+     */
+    fn column_space(&self, tol: f64) -> crate::error::SlalErr<crate::matrix::Matrix<f64>, T>;
+}
+
+pub trait ReducedRowEchelon<T> {
+    type Output;
+
+    /**
+    Computes the reduced row echelon form of a matrix with exact arithmetic
+    and returns it with the indices of the pivot columns.
+    Integer matrices return entries as `Rational<i128>`, and rational matrices keep their element type.
+    Returns `SlalError::ArithmeticOverflow` instead of a wrong value when an intermediate value overflows.
+
+    # Example
+    ```
+    use slal::linear::ReducedRowEchelon;
+    use slal::matrix::Matrix;
+    use slal::rational::Rational;
+
+    let m = Matrix::<i32>::new(&[&[3, 1, 3], &[6, 2, 7]]).unwrap();
+    let (rref, pivots) = m.rref().unwrap();
+
+    assert!(rref[0][1] == Rational::new(1, 3));
+    assert!(rref[1] == [Rational::from(0), Rational::from(0), Rational::from(1)]);
+    assert!(pivots == vec![0, 2]);
+    ```
+     */
+    fn rref(&self) -> Self::Output;
+}
+
+pub trait ExactDeterminant<T> {
+    type Output;
+
+    /**
+    Calculates the determinant of a matrix with size (n, n) without rounding.
+    Integer matrices use fraction-free (Bareiss) elimination, so every intermediate value is an integer.
+    Returns `SlalError::ArithmeticOverflow` instead of a wrong value when an intermediate value overflows.
+
+    # Example
+    ```
+    use slal::linear::ExactDeterminant;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i32>::new(&[&[2, 0, 1], &[1, 3, 2], &[1, 1, 2]]).unwrap();
+
+    assert!(m.det_exact() == Ok(6));
+    ```
+     */
+    fn det_exact(&self) -> Self::Output;
+}
+
+pub trait ExactInverse<T> {
+    type Output;
+
+    /**
+    Calculates the inverse of a matrix as rational numbers without rounding.
+    Returns `SlalError::ArithmeticOverflow` instead of a wrong value when an intermediate value overflows.
+
+    # Example
+    ```
+    use slal::linear::ExactInverse;
+    use slal::matrix::Matrix;
+    use slal::rational::Rational;
+
+    let m = Matrix::<i32>::new(&[&[3, 0], &[0, 2]]).unwrap();
+    let inv = m.inverse_exact().unwrap();
+
+    assert!(inv[0][0] == Rational::new(1, 3) && inv[1][1] == Rational::new(1, 2));
+    ```
+     */
+    fn inverse_exact(&self) -> Self::Output;
+}
+
+/**
+    Linear map that only exposes its matrix-vector product.
+    Used by iterative solvers so they work on any operator type.
+*/
+pub trait LinearOperator {
+    /**
+    Returns the number of rows of the operator (length of `y` in `y = Ax`)
+
+    # Example
+    ```
+    use slal::linear::LinearOperator;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<f64>::new(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+
+    assert!(m.rows() == 2);
+    ```
+     */
+    fn rows(&self) -> usize;
+
+    /**
+    Returns the number of columns of the operator (length of `x` in `y = Ax`)
+
+    # Example
+    ```
+    use slal::linear::LinearOperator;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<f64>::new(&[&[1., 2., 3.], &[4., 5., 6.]]).unwrap();
+
+    assert!(m.columns() == 3);
+    ```
+     */
+    fn columns(&self) -> usize;
+
+    /**
+    Computes the matrix-vector product `y = Ax`
+
+    # Example
+    ```
+    use slal::linear::LinearOperator;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<f64>::new(&[&[1., 2.], &[3., 4.]]).unwrap();
+    let mut y = vec![0.; 2];
+
+    m.apply(&[1., 1.], &mut y);
+
+    assert!(y == vec![3., 7.]);
+    ```
+     */
+    fn apply(&self, x: &[f64], y: &mut [f64]);
+}
+
 pub trait Random {
     type Output;
     type Size;
@@ -282,16 +1016,17 @@ pub trait Normalize {
 
 // Untestable trait
 // Can only be tested wether the result is error or not
+// Use EigenDecomposition or SymmetricEigen for deterministic computation of all eigenpairs
 pub trait Eigen {
     type Output;
 
     /**
-    Computes eigenvector of matrix
+    Computes the dominant eigenvector of matrix with power iteration from a random start
 
     # Example
     ```
     use slal::linear::Eigen;
-    use slal::linear::Matrix;
+    use slal::matrix::Matrix;
 
     let m = Matrix::new(&[&[1, 2], &[4, 9]]).unwrap();
     let _ = m.eigen().unwrap();
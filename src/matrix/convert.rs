@@ -3,9 +3,8 @@ macro_rules! impl_from {
         impl From<super::Matrix<$Small>> for super::Matrix<$Large> {
             #[inline(always)]
             fn from(other: super::Matrix<$Small>) -> Self {
-                use rayon::prelude::*;
-
-                let m: Vec<$Large> = other.m.par_iter().map(|m_ij| *m_ij as $Large).collect();
+                let m: Vec<$Large> =
+                    crate::parallel::map_collect(other.m.len(), |idx| other.m[idx] as $Large);
 
                 super::Matrix::<$Large> {
                     m,
@@ -0,0 +1,1492 @@
+// Ignore anything below this value and treat it as 0.0
+const DELTA: f64 = 1e-10;
+
+/**
+    Checks that vertex `b` is a right-hand side of a linear system with size (n, n)
+*/
+fn check_vertex<F>(n: usize, b: &crate::vertex::Vertex<f64>, a: F) -> crate::error::SlalErr<(), f64>
+where
+    F: Fn() -> super::Matrix<f64>,
+{
+    use crate::error::SlalError;
+
+    if !b.is_transposed() {
+        return Err(SlalError::VertexStateError(format!(
+            "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+            a(),
+            *b,
+        )));
+    } else if b.len() != n {
+        return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+            format!("{:?}", *b),
+            format!("{:?}", a()),
+            String::from("while solving linear system"),
+        ));
+    }
+
+    Ok(())
+}
+
+/**
+    Checks that matrix `b` is a right-hand side of a linear system with size (n, n)
+*/
+fn check_matrix<F>(n: usize, b: &super::Matrix<f64>, a: F) -> crate::error::SlalErr<(), f64>
+where
+    F: Fn() -> super::Matrix<f64>,
+{
+    use crate::error::SlalError;
+
+    if b.size[1] != n {
+        return Err(SlalError::UnmatchingMatrixSize(
+            format!("{:?}", a()),
+            format!("{:?}", *b),
+        ));
+    }
+
+    Ok(())
+}
+
+/**
+    Applies `substitute` to every column of `b` in parallel
+*/
+fn substitute_columns<F>(b: &super::Matrix<f64>, substitute: F) -> super::Matrix<f64>
+where
+    F: Fn(&mut [f64]) + Sync,
+{
+    let [width, n] = b.size;
+    let columns: Vec<Vec<f64>> = crate::parallel::map_collect(width, |i| {
+        let mut column: Vec<f64> = (0..n).map(|j| b[j][i]).collect();
+
+        substitute(&mut column);
+
+        column
+    });
+
+    let mut m = vec![0.; width * n];
+    crate::parallel::for_each_mut(&mut m, |idx, m_ji| {
+        *m_ji = columns[idx % width][idx / width];
+    });
+
+    super::Matrix {
+        m,
+        size: [width, n],
+    }
+}
+
+/**
+    LU decomposition with partial pivoting of a square matrix.
+
+    Holds the unit lower triangular matrix `L`, the upper triangular matrix `U`
+    and the row permutation `P` so that `PA = LU`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    l: super::Matrix<f64>,
+    u: super::Matrix<f64>,
+    p: Vec<usize>,
+    sign: f64,
+}
+
+impl Lu {
+    /**
+    Returns the unit lower triangular matrix `L`
+
+    # Example
+    ```
+    use slal::linear::{LuDecomposition, TriangularMatrix};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[4, 3]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert!(lu.l().is_lower_triangular());
+    ```
+     */
+    pub fn l(&self) -> &super::Matrix<f64> {
+        &self.l
+    }
+
+    /**
+    Returns the upper triangular matrix `U`
+
+    # Example
+    ```
+    use slal::linear::{LuDecomposition, TriangularMatrix};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[4, 3]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert!(lu.u().is_upper_triangular());
+    ```
+     */
+    pub fn u(&self) -> &super::Matrix<f64> {
+        &self.u
+    }
+
+    /**
+    Returns the row permutation of the decomposition.
+    Row `i` of `PA` is row `p()[i]` of the original matrix.
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[4, 3]]).unwrap();
+
+    assert!(m.lu().unwrap().p() == &[1, 0]);
+    ```
+     */
+    pub fn p(&self) -> &[usize] {
+        &self.p
+    }
+
+    /**
+    Returns the sign of the row permutation (1.0 or -1.0)
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[4, 3]]).unwrap();
+
+    assert!(m.lu().unwrap().sign() == -1.);
+    ```
+     */
+    pub fn sign(&self) -> f64 {
+        self.sign
+    }
+
+    /**
+    Returns the row permutation as a permutation matrix `P`
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 1], &[4, 3]]).unwrap();
+
+    assert!(m.lu().unwrap().permutation() == Matrix::new(&[&[0., 1.], &[1., 0.]]).unwrap());
+    ```
+     */
+    pub fn permutation(&self) -> super::Matrix<f64> {
+        let n = self.p.len();
+        let mut m = vec![0.; n * n];
+        self.p
+            .iter()
+            .enumerate()
+            .for_each(|(j, p_j)| m[j * n + p_j] = 1.);
+
+        super::Matrix { m, size: [n, n] }
+    }
+
+    /**
+    Checks if the decomposed matrix is singular
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 2], &[2, 4]]).unwrap();
+
+    assert!(m.lu().unwrap().is_singular());
+    ```
+     */
+    pub fn is_singular(&self) -> bool {
+        (0..self.p.len()).any(|ij| self.u[ij][ij] == 0.)
+    }
+
+    /**
+    Calculates the determinant of the decomposed matrix without recomputing the decomposition
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 2], &[3, 4]]).unwrap();
+
+    assert!(m.lu().unwrap().det() == -2.);
+    ```
+     */
+    pub fn det(&self) -> f64 {
+        (0..self.p.len()).fold(self.sign, |det, ij| det * self.u[ij][ij])
+    }
+
+    /**
+    Calculates the inverse of the decomposed matrix without recomputing the decomposition
+
+    # Example
+    ```
+    use slal::linear::LuDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[0, 1], &[1, 0]]).unwrap();
+
+    assert!(m.lu().unwrap().inverse() == Ok(Matrix::new(&[&[0., 1.], &[1., 0.]]).unwrap()));
+    ```
+     */
+    pub fn inverse(&self) -> crate::error::SlalErr<super::Matrix<f64>, f64> {
+        use crate::error::SlalError;
+
+        if self.is_singular() {
+            return Err(SlalError::DeterminantZero(self.reconstruct()));
+        }
+
+        let n = self.p.len();
+        let mut identity = vec![0.; n * n];
+        (0..n).for_each(|ij| identity[ij * n + ij] = 1.);
+
+        Ok(self.substitute_matrix(&super::Matrix {
+            m: identity,
+            size: [n, n],
+        }))
+    }
+
+    /**
+    Solves `LUx = Pb` in place with forward and back substitution.
+    Assumes the decomposed matrix is not singular.
+     */
+    pub(crate) fn substitute(&self, b: &mut [f64]) {
+        let n = self.p.len();
+        let pb: Vec<f64> = self.p.iter().map(|p_j| b[*p_j]).collect();
+
+        b.copy_from_slice(&pb);
+
+        // Forward substitution (L has ones on its diagonal)
+        for j in 1..n {
+            b[j] -= (0..j).map(|i| self.l[j][i] * b[i]).sum::<f64>();
+        }
+
+        // Back substitution
+        for j in (0..n).rev() {
+            b[j] = (b[j] - ((j + 1)..n).map(|i| self.u[j][i] * b[i]).sum::<f64>()) / self.u[j][j];
+        }
+    }
+
+    /**
+    Solves `A^Tx = b` in place, where `A^T = U^TL^TP`.
+    Assumes the decomposed matrix is not singular.
+     */
+    pub(crate) fn substitute_transposed(&self, b: &mut [f64]) {
+        let n = self.p.len();
+
+        // Forward substitution with U^T
+        for j in 0..n {
+            b[j] = (b[j] - (0..j).map(|i| self.u[i][j] * b[i]).sum::<f64>()) / self.u[j][j];
+        }
+
+        // Back substitution with L^T (ones on its diagonal)
+        for j in (0..n).rev() {
+            b[j] -= ((j + 1)..n).map(|i| self.l[i][j] * b[i]).sum::<f64>();
+        }
+
+        let mut x = vec![0.; n];
+        self.p
+            .iter()
+            .zip(b.iter())
+            .for_each(|(p_j, b_j)| x[*p_j] = *b_j);
+
+        b.copy_from_slice(&x);
+    }
+
+    /**
+    Solves `LUX = PB` for every column of `B`.
+    Assumes the decomposed matrix is not singular.
+     */
+    pub(crate) fn substitute_matrix(&self, b: &super::Matrix<f64>) -> super::Matrix<f64> {
+        substitute_columns(b, |column| self.substitute(column))
+    }
+
+    /**
+    Rebuilds the decomposed matrix from `P^T LU`
+     */
+    pub(crate) fn reconstruct(&self) -> super::Matrix<f64> {
+        let n = self.p.len();
+        let mut m = vec![0.; n * n];
+        for j in 0..n {
+            for i in 0..n {
+                m[self.p[j] * n + i] = (0..=j.min(i)).map(|k| self.l[j][k] * self.u[k][i]).sum();
+            }
+        }
+
+        super::Matrix { m, size: [n, n] }
+    }
+}
+
+/**
+    Returns the tolerance below which a pivot of an n x n matrix is treated as zero,
+    given the largest modulus of its entries.
+    The tolerance is relative to the largest entry, so it does not depend on the scale of the matrix.
+*/
+pub(crate) fn pivot_tolerance(n: usize, max: f64) -> f64 {
+    n as f64 * f64::EPSILON * max
+}
+
+/**
+    Eliminates an n x n matrix in row-major order in place with partial pivoting,
+    leaving the multipliers of `L` below the diagonal and `U` on and above it.
+    Entries are compared by `modulus`, and pivots below `pivot_tolerance` are set to zero,
+    so the matrix is singular if any diagonal entry of `U` is zero.
+    Returns the row permutation and its sign.
+*/
+pub(crate) fn eliminate<T, F>(a: &mut [T], n: usize, modulus: F) -> (Vec<usize>, f64)
+where
+    T: crate::scalar::Scalar,
+    F: Fn(T) -> f64,
+{
+    let mut p: Vec<usize> = (0..n).collect();
+    let mut sign = 1.;
+    let tol = pivot_tolerance(
+        n,
+        a.iter().fold(0., |max: f64, a_ji| max.max(modulus(*a_ji))),
+    );
+
+    for k in 0..n {
+        // Pick the row with the largest modulus in column k as pivot
+        let pivot = (k..n)
+            .max_by(|&x, &y| modulus(a[x * n + k]).total_cmp(&modulus(a[y * n + k])))
+            .unwrap_or(k);
+
+        if pivot != k {
+            for i in 0..n {
+                a.swap(k * n + i, pivot * n + i);
+            }
+            p.swap(k, pivot);
+            sign = -sign;
+        }
+
+        // Column is already eliminated, so the matrix is singular
+        if modulus(a[k * n + k]) <= tol {
+            a[k * n + k] = T::zero();
+
+            continue;
+        }
+
+        for j in (k + 1)..n {
+            let l_jk = a[j * n + k] / a[k * n + k];
+
+            a[j * n + k] = l_jk;
+            for i in (k + 1)..n {
+                let u_ki = a[k * n + i];
+
+                a[j * n + i] -= l_jk * u_ki;
+            }
+        }
+    }
+
+    (p, sign)
+}
+
+/**
+    Computes the LU decomposition of a square matrix with partial pivoting.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn lu(m: &super::Matrix<f64>) -> Lu {
+    let n = m.size[0];
+    let mut a = m.clone();
+    let (p, sign) = eliminate(&mut a.m, n, f64::abs);
+
+    let mut l = vec![0.; n * n];
+    let mut u = vec![0.; n * n];
+    for j in 0..n {
+        for i in 0..n {
+            if i < j {
+                l[j * n + i] = a[j][i];
+            } else {
+                u[j * n + i] = a[j][i];
+            }
+        }
+        l[j * n + j] = 1.;
+    }
+
+    Lu {
+        l: super::Matrix { m: l, size: [n, n] },
+        u: super::Matrix { m: u, size: [n, n] },
+        p,
+        sign,
+    }
+}
+
+impl<T> crate::linear::LuDecomposition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn lu(&self) -> crate::error::SlalErr<Lu, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
+
+        Ok(lu(&self.to_f64()))
+    }
+}
+
+impl crate::linear::Solve<crate::vertex::Vertex<f64>> for Lu {
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, f64>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<f64>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        check_vertex(self.p.len(), b, || self.reconstruct())?;
+
+        if self.is_singular() {
+            return Err(SlalError::SingularMatrix(format!(
+                "{:?}",
+                self.reconstruct()
+            )));
+        }
+
+        let mut x = b.to_vec();
+
+        self.substitute(&mut x);
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl crate::linear::Solve<super::Matrix<f64>> for Lu {
+    type Output = crate::error::SlalErr<super::Matrix<f64>, f64>;
+
+    fn solve(&self, b: &super::Matrix<f64>) -> Self::Output {
+        use crate::error::SlalError;
+
+        check_matrix(self.p.len(), b, || self.reconstruct())?;
+
+        if self.is_singular() {
+            return Err(SlalError::SingularMatrix(format!(
+                "{:?}",
+                self.reconstruct()
+            )));
+        }
+
+        Ok(self.substitute_matrix(b))
+    }
+}
+
+/**
+    QR decomposition of a matrix.
+
+    Holds the matrix `Q` with orthonormal columns and the upper triangular matrix `R`
+    so that `A = QR`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Qr {
+    q: super::Matrix<f64>,
+    r: super::Matrix<f64>,
+}
+
+impl Qr {
+    /**
+    Returns the matrix `Q` with orthonormal columns
+
+    # Example
+    ```
+    use slal::linear::QrDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 1], &[4, 2]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert!(qr.q().size() == (2, 2));
+    ```
+     */
+    pub fn q(&self) -> &super::Matrix<f64> {
+        &self.q
+    }
+
+    /**
+    Returns the upper triangular matrix `R`
+
+    # Example
+    ```
+    use slal::linear::QrDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 1], &[4, 2]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert!(qr.r()[1][0] == 0.);
+    ```
+     */
+    pub fn r(&self) -> &super::Matrix<f64> {
+        &self.r
+    }
+}
+
+/**
+    Computes the QR decomposition of a matrix with Householder reflections.
+    Assumes the matrix is not empty.
+*/
+pub(crate) fn qr(a: &super::Matrix<f64>, thin: bool) -> Qr {
+    let [n, m] = a.size;
+    let mut r = a.clone();
+    let mut q = vec![0.; m * m];
+    (0..m).for_each(|ij| q[ij * m + ij] = 1.);
+    let tol = pivot_tolerance(m, a.m.iter().fold(0., |max: f64, a_ji| max.max(a_ji.abs())));
+
+    for k in 0..n.min(m - 1) {
+        // Householder vector reflecting column k below the diagonal onto e_k
+        let mut v: Vec<f64> = (k..m).map(|j| r[j][k]).collect();
+        let norm_x = v.iter().map(|v_j| v_j.powi(2)).sum::<f64>().sqrt();
+
+        // Column is already eliminated up to rounding relative to the matrix
+        if v[1..].iter().map(|v_j| v_j.powi(2)).sum::<f64>().sqrt() <= tol {
+            continue;
+        }
+
+        v[0] += if v[0] < 0. { -norm_x } else { norm_x };
+
+        let norm_v = v.iter().map(|v_j| v_j.powi(2)).sum::<f64>().sqrt();
+        v.iter_mut().for_each(|v_j| *v_j /= norm_v);
+
+        // R = (I - 2vv^T)R
+        let w: Vec<f64> =
+            crate::parallel::map_collect(n - k, |i| (k..m).map(|j| v[j - k] * r[j][i + k]).sum());
+        crate::parallel::for_each_chunk_mut(&mut r.m[k * n..], n, |j, r_j| {
+            (k..n).for_each(|i| r_j[i] -= 2. * v[j] * w[i - k]);
+        });
+
+        // Q = Q(I - 2vv^T)
+        crate::parallel::for_each_chunk_mut(&mut q, m, |_, q_j| {
+            let qv: f64 = (k..m).map(|i| q_j[i] * v[i - k]).sum();
+
+            (k..m).for_each(|i| q_j[i] -= 2. * qv * v[i - k]);
+        });
+    }
+
+    // Values below the diagonal are either eliminated by a reflection or below the tolerance
+    r.m.iter_mut().enumerate().for_each(|(idx, r_ji)| {
+        if idx % n < idx / n {
+            *r_ji = 0.;
+        }
+    });
+
+    if thin && m > n {
+        Qr {
+            q: super::Matrix {
+                m: q.chunks(m).flat_map(|q_j| q_j[..n].to_vec()).collect(),
+                size: [n, m],
+            },
+            r: super::Matrix {
+                m: r.m[..n * n].to_vec(),
+                size: [n, n],
+            },
+        }
+    } else {
+        Qr {
+            q: super::Matrix { m: q, size: [m, m] },
+            r,
+        }
+    }
+}
+
+impl<T> crate::linear::QrDecomposition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn qr(&self) -> crate::error::SlalErr<Qr, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(qr(&self.to_f64(), false))
+    }
+
+    fn qr_thin(&self) -> crate::error::SlalErr<Qr, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(qr(&self.to_f64(), true))
+    }
+}
+
+/**
+    Checks if a square matrix is symmetric within a tolerance relative to its largest entry
+*/
+pub(crate) fn is_symmetric(m: &super::Matrix<f64>) -> bool {
+    let n = m.size[0];
+    let tol = pivot_tolerance(n, m.m.iter().fold(0., |max: f64, m_ji| max.max(m_ji.abs())));
+
+    (0..n).all(|j| ((j + 1)..n).all(|i| (m[j][i] - m[i][j]).abs() <= tol))
+}
+
+/**
+    Solves `Lx = b` in place with forward substitution
+*/
+fn forward_substitute(l: &super::Matrix<f64>, b: &mut [f64], unit_diagonal: bool) {
+    for j in 0..b.len() {
+        b[j] -= (0..j).map(|i| l[j][i] * b[i]).sum::<f64>();
+        if !unit_diagonal {
+            b[j] /= l[j][j];
+        }
+    }
+}
+
+/**
+    Solves `L^Tx = b` in place with back substitution
+*/
+fn back_substitute_transposed(l: &super::Matrix<f64>, b: &mut [f64], unit_diagonal: bool) {
+    let n = b.len();
+    for j in (0..n).rev() {
+        b[j] -= ((j + 1)..n).map(|i| l[i][j] * b[i]).sum::<f64>();
+        if !unit_diagonal {
+            b[j] /= l[j][j];
+        }
+    }
+}
+
+/**
+    Cholesky decomposition of a symmetric positive-definite matrix.
+
+    Holds the lower triangular matrix `L` with positive diagonal so that `A = LL^T`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky {
+    l: super::Matrix<f64>,
+}
+
+impl Cholesky {
+    /**
+    Returns the lower triangular matrix `L`
+
+    # Example
+    ```
+    use slal::linear::{CholeskyDecomposition, TriangularMatrix};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, 5]]).unwrap();
+
+    assert!(m.cholesky().unwrap().l().is_lower_triangular());
+    ```
+     */
+    pub fn l(&self) -> &super::Matrix<f64> {
+        &self.l
+    }
+
+    /**
+    Calculates the determinant of the decomposed matrix without recomputing the decomposition
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, 5]]).unwrap();
+
+    assert!(m.cholesky().unwrap().det() == 16.);
+    ```
+     */
+    pub fn det(&self) -> f64 {
+        (0..self.l.size[0])
+            .map(|ij| self.l[ij][ij])
+            .product::<f64>()
+            .powi(2)
+    }
+
+    /**
+    Solves `LL^Tx = b` in place
+     */
+    pub(crate) fn substitute(&self, b: &mut [f64]) {
+        forward_substitute(&self.l, b, false);
+        back_substitute_transposed(&self.l, b, false);
+    }
+
+    /**
+    Rebuilds the decomposed matrix from `LL^T`
+     */
+    fn reconstruct(&self) -> super::Matrix<f64> {
+        let n = self.l.size[0];
+        let mut m = vec![0.; n * n];
+        for j in 0..n {
+            for i in 0..n {
+                m[j * n + i] = (0..=j.min(i)).map(|k| self.l[j][k] * self.l[i][k]).sum();
+            }
+        }
+
+        super::Matrix { m, size: [n, n] }
+    }
+}
+
+/**
+    Returns the tolerance below which a pivot of a symmetric matrix is treated as zero.
+    The tolerance is relative to the largest diagonal entry, so it does not depend on the scale of the matrix.
+*/
+fn diagonal_tolerance(a: &super::Matrix<f64>) -> f64 {
+    let n = a.size[0];
+    let max = (0..n).fold(0., |max: f64, jj| max.max(a[jj][jj].abs()));
+
+    n as f64 * f64::EPSILON * max
+}
+
+/**
+    Computes the Cholesky decomposition of a symmetric matrix.
+    Returns `None` if the matrix is not positive-definite.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn cholesky(a: &super::Matrix<f64>) -> Option<Cholesky> {
+    let n = a.size[0];
+    let mut l = super::Matrix::<f64> {
+        m: vec![0.; n * n],
+        size: [n, n],
+    };
+
+    let tol = diagonal_tolerance(a);
+
+    for j in 0..n {
+        let l_jj = a[j][j] - (0..j).map(|k| l[j][k].powi(2)).sum::<f64>();
+
+        if l_jj <= tol {
+            return None;
+        }
+
+        l[j][j] = l_jj.sqrt();
+
+        let l_j = l[j][..=j].to_vec();
+        crate::parallel::for_each_chunk_mut(&mut l.m[(j + 1) * n..], n, |i, l_i| {
+            l_i[j] = (a[j + 1 + i][j] - (0..j).map(|k| l_i[k] * l_j[k]).sum::<f64>()) / l_j[j];
+        });
+    }
+
+    Some(Cholesky { l })
+}
+
+/**
+    LDL^T decomposition of a symmetric matrix.
+
+    Holds the unit lower triangular matrix `L` and the diagonal of `D` so that `A = LDL^T`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ldlt {
+    l: super::Matrix<f64>,
+    d: crate::vertex::Vertex<f64>,
+}
+
+impl Ldlt {
+    /**
+    Returns the unit lower triangular matrix `L`
+
+    # Example
+    ```
+    use slal::linear::{CholeskyDecomposition, TriangularMatrix};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, 5]]).unwrap();
+
+    assert!(m.ldlt().unwrap().l().is_lower_triangular());
+    ```
+     */
+    pub fn l(&self) -> &super::Matrix<f64> {
+        &self.l
+    }
+
+    /**
+    Returns the diagonal of `D`
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[4, 2], &[2, 5]]).unwrap();
+
+    assert!(m.ldlt().unwrap().d() == &Vertex::new(&[4., 4.]));
+    ```
+     */
+    pub fn d(&self) -> &crate::vertex::Vertex<f64> {
+        &self.d
+    }
+
+    /**
+    Checks if the decomposed matrix is positive-definite
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, -1]]).unwrap();
+
+    assert!(!m.ldlt().unwrap().is_positive_definite());
+    ```
+     */
+    pub fn is_positive_definite(&self) -> bool {
+        (0..self.d.len()).all(|idx| self.d[idx] > 0.)
+    }
+
+    /**
+    Calculates the determinant of the decomposed matrix without recomputing the decomposition
+
+    # Example
+    ```
+    use slal::linear::CholeskyDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[4, 2], &[2, -1]]).unwrap();
+
+    assert!(m.ldlt().unwrap().det() == -8.);
+    ```
+     */
+    pub fn det(&self) -> f64 {
+        (0..self.d.len()).map(|idx| self.d[idx]).product()
+    }
+
+    /**
+    Solves `LDL^Tx = b` in place
+     */
+    pub(crate) fn substitute(&self, b: &mut [f64]) {
+        forward_substitute(&self.l, b, true);
+        b.iter_mut()
+            .enumerate()
+            .for_each(|(idx, b_i)| *b_i /= self.d[idx]);
+        back_substitute_transposed(&self.l, b, true);
+    }
+
+    /**
+    Rebuilds the decomposed matrix from `LDL^T`
+     */
+    fn reconstruct(&self) -> super::Matrix<f64> {
+        let n = self.l.size[0];
+        let mut m = vec![0.; n * n];
+        for j in 0..n {
+            for i in 0..n {
+                m[j * n + i] = (0..=j.min(i))
+                    .map(|k| self.l[j][k] * self.d[k] * self.l[i][k])
+                    .sum();
+            }
+        }
+
+        super::Matrix { m, size: [n, n] }
+    }
+}
+
+/**
+    Computes the LDL^T decomposition of a symmetric matrix without pivoting.
+    Returns `None` if a zero pivot is found.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn ldlt(a: &super::Matrix<f64>) -> Option<Ldlt> {
+    let n = a.size[0];
+    let mut l = super::Matrix::<f64> {
+        m: vec![0.; n * n],
+        size: [n, n],
+    };
+    let mut d = vec![0.; n];
+    let tol = diagonal_tolerance(a);
+
+    for j in 0..n {
+        d[j] = a[j][j] - (0..j).map(|k| l[j][k].powi(2) * d[k]).sum::<f64>();
+
+        if d[j].abs() <= tol {
+            return None;
+        }
+
+        l[j][j] = 1.;
+
+        let l_j = l[j][..j].to_vec();
+        crate::parallel::for_each_chunk_mut(&mut l.m[(j + 1) * n..], n, |i, l_i| {
+            l_i[j] = (a[j + 1 + i][j] - (0..j).map(|k| l_i[k] * l_j[k] * d[k]).sum::<f64>()) / d[j];
+        });
+    }
+
+    Some(Ldlt {
+        l,
+        d: crate::vertex::Vertex::new(d.as_slice()),
+    })
+}
+
+impl<T> crate::linear::CholeskyDecomposition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn cholesky(&self) -> crate::error::SlalErr<Cholesky, T> {
+        use crate::error::SlalError;
+
+        let m = symmetric_matrix(self)?;
+
+        match cholesky(&m) {
+            Some(cholesky) => Ok(cholesky),
+            None => Err(SlalError::NotPositiveDefinite(format!("{:?}", *self))),
+        }
+    }
+
+    fn ldlt(&self) -> crate::error::SlalErr<Ldlt, T> {
+        use crate::error::SlalError;
+
+        let m = symmetric_matrix(self)?;
+
+        match ldlt(&m) {
+            Some(ldlt) => Ok(ldlt),
+            None => Err(SlalError::SingularMatrix(format!("{:?}", *self))),
+        }
+    }
+}
+
+/**
+    Converts a matrix into `Matrix<f64>` after checking that it is a non-empty symmetric matrix
+*/
+pub(crate) fn symmetric_matrix<T>(
+    m: &super::Matrix<T>,
+) -> crate::error::SlalErr<super::Matrix<f64>, T>
+where
+    T: crate::scalar::RealScalar,
+{
+    use crate::error::SlalError;
+
+    if m.m.is_empty() {
+        return Err(SlalError::EmptyMatrix(format!("{:?}", *m)));
+    }
+
+    if m.size[0] != m.size[1] {
+        return Err(SlalError::NotSquareMatrix(
+            format!("{:?}", *m),
+            format!("{}", m.size[0]),
+            format!("{}", m.size[1]),
+        ));
+    }
+
+    let m_f64 = m.to_f64();
+
+    if !is_symmetric(&m_f64) {
+        return Err(SlalError::NotSymmetricMatrix(format!("{:?}", *m)));
+    }
+
+    Ok(m_f64)
+}
+
+impl crate::linear::Solve<crate::vertex::Vertex<f64>> for Cholesky {
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, f64>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<f64>) -> Self::Output {
+        use crate::vertex::Vertex;
+
+        check_vertex(self.l.size[0], b, || self.reconstruct())?;
+
+        let mut x = b.to_vec();
+
+        self.substitute(&mut x);
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl crate::linear::Solve<super::Matrix<f64>> for Cholesky {
+    type Output = crate::error::SlalErr<super::Matrix<f64>, f64>;
+
+    fn solve(&self, b: &super::Matrix<f64>) -> Self::Output {
+        check_matrix(self.l.size[0], b, || self.reconstruct())?;
+
+        Ok(substitute_columns(b, |column| self.substitute(column)))
+    }
+}
+
+impl crate::linear::Solve<crate::vertex::Vertex<f64>> for Ldlt {
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, f64>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<f64>) -> Self::Output {
+        use crate::vertex::Vertex;
+
+        check_vertex(self.l.size[0], b, || self.reconstruct())?;
+
+        let mut x = b.to_vec();
+
+        self.substitute(&mut x);
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl crate::linear::Solve<super::Matrix<f64>> for Ldlt {
+    type Output = crate::error::SlalErr<super::Matrix<f64>, f64>;
+
+    fn solve(&self, b: &super::Matrix<f64>) -> Self::Output {
+        check_matrix(self.l.size[0], b, || self.reconstruct())?;
+
+        Ok(substitute_columns(b, |column| self.substitute(column)))
+    }
+}
+
+/**
+    Singular value decomposition of a matrix.
+
+    Holds `U` with orthonormal columns, the singular values `s` in descending order
+    and `V^T` with orthonormal rows so that `A = U diag(s) V^T`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Svd {
+    u: super::Matrix<f64>,
+    s: crate::vertex::Vertex<f64>,
+    v_t: super::Matrix<f64>,
+}
+
+impl Svd {
+    /**
+    Returns the matrix `U` of left singular vectors
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 0], &[0, -2]]).unwrap();
+
+    assert!(m.svd().unwrap().u() == &Matrix::new(&[&[1., 0.], &[0., -1.]]).unwrap());
+    ```
+     */
+    pub fn u(&self) -> &super::Matrix<f64> {
+        &self.u
+    }
+
+    /**
+    Returns the singular values in descending order
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[3, 0], &[0, -2]]).unwrap();
+
+    assert!(m.svd().unwrap().s() == &Vertex::new(&[3., 2.]));
+    ```
+     */
+    pub fn s(&self) -> &crate::vertex::Vertex<f64> {
+        &self.s
+    }
+
+    /**
+    Returns the matrix `V^T` of right singular vectors
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 0], &[0, -2]]).unwrap();
+
+    assert!(m.svd().unwrap().v_t() == &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap());
+    ```
+     */
+    pub fn v_t(&self) -> &super::Matrix<f64> {
+        &self.v_t
+    }
+
+    /**
+    Returns the number of singular values above `tol`
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[1, 2], &[2, 4]]).unwrap();
+
+    assert!(m.svd().unwrap().rank(1e-10) == 1);
+    ```
+     */
+    pub fn rank(&self, tol: f64) -> usize {
+        self.s.to_vec().iter().filter(|s_i| **s_i > tol).count()
+    }
+
+    /**
+    Computes the Moore-Penrose pseudo-inverse `V diag(1/s) U^T`,
+    treating singular values not above `tol` as 0
+
+    # Example
+    ```
+    use slal::linear::SingularValueDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 0], &[0, 0]]).unwrap();
+
+    assert!(m.svd().unwrap().pinv(1e-10) == Matrix::new(&[&[0.5, 0.], &[0., 0.]]).unwrap());
+    ```
+     */
+    pub fn pinv(&self, tol: f64) -> super::Matrix<f64> {
+        let (k, m) = (self.u.size[0], self.u.size[1]);
+        let n = self.v_t.size[0];
+        let rank = self.rank(tol);
+        let mut pinv = super::Matrix::<f64> {
+            m: vec![0.; n * m],
+            size: [m, n],
+        };
+
+        crate::parallel::for_each_mut(&mut pinv.m, |idx, x| {
+            let (r, c) = (idx / m, idx % m);
+
+            *x = (0..rank.min(k))
+                .map(|i| self.v_t[i][r] * self.u[c][i] / self.s[i])
+                .sum();
+        });
+
+        pinv
+    }
+
+    /**
+    Default tolerance for treating singular values as 0 (`max(n, m) * ε * s_max`)
+    */
+    pub(crate) fn default_tolerance(&self) -> f64 {
+        let size = self.u.size[1].max(self.v_t.size[0]);
+
+        size as f64 * f64::EPSILON * if self.s.is_empty() { 0. } else { self.s[0] }
+    }
+}
+
+/**
+    Least squares solution of a linear system `Ax = b`.
+
+    Holds the minimum-norm solution `x` minimizing `|Ax - b|`,
+    the residual `|Ax - b|` and the effective rank of `A`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lstsq {
+    x: crate::vertex::Vertex<f64>,
+    residual: f64,
+    rank: usize,
+}
+
+impl Lstsq {
+    /**
+    Returns the minimum-norm least squares solution
+
+    # Example
+    ```
+    use slal::linear::LeastSquares;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    // x + y = 2 has minimum-norm solution x = y = 1
+    let a = Matrix::<i32>::new(&[&[1, 1]]).unwrap();
+    let b = Vertex::new_transposed(&[2]);
+    let x = a.lstsq(&b).unwrap().x().to_vec();
+
+    assert!((x[0] - 1.).abs() < 1e-10 && (x[1] - 1.).abs() < 1e-10);
+    ```
+     */
+    pub fn x(&self) -> &crate::vertex::Vertex<f64> {
+        &self.x
+    }
+
+    /**
+    Returns the Euclidean norm of the residual `Ax - b`
+
+    # Example
+    ```
+    use slal::linear::LeastSquares;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    // x = 1 and x = 3 are best fitted by x = 2
+    let a = Matrix::<i32>::new(&[&[1], &[1]]).unwrap();
+    let b = Vertex::new_transposed(&[1, 3]);
+
+    assert!((a.lstsq(&b).unwrap().residual() - 2f64.sqrt()).abs() < 1e-10);
+    ```
+     */
+    pub fn residual(&self) -> f64 {
+        self.residual
+    }
+
+    /**
+    Returns the effective rank of the matrix
+
+    # Example
+    ```
+    use slal::linear::LeastSquares;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let a = Matrix::<i32>::new(&[&[1, 2], &[2, 4], &[3, 6]]).unwrap();
+    let b = Vertex::new_transposed(&[1, 2, 3]);
+
+    assert!(a.lstsq(&b).unwrap().rank() == 1);
+    ```
+     */
+    pub fn rank(&self) -> usize {
+        self.rank
+    }
+}
+
+/**
+    Solves `Ax = b` in the least squares sense from the singular value decomposition of `A`
+*/
+pub(crate) fn lstsq(a: &super::Matrix<f64>, svd: &Svd, b: &[f64]) -> Lstsq {
+    let rank = svd.rank(svd.default_tolerance());
+    let n = a.size[0];
+
+    // Coordinates of b in the basis of left singular vectors, scaled by 1/s
+    let c: Vec<f64> = (0..rank)
+        .map(|i| (0..b.len()).map(|j| svd.u[j][i] * b[j]).sum::<f64>() / svd.s[i])
+        .collect();
+    let x: Vec<f64> = (0..n)
+        .map(|r| (0..rank).map(|i| svd.v_t[i][r] * c[i]).sum())
+        .collect();
+    let residual = (0..b.len())
+        .map(|j| ((0..n).map(|i| a[j][i] * x[i]).sum::<f64>() - b[j]).powi(2))
+        .sum::<f64>()
+        .sqrt();
+
+    Lstsq {
+        x: crate::vertex::Vertex::new_transposed(x.as_slice()),
+        residual,
+        rank,
+    }
+}
+
+// Maximum number of sweeps for one-sided Jacobi method
+pub(crate) const SVD_MAX_SWEEP: usize = 64;
+// Columns are treated as orthogonal when their cosine is below this value
+const SVD_TOLERANCE: f64 = 1e-15;
+
+/**
+    Orthogonalizes `columns` against each other with one-sided Jacobi rotations.
+    Rotations are also applied to `v` if given.
+    Returns false if the columns did not converge within `SVD_MAX_SWEEP` sweeps.
+*/
+fn one_sided_jacobi(columns: &mut [Vec<f64>], mut v: Option<&mut [Vec<f64>]>) -> bool {
+    let n = columns.len();
+    // Columns with norm below this value are treated as zero columns
+    let zero_column = DELTA.powi(2)
+        * columns
+            .iter()
+            .map(|column| column.iter().map(|a| a.powi(2)).sum::<f64>())
+            .sum::<f64>();
+
+    for _ in 0..SVD_MAX_SWEEP {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let alpha: f64 = columns[p].iter().map(|a| a.powi(2)).sum();
+                let beta: f64 = columns[q].iter().map(|a| a.powi(2)).sum();
+                let gamma: f64 = columns[p]
+                    .iter()
+                    .zip(columns[q].iter())
+                    .map(|(a_p, a_q)| a_p * a_q)
+                    .sum();
+
+                if alpha <= zero_column
+                    || beta <= zero_column
+                    || gamma.abs() <= SVD_TOLERANCE * (alpha * beta).sqrt()
+                {
+                    continue;
+                }
+
+                rotated = true;
+
+                let zeta = (beta - alpha) / (2. * gamma);
+                let t = zeta.signum() / (zeta.abs() + (1. + zeta.powi(2)).sqrt());
+                let c = 1. / (1. + t.powi(2)).sqrt();
+                let s = c * t;
+                let rotate = |x: &mut [Vec<f64>]| {
+                    for j in 0..x[p].len() {
+                        let (x_p, x_q) = (x[p][j], x[q][j]);
+
+                        x[p][j] = c * x_p - s * x_q;
+                        x[q][j] = s * x_p + c * x_q;
+                    }
+                };
+
+                rotate(columns);
+                if let Some(v) = v.as_deref_mut() {
+                    rotate(v);
+                }
+            }
+        }
+
+        if !rotated {
+            return true;
+        }
+    }
+
+    false
+}
+
+/**
+    Splits a matrix into columns, transposing wide matrices so that
+    there are never more columns than rows
+*/
+fn svd_columns(a: &super::Matrix<f64>) -> Vec<Vec<f64>> {
+    let [n, m] = a.size;
+
+    if m >= n {
+        (0..n).map(|i| (0..m).map(|j| a[j][i]).collect()).collect()
+    } else {
+        (0..m).map(|j| a[j].to_vec()).collect()
+    }
+}
+
+/**
+    Computes the singular values of a matrix in descending order.
+    Returns `None` if the computation did not converge.
+*/
+pub(crate) fn singular_values(a: &super::Matrix<f64>) -> Option<Vec<f64>> {
+    let mut columns = svd_columns(a);
+
+    if !one_sided_jacobi(&mut columns, None) {
+        return None;
+    }
+
+    let mut s: Vec<f64> = columns
+        .iter()
+        .map(|column| column.iter().map(|a| a.powi(2)).sum::<f64>().sqrt())
+        .collect();
+    s.sort_by(|x, y| y.total_cmp(x));
+
+    Some(s)
+}
+
+/**
+    Computes the thin singular value decomposition of a matrix.
+    Returns `None` if the computation did not converge.
+*/
+pub(crate) fn svd(a: &super::Matrix<f64>) -> Option<Svd> {
+    let [n, m] = a.size;
+    let mut columns = svd_columns(a);
+    let k = columns.len();
+    let mut v: Vec<Vec<f64>> = (0..k)
+        .map(|i| (0..k).map(|j| if i == j { 1. } else { 0. }).collect())
+        .collect();
+
+    if !one_sided_jacobi(&mut columns, Some(&mut v)) {
+        return None;
+    }
+
+    let norms: Vec<f64> = columns
+        .iter()
+        .map(|column| column.iter().map(|a| a.powi(2)).sum::<f64>().sqrt())
+        .collect();
+    let mut order: Vec<usize> = (0..k).collect();
+    order.sort_by(|x, y| norms[*y].total_cmp(&norms[*x]));
+
+    let s: Vec<f64> = order.iter().map(|idx| norms[*idx]).collect();
+    let mut left: Vec<Vec<f64>> = Vec::with_capacity(k);
+    for (idx, s_i) in order.iter().zip(s.iter()) {
+        if *s_i > DELTA * s[0] {
+            left.push(columns[*idx].iter().map(|a| a / s_i).collect());
+        } else {
+            // Singular vectors of zero singular values are any orthonormal completion
+            left.push(orthonormal_complement(&left, columns[*idx].len()));
+        }
+    }
+    let right: Vec<&Vec<f64>> = order.iter().map(|idx| &v[*idx]).collect();
+
+    // A = U S V^T for tall matrices, A^T = U S V^T for wide matrices
+    let (u_columns, v_t_rows): (Vec<&Vec<f64>>, Vec<&Vec<f64>>) = if m >= n {
+        (left.iter().collect(), right)
+    } else {
+        (right, left.iter().collect())
+    };
+
+    Some(Svd {
+        u: super::Matrix {
+            m: (0..m)
+                .flat_map(|j| u_columns.iter().map(move |u_i| u_i[j]))
+                .collect(),
+            size: [k, m],
+        },
+        s: crate::vertex::Vertex::new(s.as_slice()),
+        v_t: super::Matrix {
+            m: v_t_rows
+                .iter()
+                .flat_map(|v_j| v_j.iter().copied())
+                .collect(),
+            size: [n, k],
+        },
+    })
+}
+
+/**
+    Finds a unit vector of length `len` orthogonal to every vector in `basis`
+*/
+pub(crate) fn orthonormal_complement(basis: &[Vec<f64>], len: usize) -> Vec<f64> {
+    (0..len)
+        .map(|e| {
+            let mut x = vec![0.; len];
+            x[e] = 1.;
+
+            // Gram-Schmidt is applied twice for numerical stability
+            for _ in 0..2 {
+                for b in basis {
+                    let proj: f64 = b.iter().zip(x.iter()).map(|(b_i, x_i)| b_i * x_i).sum();
+
+                    x.iter_mut()
+                        .zip(b.iter())
+                        .for_each(|(x_i, b_i)| *x_i -= proj * b_i);
+                }
+            }
+
+            x
+        })
+        .max_by(|x, y| {
+            let norm_x: f64 = x.iter().map(|x_i| x_i.powi(2)).sum();
+            let norm_y: f64 = y.iter().map(|y_i| y_i.powi(2)).sum();
+
+            norm_x.total_cmp(&norm_y)
+        })
+        .map(|x| {
+            let norm = x.iter().map(|x_i| x_i.powi(2)).sum::<f64>().sqrt();
+
+            x.iter().map(|x_i| x_i / norm).collect()
+        })
+        .unwrap_or_default()
+}
+
+impl<T> crate::linear::SingularValueDecomposition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn svd(&self) -> crate::error::SlalErr<Svd, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        match svd(&self.to_f64()) {
+            Some(svd) => Ok(svd),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", SVD_MAX_SWEEP),
+            )),
+        }
+    }
+
+    fn singular_values(&self) -> crate::error::SlalErr<crate::vertex::Vertex<f64>, T> {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        match singular_values(&self.to_f64()) {
+            Some(s) => Ok(Vertex::new(s.as_slice())),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", SVD_MAX_SWEEP),
+            )),
+        }
+    }
+}
@@ -0,0 +1,534 @@
+use super::Matrix;
+use crate::error::SlalError;
+use crate::linear::{
+    CholeskyDecomposition, Dot, LuDecomposition, QrDecomposition, SingularValueDecomposition,
+    Solve, TriangularMatrix,
+};
+use crate::vertex::Vertex;
+
+const TOLERANCE: f64 = 1e-10;
+
+fn identity(n: usize) -> Matrix<f64> {
+    let mut m = vec![0.; n * n];
+    (0..n).for_each(|ij| m[ij * n + ij] = 1.);
+
+    Matrix { m, size: [n, n] }
+}
+
+fn transposed(m: &Matrix<f64>) -> Matrix<f64> {
+    let mut m_t = m.clone();
+
+    m_t.t();
+
+    m_t
+}
+
+fn svd_reconstruct(svd: &crate::matrix::Svd) -> Matrix<f64> {
+    let mut us = svd.u().clone();
+    let k = svd.s().len();
+    (0..us.size[1]).for_each(|j| (0..k).for_each(|i| us[j][i] *= svd.s()[i]));
+
+    us.dot(svd.v_t()).unwrap()
+}
+
+fn approx_eq(m: &Matrix<f64>, n: &Matrix<f64>) -> bool {
+    m.size == n.size
+        && m.m
+            .iter()
+            .zip(n.m.iter())
+            .all(|(m_ji, n_ji)| (m_ji - n_ji).abs() < TOLERANCE)
+}
+
+#[test]
+fn lu() {
+    let m = Matrix::<f64>::new(&[
+        &[2., 1., 1., 0.],
+        &[4., 3., 3., 1.],
+        &[8., 7., 9., 5.],
+        &[6., 7., 9., 8.],
+    ])
+    .unwrap();
+    let lu = m.lu().unwrap();
+    let mut pa = m.clone();
+    lu.p()
+        .iter()
+        .enumerate()
+        .for_each(|(j, p_j)| pa[j].copy_from_slice(&m[*p_j]));
+
+    assert!(lu.l().is_lower_triangular());
+    assert!(lu.u().is_upper_triangular());
+    assert!((0..4).all(|ij| lu.l()[ij][ij] == 1.));
+    assert!(approx_eq(&(lu.l().clone() * lu.u().clone()), &pa));
+}
+
+#[test]
+fn lu_pivoting() {
+    let m = Matrix::<i32>::new(&[&[0, 1], &[1, 0]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert_eq!(lu.p(), &[1, 0]);
+    assert_eq!(lu.sign(), -1.);
+    assert_eq!(lu.l(), &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap());
+    assert_eq!(lu.u(), &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap());
+    assert_eq!(
+        lu.permutation(),
+        Matrix::new(&[&[0., 1.], &[1., 0.]]).unwrap()
+    );
+}
+
+#[test]
+fn lu_det() {
+    let m =
+        Matrix::<u8>::new(&[&[2, 1, 1, 0], &[4, 3, 3, 1], &[8, 7, 9, 5], &[6, 7, 9, 8]]).unwrap();
+
+    assert!((m.lu().unwrap().det() - 8.).abs() < TOLERANCE);
+}
+
+#[test]
+fn lu_singular() {
+    let m = Matrix::<i16>::new(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert!(lu.is_singular());
+    assert_eq!(lu.det(), 0.);
+}
+
+#[test]
+fn lu_small_entries() {
+    let m = Matrix::<f64>::new(&[&[2e-11, 1e-11], &[1e-11, 2e-11]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    assert!(!lu.is_singular());
+    assert!((lu.det() - 3e-22).abs() < 1e-32);
+
+    let inverse = lu.inverse().unwrap();
+    let ans = [[2. / 3e-11, -1. / 3e-11], [-1. / 3e-11, 2. / 3e-11]];
+
+    assert!(
+        (0..2).all(|j| (0..2).all(|i| (inverse[j][i] - ans[j][i]).abs() < 1e-6 * ans[j][i].abs()))
+    );
+}
+
+#[test]
+fn lu_inverse() {
+    let m = Matrix::<f32>::new(&[&[4., 7.], &[2., 6.]]).unwrap();
+    let inv = m.lu().unwrap().inverse().unwrap();
+
+    assert!(approx_eq(
+        &inv,
+        &Matrix::new(&[&[0.6, -0.7], &[-0.2, 0.4]]).unwrap()
+    ));
+}
+
+#[test]
+fn lu_inverse_singular() {
+    let m = Matrix::<u32>::new(&[&[1, 2], &[2, 4]]).unwrap();
+
+    assert_eq!(
+        m.lu().unwrap().inverse(),
+        Err(SlalError::DeterminantZero(
+            Matrix::new(&[&[1., 2.], &[2., 4.]]).unwrap()
+        ))
+    );
+}
+
+#[test]
+fn lu_substitute_transposed() {
+    let m = Matrix::<f64>::new(&[&[1., 2., 0.], &[3., 1., 4.], &[0., 5., 1.]]).unwrap();
+    let lu = m.lu().unwrap();
+    let mut x = vec![1., -2., 3.];
+
+    lu.substitute_transposed(&mut x);
+
+    // A^Tx = b
+    (0..3).for_each(|i| {
+        let b_i: f64 = (0..3).map(|j| m[j][i] * x[j]).sum();
+
+        assert!((b_i - [1., -2., 3.][i]).abs() < TOLERANCE);
+    });
+}
+
+#[test]
+fn lu_not_square() {
+    let m = Matrix::<i8>::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    assert!(m.lu().is_err());
+}
+
+#[test]
+fn lu_empty() {
+    let m = Matrix::<f64>::empty();
+
+    assert!(m.lu().is_err());
+}
+
+#[test]
+fn lu_solve() {
+    let m = Matrix::<i8>::new(&[&[0, 2, 1], &[1, 1, 0], &[3, 0, 1]]).unwrap();
+    let lu = m.lu().unwrap();
+
+    // Factorization is reused for every right-hand side
+    for ans in [[1., 2., 3.], [-1., 0., 4.], [0.5, 0.25, -2.]] {
+        let b =
+            Vertex::new_transposed(&[2. * ans[1] + ans[2], ans[0] + ans[1], 3. * ans[0] + ans[2]]);
+        let x = lu.solve(&b).unwrap();
+
+        assert!((0..3).all(|i| (x[i] - ans[i]).abs() < TOLERANCE));
+    }
+}
+
+#[test]
+fn lu_solve_matrix() {
+    let m = Matrix::<f64>::new(&[&[4., 7.], &[2., 6.]]).unwrap();
+    let lu = m.lu().unwrap();
+    let b = Matrix::new(&[&[4., 7.], &[2., 6.]]).unwrap();
+
+    assert!(approx_eq(
+        &lu.solve(&b).unwrap(),
+        &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap()
+    ));
+}
+
+#[test]
+fn lu_solve_small_coefficients() {
+    let m = Matrix::<f64>::new(&[&[1e-11, 3e-11], &[2e-11, 1e-11]]).unwrap();
+    let b = Matrix::<f64>::new(&[&[7e-11, 1e-11], &[4e-11, 2e-11]]).unwrap();
+    let x = m.lu().unwrap().solve(&b).unwrap();
+    let ans = [[1., 1.], [2., 0.]];
+
+    assert!((0..2).all(|j| (0..2).all(|i| (x[j][i] - ans[j][i]).abs() < TOLERANCE)));
+}
+
+#[test]
+fn lu_solve_singular() {
+    let m = Matrix::<u8>::new(&[&[1, 2], &[2, 4]]).unwrap();
+    let b = Vertex::new_transposed(&[1., 2.]);
+
+    assert_eq!(
+        m.lu().unwrap().solve(&b),
+        Err(SlalError::SingularMatrix(format!(
+            "{:?}",
+            Matrix::new(&[&[1., 2.], &[2., 4.]]).unwrap()
+        )))
+    );
+}
+
+#[test]
+fn qr_square() {
+    let m = Matrix::<i32>::new(&[&[12, -51, 4], &[6, 167, -68], &[-4, 24, -41]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert!(qr.r().is_upper_triangular());
+    assert!(approx_eq(
+        &transposed(qr.q()).dot(qr.q()).unwrap(),
+        &identity(3)
+    ));
+    assert!(approx_eq(
+        &qr.q().dot(qr.r()).unwrap(),
+        &Matrix::<f64>::from(m)
+    ));
+}
+
+#[test]
+fn qr_tall() {
+    let m = Matrix::<f64>::new(&[&[1., 2.], &[3., 4.], &[5., 6.], &[7., 9.]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert_eq!(qr.q().size(), (4, 4));
+    assert_eq!(qr.r().size(), (2, 4));
+    assert!((0..4).all(|j| (0..j.min(2)).all(|i| qr.r()[j][i] == 0.)));
+    assert!(approx_eq(
+        &transposed(qr.q()).dot(qr.q()).unwrap(),
+        &identity(4)
+    ));
+    assert!(approx_eq(&qr.q().dot(qr.r()).unwrap(), &m));
+}
+
+#[test]
+fn qr_thin_tall() {
+    let m = Matrix::<u8>::new(&[&[1, 2], &[3, 4], &[5, 6], &[7, 9]]).unwrap();
+    let qr = m.qr_thin().unwrap();
+
+    assert_eq!(qr.q().size(), (2, 4));
+    assert_eq!(qr.r().size(), (2, 2));
+    assert!(qr.r().is_upper_triangular());
+    assert!(approx_eq(
+        &transposed(qr.q()).dot(qr.q()).unwrap(),
+        &identity(2)
+    ));
+    assert!(approx_eq(
+        &qr.q().dot(qr.r()).unwrap(),
+        &Matrix::<f64>::from(m)
+    ));
+}
+
+#[test]
+fn qr_wide() {
+    let m = Matrix::<f32>::new(&[&[1., 2., 3., 4.], &[0., 5., 6., 7.]]).unwrap();
+
+    for qr in [m.qr().unwrap(), m.qr_thin().unwrap()] {
+        assert_eq!(qr.q().size(), (2, 2));
+        assert_eq!(qr.r().size(), (4, 2));
+        assert_eq!(qr.r()[1][0], 0.);
+        assert!(approx_eq(
+            &transposed(qr.q()).dot(qr.q()).unwrap(),
+            &identity(2)
+        ));
+        assert!(approx_eq(
+            &qr.q().dot(qr.r()).unwrap(),
+            &Matrix::<f64>::from(m.clone())
+        ));
+    }
+}
+
+#[test]
+fn qr_rank_deficient() {
+    let m = Matrix::<i16>::new(&[&[1, 2, 3], &[2, 4, 6], &[3, 6, 9]]).unwrap();
+    let qr = m.qr().unwrap();
+
+    assert!(approx_eq(
+        &transposed(qr.q()).dot(qr.q()).unwrap(),
+        &identity(3)
+    ));
+    assert!(approx_eq(
+        &qr.q().dot(qr.r()).unwrap(),
+        &Matrix::<f64>::from(m)
+    ));
+}
+
+#[test]
+fn qr_small_entries() {
+    let m = Matrix::<f64>::new(&[&[1e-11, 2e-11], &[3e-11, 4e-11], &[5e-11, 6e-11]]).unwrap();
+
+    for qr in [m.qr().unwrap(), m.qr_thin().unwrap()] {
+        let product = qr.q().dot(qr.r()).unwrap();
+
+        assert!(product
+            .m
+            .iter()
+            .zip(m.m.iter())
+            .all(|(p_ji, m_ji)| (p_ji - m_ji).abs() < 1e-10 * m_ji.abs()));
+        assert_ne!(qr.r()[0][0], 0.);
+        assert_ne!(qr.r()[1][1], 0.);
+    }
+}
+
+#[test]
+fn qr_empty() {
+    let m = Matrix::<f64>::empty();
+
+    assert!(m.qr().is_err());
+    assert!(m.qr_thin().is_err());
+}
+
+#[test]
+fn cholesky() {
+    let m = Matrix::<i32>::new(&[&[4, 12, -16], &[12, 37, -43], &[-16, -43, 98]]).unwrap();
+    let cholesky = m.cholesky().unwrap();
+
+    assert_eq!(
+        cholesky.l(),
+        &Matrix::new(&[&[2., 0., 0.], &[6., 1., 0.], &[-8., 5., 3.]]).unwrap()
+    );
+    assert!((cholesky.det() - 36.).abs() < TOLERANCE);
+    assert!(approx_eq(
+        &cholesky.l().dot(&transposed(cholesky.l())).unwrap(),
+        &Matrix::<f64>::from(m)
+    ));
+}
+
+#[test]
+fn cholesky_solve() {
+    let m = Matrix::<f64>::new(&[&[4., 12., -16.], &[12., 37., -43.], &[-16., -43., 98.]]).unwrap();
+    let b = Vertex::new_transposed(&[4. - 24. - 48., 12. - 74. - 129., -16. + 86. + 294.]);
+    let x = m.cholesky().unwrap().solve(&b).unwrap();
+
+    assert!([1., -2., 3.]
+        .iter()
+        .enumerate()
+        .all(|(i, ans)| (x[i] - ans).abs() < TOLERANCE));
+}
+
+#[test]
+fn cholesky_not_positive_definite() {
+    let m = Matrix::<i8>::new(&[&[1, 2], &[2, 1]]).unwrap();
+
+    assert_eq!(
+        m.cholesky(),
+        Err(SlalError::NotPositiveDefinite(format!("{:?}", m)))
+    );
+}
+
+#[test]
+fn cholesky_not_symmetric() {
+    let m = Matrix::<u8>::new(&[&[4, 1], &[2, 4]]).unwrap();
+
+    assert_eq!(
+        m.cholesky(),
+        Err(SlalError::NotSymmetricMatrix(format!("{:?}", m)))
+    );
+}
+
+#[test]
+fn symmetric_relative_tolerance() {
+    let large = 1e8_f64;
+    let next = f64::from_bits(large.to_bits() + 1);
+
+    // One ulp apart at a large scale is symmetric
+    assert!(Matrix::<f64>::new(&[&[2e8, large], &[next, 2e8]])
+        .unwrap()
+        .ldlt()
+        .is_ok());
+
+    // Asymmetry at a small scale is not hidden by an absolute tolerance
+    let m = Matrix::<f64>::new(&[&[4e-11, 1e-11], &[2e-11, 4e-11]]).unwrap();
+
+    assert_eq!(
+        m.cholesky(),
+        Err(SlalError::NotSymmetricMatrix(format!("{:?}", m)))
+    );
+}
+
+#[test]
+fn cholesky_not_square() {
+    let m = Matrix::<f32>::new(&[&[4., 1., 0.], &[1., 4., 0.]]).unwrap();
+
+    assert!(m.cholesky().is_err());
+    assert!(m.ldlt().is_err());
+}
+
+#[test]
+fn ldlt() {
+    let m = Matrix::<i16>::new(&[&[4, 12, -16], &[12, 37, -43], &[-16, -43, 98]]).unwrap();
+    let ldlt = m.ldlt().unwrap();
+
+    assert_eq!(
+        ldlt.l(),
+        &Matrix::new(&[&[1., 0., 0.], &[3., 1., 0.], &[-4., 5., 1.]]).unwrap()
+    );
+    assert_eq!(ldlt.d(), &Vertex::new(&[4., 1., 9.]));
+    assert!(ldlt.is_positive_definite());
+}
+
+#[test]
+fn ldlt_indefinite() {
+    let m = Matrix::<f64>::new(&[&[1., 2., 3.], &[2., 1., 4.], &[3., 4., 1.]]).unwrap();
+    let ldlt = m.ldlt().unwrap();
+    let mut ld = ldlt.l().clone();
+    (0..3).for_each(|j| (0..3).for_each(|i| ld[j][i] *= ldlt.d()[i]));
+
+    assert!(!ldlt.is_positive_definite());
+    assert!((ldlt.det() - m.lu().unwrap().det()).abs() < TOLERANCE);
+    assert!(approx_eq(&ld.dot(&transposed(ldlt.l())).unwrap(), &m));
+}
+
+#[test]
+fn ldlt_solve() {
+    let m = Matrix::<f64>::new(&[&[1., 2., 3.], &[2., 1., 4.], &[3., 4., 1.]]).unwrap();
+    let b = Matrix::new(&[&[1., 2.], &[2., 1.], &[3., 4.]]).unwrap();
+    let x = m.ldlt().unwrap().solve(&b).unwrap();
+
+    assert!(approx_eq(&m.dot(&x).unwrap(), &b));
+}
+
+#[test]
+fn ldlt_zero_pivot() {
+    let m = Matrix::<i32>::new(&[&[0, 1], &[1, 0]]).unwrap();
+
+    // Not singular, but LDL^T does not pivot
+    assert_eq!(m.ldlt(), Err(SlalError::SingularMatrix(format!("{:?}", m))));
+}
+
+#[test]
+fn cholesky_small_entries() {
+    let m = Matrix::<f64>::new(&[&[2e-11, 1e-11], &[1e-11, 2e-11]]).unwrap();
+    let cholesky = m.cholesky().unwrap();
+    let ldlt = m.ldlt().unwrap();
+
+    assert!((cholesky.det() - 3e-22).abs() < 1e-32);
+    assert!((ldlt.d()[0] - 2e-11).abs() < 1e-21);
+    assert!((ldlt.d()[1] - 1.5e-11).abs() < 1e-21);
+}
+
+#[test]
+fn svd_wide() {
+    let m = Matrix::<i32>::new(&[&[3, 2, 2], &[2, 3, -2]]).unwrap();
+    let svd = m.svd().unwrap();
+
+    assert_eq!(svd.u().size(), (2, 2));
+    assert_eq!(svd.v_t().size(), (3, 2));
+    assert!((svd.s()[0] - 5.).abs() < TOLERANCE);
+    assert!((svd.s()[1] - 3.).abs() < TOLERANCE);
+    assert!(approx_eq(
+        &transposed(svd.u()).dot(svd.u()).unwrap(),
+        &identity(2)
+    ));
+    assert!(approx_eq(
+        &svd.v_t().dot(&transposed(svd.v_t())).unwrap(),
+        &identity(2)
+    ));
+    assert!(approx_eq(&svd_reconstruct(&svd), &Matrix::<f64>::from(m)));
+}
+
+#[test]
+fn svd_tall() {
+    let m = Matrix::<f64>::new(&[&[1., 2.], &[3., 4.], &[5., 6.], &[7., 8.]]).unwrap();
+    let svd = m.svd().unwrap();
+
+    assert_eq!(svd.u().size(), (2, 4));
+    assert_eq!(svd.v_t().size(), (2, 2));
+    assert!(svd.s()[0] >= svd.s()[1]);
+    assert!(approx_eq(
+        &transposed(svd.u()).dot(svd.u()).unwrap(),
+        &identity(2)
+    ));
+    assert!(approx_eq(
+        &svd.v_t().dot(&transposed(svd.v_t())).unwrap(),
+        &identity(2)
+    ));
+    assert!(approx_eq(&svd_reconstruct(&svd), &m));
+}
+
+#[test]
+fn svd_rank_deficient() {
+    let m = Matrix::<u8>::new(&[&[1, 2, 3], &[2, 4, 6], &[1, 1, 1]]).unwrap();
+    let svd = m.svd().unwrap();
+
+    assert!(svd.s()[2].abs() < TOLERANCE);
+    assert!(approx_eq(
+        &transposed(svd.u()).dot(svd.u()).unwrap(),
+        &identity(3)
+    ));
+    assert!(approx_eq(&svd_reconstruct(&svd), &Matrix::<f64>::from(m)));
+}
+
+#[test]
+fn svd_zero() {
+    let m = Matrix::<i16>::new(&[&[0, 0], &[0, 0], &[0, 0]]).unwrap();
+    let svd = m.svd().unwrap();
+
+    assert_eq!(svd.s(), &Vertex::new(&[0., 0.]));
+    assert!(approx_eq(
+        &transposed(svd.u()).dot(svd.u()).unwrap(),
+        &identity(2)
+    ));
+}
+
+#[test]
+fn singular_values() {
+    let m =
+        Matrix::<f32>::new(&[&[2., 0., 1.], &[-1., 3., 0.], &[0., 1., 4.], &[1., 1., 1.]]).unwrap();
+    let s = m.singular_values().unwrap();
+    let svd = m.svd().unwrap();
+
+    assert_eq!(s.len(), 3);
+    assert!((0..3).all(|i| (s[i] - svd.s()[i]).abs() < TOLERANCE));
+}
+
+#[test]
+fn svd_empty() {
+    let m = Matrix::<u32>::empty();
+
+    assert!(m.svd().is_err());
+    assert!(m.singular_values().is_err());
+}
@@ -0,0 +1,1101 @@
+// Machine epsilon used to detect negligible sub-diagonal elements
+const EPSILON: f64 = f64::EPSILON;
+
+/**
+    Eigendecomposition of a square matrix.
+
+    Holds the real and imaginary parts of all eigenvalues and eigenvectors.
+    Column `i` of the eigenvector matrices is the unit eigenvector of eigenvalue `i`.
+    Eigenvectors of a defective matrix are numerically parallel, so they do not form a basis.
+    Eigenvalues of a complex conjugate pair are stored next to each other,
+    the one with positive imaginary part first.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eig {
+    values_re: crate::vertex::Vertex<f64>,
+    values_im: crate::vertex::Vertex<f64>,
+    vectors_re: super::Matrix<f64>,
+    vectors_im: super::Matrix<f64>,
+}
+
+impl Eig {
+    /**
+    Returns the real parts of the eigenvalues
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[2, 0], &[0, 3]]).unwrap();
+
+    assert!(m.eig().unwrap().values_re() == &Vertex::new(&[2., 3.]));
+    ```
+     */
+    pub fn values_re(&self) -> &crate::vertex::Vertex<f64> {
+        &self.values_re
+    }
+
+    /**
+    Returns the imaginary parts of the eigenvalues
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    // Rotation by 90 degrees has eigenvalues i and -i
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+
+    assert!(m.eig().unwrap().values_im() == &Vertex::new(&[1., -1.]));
+    ```
+     */
+    pub fn values_im(&self) -> &crate::vertex::Vertex<f64> {
+        &self.values_im
+    }
+
+    /**
+    Returns the real parts of the eigenvectors as columns of a matrix
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 0], &[0, 3]]).unwrap();
+
+    assert!(m.eig().unwrap().vectors_re() == &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap());
+    ```
+     */
+    pub fn vectors_re(&self) -> &super::Matrix<f64> {
+        &self.vectors_re
+    }
+
+    /**
+    Returns the imaginary parts of the eigenvectors as columns of a matrix
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[2, 0], &[0, 3]]).unwrap();
+
+    assert!(m.eig().unwrap().vectors_im() == &Matrix::new(&[&[0., 0.], &[0., 0.]]).unwrap());
+    ```
+     */
+    pub fn vectors_im(&self) -> &super::Matrix<f64> {
+        &self.vectors_im
+    }
+
+    /**
+    Checks if all eigenvalues are real
+
+    # Example
+    ```
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+
+    assert!(!m.eig().unwrap().is_real());
+    ```
+     */
+    pub fn is_real(&self) -> bool {
+        (0..self.values_im.len()).all(|idx| self.values_im[idx] == 0.)
+    }
+
+    /**
+    Returns the eigenvalues as complex numbers
+
+    # Example
+    ```
+    use slal::complex::Complex;
+    use slal::linear::EigenDecomposition;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+
+    assert!(m.eig().unwrap().values() == Vertex::new(&[Complex::new(0., 1.), Complex::new(0., -1.)]));
+    ```
+     */
+    pub fn values(&self) -> crate::vertex::Vertex<crate::complex::Complex<f64>> {
+        use crate::complex::Complex;
+
+        let rv: Vec<Complex<f64>> = (0..self.values_re.len())
+            .map(|idx| Complex::new(self.values_re[idx], self.values_im[idx]))
+            .collect();
+
+        crate::vertex::Vertex::new(rv.as_slice())
+    }
+
+    /**
+    Returns the eigenvectors as columns of a complex matrix
+
+    # Example
+    ```
+    use slal::complex::Complex;
+    use slal::linear::{Dot, EigenDecomposition};
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+    let eig = m.eig().unwrap();
+    let v = eig.vectors();
+    let a = Matrix::new(&[
+        &[Complex::new(0., 0.), Complex::new(-1., 0.)],
+        &[Complex::new(1., 0.), Complex::new(0., 0.)],
+    ])
+    .unwrap();
+
+    // A v = v diag(λ)
+    let av = a.dot(&v).unwrap();
+    let lambda = eig.values();
+
+    assert!((0..2).all(|j| (0..2).all(|i| (av[j][i] - v[j][i] * lambda[i]).abs() < 1e-12)));
+    ```
+     */
+    pub fn vectors(&self) -> super::Matrix<crate::complex::Complex<f64>> {
+        use crate::complex::Complex;
+
+        let (x, y) = self.vectors_re.size();
+        let rv: Vec<Complex<f64>> = self
+            .vectors_re
+            .m
+            .iter()
+            .zip(self.vectors_im.m.iter())
+            .map(|(re, im)| Complex::new(*re, *im))
+            .collect();
+
+        super::Matrix::from_vec(rv, [x, y])
+    }
+}
+
+/**
+    Maximum number of QR iterations for a matrix with size (n, n)
+*/
+pub(crate) fn max_iteration(n: usize) -> usize {
+    30 * n.max(10)
+}
+
+/**
+    Reduces a square matrix to upper Hessenberg form `H` with Householder similarity transformations.
+    Returns `H` and the accumulated orthogonal transformation `V` so that `A = VHV^T`.
+*/
+fn hessenberg(a: &super::Matrix<f64>) -> (super::Matrix<f64>, super::Matrix<f64>) {
+    let n = a.size[0];
+    let high = n - 1;
+    let mut h = a.clone();
+    let mut v = super::Matrix::<f64> {
+        m: vec![0.; n * n],
+        size: [n, n],
+    };
+    let mut ort = vec![0.; n];
+
+    for m in 1..high {
+        // Scale column
+        let scale: f64 = (m..=high).map(|j| h[j][m - 1].abs()).sum();
+
+        if scale == 0. {
+            continue;
+        }
+
+        // Compute Householder transformation
+        (m..=high).for_each(|j| ort[j] = h[j][m - 1] / scale);
+
+        let mut norm: f64 = (m..=high).map(|j| ort[j].powi(2)).sum();
+        let g = if ort[m] > 0. {
+            -norm.sqrt()
+        } else {
+            norm.sqrt()
+        };
+
+        norm -= ort[m] * g;
+        ort[m] -= g;
+
+        // Apply Householder similarity transformation H = (I - uu^T/norm)H(I - uu^T/norm)
+        for i in m..n {
+            let f = (m..=high).map(|j| ort[j] * h[j][i]).sum::<f64>() / norm;
+
+            (m..=high).for_each(|j| h[j][i] -= f * ort[j]);
+        }
+        for j in 0..=high {
+            let f = (m..=high).map(|i| ort[i] * h[j][i]).sum::<f64>() / norm;
+
+            (m..=high).for_each(|i| h[j][i] -= f * ort[i]);
+        }
+
+        ort[m] *= scale;
+        h[m][m - 1] = scale * g;
+    }
+
+    // Accumulate transformations
+    (0..n).for_each(|ij| v[ij][ij] = 1.);
+    for m in (1..high).rev() {
+        if h[m][m - 1] == 0. {
+            continue;
+        }
+
+        ((m + 1)..=high).for_each(|j| ort[j] = h[j][m - 1]);
+        for i in m..=high {
+            // Double division avoids possible underflow
+            let g = ((m..=high).map(|j| ort[j] * v[j][i]).sum::<f64>() / ort[m]) / h[m][m - 1];
+
+            (m..=high).for_each(|j| v[j][i] += g * ort[j]);
+        }
+    }
+
+    (h, v)
+}
+
+/**
+    Real Schur decomposition (A = ZTZ^T) left by the QR algorithm.
+
+    `t` is quasi upper triangular with 2x2 diagonal blocks for complex conjugate eigenvalues,
+    `z` is orthogonal and `norm` is the norm of the Hessenberg matrix used for convergence checks.
+*/
+pub(crate) struct Schur {
+    pub(crate) t: super::Matrix<f64>,
+    pub(crate) z: super::Matrix<f64>,
+    pub(crate) values_re: Vec<f64>,
+    pub(crate) values_im: Vec<f64>,
+    pub(crate) norm: f64,
+}
+
+/**
+    Computes the real Schur decomposition of a square matrix from its Hessenberg form
+    with the shifted double-step QR algorithm.
+    Returns `None` if the QR algorithm did not converge.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn schur(a: &super::Matrix<f64>) -> Option<Schur> {
+    let nn = a.size[0];
+    let (mut h, mut v) = hessenberg(a);
+    let mut d = vec![0.; nn];
+    let mut e = vec![0.; nn];
+    let mut exshift = 0.;
+    let mut iteration = 0;
+    let mut total_iteration = 0;
+
+    // Matrix norm for convergence checks
+    let norm: f64 = (0..nn)
+        .map(|j| (j.max(1) - 1..nn).map(|i| h[j][i].abs()).sum::<f64>())
+        .sum();
+
+    // Deflate eigenvalues from the bottom of the Hessenberg matrix
+    let mut top = nn as isize - 1;
+    while top >= 0 {
+        let n = top as usize;
+
+        // Look for single small sub-diagonal element
+        let mut l = n;
+        while l > 0 {
+            let mut s = h[l - 1][l - 1].abs() + h[l][l].abs();
+            if s == 0. {
+                s = norm;
+            }
+            if h[l][l - 1] == 0. || h[l][l - 1].abs() < EPSILON * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // One root found
+            h[n][n] += exshift;
+            d[n] = h[n][n];
+            e[n] = 0.;
+            top -= 1;
+            iteration = 0;
+        } else if l == n - 1 {
+            // Two roots found
+            let w = h[n][n - 1] * h[n - 1][n];
+            let p = (h[n - 1][n - 1] - h[n][n]) / 2.;
+            let q = p.powi(2) + w;
+            let mut z = q.abs().sqrt();
+
+            h[n][n] += exshift;
+            h[n - 1][n - 1] += exshift;
+
+            let x = h[n][n];
+
+            if q >= 0. {
+                // Real pair
+                z = if p >= 0. { p + z } else { p - z };
+                d[n - 1] = x + z;
+                d[n] = if z != 0. { x - w / z } else { d[n - 1] };
+                e[n - 1] = 0.;
+                e[n] = 0.;
+
+                let x = h[n][n - 1];
+                let s = x.abs() + z.abs();
+                let r = ((x / s).powi(2) + (z / s).powi(2)).sqrt();
+                let p = x / s / r;
+                let q = z / s / r;
+
+                // Row modification
+                for i in (n - 1)..nn {
+                    let z = h[n - 1][i];
+
+                    h[n - 1][i] = q * z + p * h[n][i];
+                    h[n][i] = q * h[n][i] - p * z;
+                }
+
+                // Column modification
+                for j in 0..=n {
+                    let z = h[j][n - 1];
+
+                    h[j][n - 1] = q * z + p * h[j][n];
+                    h[j][n] = q * h[j][n] - p * z;
+                }
+
+                // Accumulate transformations
+                for j in 0..nn {
+                    let z = v[j][n - 1];
+
+                    v[j][n - 1] = q * z + p * v[j][n];
+                    v[j][n] = q * v[j][n] - p * z;
+                }
+            } else {
+                // Complex pair
+                d[n - 1] = x + p;
+                d[n] = x + p;
+                e[n - 1] = z;
+                e[n] = -z;
+            }
+
+            top -= 2;
+            iteration = 0;
+        } else {
+            if total_iteration >= max_iteration(nn) {
+                return None;
+            }
+
+            // Form shift
+            let mut x = h[n][n];
+            let mut y = h[n - 1][n - 1];
+            let mut w = h[n][n - 1] * h[n - 1][n];
+
+            // Wilkinson's original ad hoc shift
+            if iteration == 10 {
+                exshift += x;
+                (0..=n).for_each(|ij| h[ij][ij] -= x);
+
+                let s = h[n][n - 1].abs() + h[n - 1][n - 2].abs();
+
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s.powi(2);
+            }
+
+            // MATLAB's ad hoc shift
+            if iteration == 30 {
+                let mut s = ((y - x) / 2.).powi(2) + w;
+
+                if s > 0. {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / 2. + s);
+                    (0..=n).for_each(|ij| h[ij][ij] -= s);
+                    exshift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iteration += 1;
+            total_iteration += 1;
+
+            // Look for two consecutive small sub-diagonal elements
+            let mut m = n - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = h[m][m];
+                let s = y - z;
+
+                r = x - z;
+                p = (r * s - w) / h[m + 1][m] + h[m][m + 1];
+                q = h[m + 1][m + 1] - z - r - s;
+                r = h[m + 2][m + 1];
+
+                let s = p.abs() + q.abs() + r.abs();
+
+                p /= s;
+                q /= s;
+                r /= s;
+
+                if m == l
+                    || h[m][m - 1].abs() * (q.abs() + r.abs())
+                        < EPSILON
+                            * (p.abs() * (h[m - 1][m - 1].abs() + z.abs() + h[m + 1][m + 1].abs()))
+                {
+                    break;
+                }
+
+                m -= 1;
+            }
+
+            for j in (m + 2)..=n {
+                h[j][j - 2] = 0.;
+                if j > m + 2 {
+                    h[j][j - 3] = 0.;
+                }
+            }
+
+            // Double QR step involving rows l:n and columns m:n
+            for k in m..n {
+                let not_last = k != n - 1;
+
+                if k != m {
+                    p = h[k][k - 1];
+                    q = h[k + 1][k - 1];
+                    r = if not_last { h[k + 2][k - 1] } else { 0. };
+                    x = p.abs() + q.abs() + r.abs();
+
+                    if x == 0. {
+                        continue;
+                    }
+
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                let mut s = (p.powi(2) + q.powi(2) + r.powi(2)).sqrt();
+                if p < 0. {
+                    s = -s;
+                }
+
+                if s == 0. {
+                    continue;
+                }
+
+                if k != m {
+                    h[k][k - 1] = -s * x;
+                } else if l != m {
+                    h[k][k - 1] = -h[k][k - 1];
+                }
+
+                p += s;
+                x = p / s;
+                y = q / s;
+
+                let z = r / s;
+
+                q /= p;
+                r /= p;
+
+                // Row modification
+                for i in k..nn {
+                    let mut p = h[k][i] + q * h[k + 1][i];
+
+                    if not_last {
+                        p += r * h[k + 2][i];
+                        h[k + 2][i] -= p * z;
+                    }
+                    h[k][i] -= p * x;
+                    h[k + 1][i] -= p * y;
+                }
+
+                // Column modification
+                for j in 0..=n.min(k + 3) {
+                    let mut p = x * h[j][k] + y * h[j][k + 1];
+
+                    if not_last {
+                        p += z * h[j][k + 2];
+                        h[j][k + 2] -= p * r;
+                    }
+                    h[j][k] -= p;
+                    h[j][k + 1] -= p * q;
+                }
+
+                // Accumulate transformations
+                for j in 0..nn {
+                    let mut p = x * v[j][k] + y * v[j][k + 1];
+
+                    if not_last {
+                        p += z * v[j][k + 2];
+                        v[j][k + 2] -= p * r;
+                    }
+                    v[j][k] -= p;
+                    v[j][k + 1] -= p * q;
+                }
+            }
+        }
+    }
+
+    // Drop negligible sub-diagonal elements left by deflation
+    for j in 1..nn {
+        if e[j] >= 0. {
+            h[j][j - 1] = 0.;
+        }
+        (0..j.saturating_sub(1)).for_each(|i| h[j][i] = 0.);
+    }
+
+    Some(Schur {
+        t: h,
+        z: v,
+        values_re: d,
+        values_im: e,
+        norm,
+    })
+}
+
+/**
+    Computes eigenvalues and (optionally) eigenvectors of a square matrix from its real Schur form.
+    Returns `None` if the QR algorithm did not converge.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn eig(a: &super::Matrix<f64>, vectors: bool) -> Option<Eig> {
+    let Schur {
+        t: mut h,
+        z: mut v,
+        values_re: d,
+        values_im: e,
+        norm,
+    } = schur(a)?;
+    let nn = d.len();
+    let values_re = crate::vertex::Vertex::new(d.as_slice());
+    let values_im = crate::vertex::Vertex::new(e.as_slice());
+
+    if !vectors {
+        return Some(Eig {
+            values_re,
+            values_im,
+            vectors_re: super::Matrix::empty(),
+            vectors_im: super::Matrix::empty(),
+        });
+    }
+
+    if norm != 0. {
+        h = back_substitute(h, &d, &e, norm);
+
+        // Back transformation to get eigenvectors of original matrix
+        for i in (0..nn).rev() {
+            for j in 0..nn {
+                v[j][i] = (0..=i).map(|k| v[j][k] * h[k][i]).sum();
+            }
+        }
+    }
+
+    let (vectors_re, vectors_im) = unpack_vectors(&v, &e);
+
+    Some(Eig {
+        values_re,
+        values_im,
+        vectors_re,
+        vectors_im,
+    })
+}
+
+/**
+    Computes eigenvectors of the quasi upper triangular matrix left by the QR algorithm
+    with back substitution. Returns `h` with the eigenvectors stored in its upper triangle.
+*/
+fn back_substitute(
+    mut h: super::Matrix<f64>,
+    d: &[f64],
+    e: &[f64],
+    norm: f64,
+) -> super::Matrix<f64> {
+    use crate::complex::Complex;
+
+    let nn = d.len();
+
+    for n in (0..nn).rev() {
+        let p = d[n];
+        let q = e[n];
+
+        if q == 0. {
+            // Real vector
+            let mut l = n;
+            let (mut z, mut s) = (0., 0.);
+
+            h[n][n] = 1.;
+            for j in (0..n).rev() {
+                let w = h[j][j] - p;
+                let r: f64 = (l..=n).map(|i| h[j][i] * h[i][n]).sum();
+
+                if e[j] < 0. {
+                    z = w;
+                    s = r;
+
+                    continue;
+                }
+
+                l = j;
+                if e[j] == 0. {
+                    h[j][n] = if w != 0. {
+                        -r / w
+                    } else {
+                        -r / (EPSILON * norm)
+                    };
+                } else {
+                    // Solve real equations
+                    let x = h[j][j + 1];
+                    let y = h[j + 1][j];
+                    let q = (d[j] - p).powi(2) + e[j].powi(2);
+                    let t = (x * s - z * r) / q;
+
+                    h[j][n] = t;
+                    h[j + 1][n] = if x.abs() > z.abs() {
+                        (-r - w * t) / x
+                    } else {
+                        (-s - y * t) / z
+                    };
+                }
+
+                // Overflow control
+                let t = h[j][n].abs();
+                if (EPSILON * t) * t > 1. {
+                    for i in j..=n {
+                        h[i][n] /= t;
+                    }
+                }
+            }
+        } else if q < 0. {
+            // Complex vector
+            let mut l = n - 1;
+            let (mut z, mut r, mut s) = (0., 0., 0.);
+
+            // Last vector component imaginary so matrix is triangular
+            if h[n][n - 1].abs() > h[n - 1][n].abs() {
+                h[n - 1][n - 1] = q / h[n][n - 1];
+                h[n - 1][n] = -(h[n][n] - p) / h[n][n - 1];
+            } else {
+                let c = Complex::new(0., -h[n - 1][n]) / Complex::new(h[n - 1][n - 1] - p, q);
+
+                h[n - 1][n - 1] = c.re();
+                h[n - 1][n] = c.im();
+            }
+            h[n][n - 1] = 0.;
+            h[n][n] = 1.;
+
+            for j in (0..(n - 1)).rev() {
+                let ra: f64 = (l..=n).map(|i| h[j][i] * h[i][n - 1]).sum();
+                let sa: f64 = (l..=n).map(|i| h[j][i] * h[i][n]).sum();
+                let w = h[j][j] - p;
+
+                if e[j] < 0. {
+                    z = w;
+                    r = ra;
+                    s = sa;
+
+                    continue;
+                }
+
+                l = j;
+                if e[j] == 0. {
+                    let c = Complex::new(-ra, -sa) / Complex::new(w, q);
+
+                    h[j][n - 1] = c.re();
+                    h[j][n] = c.im();
+                } else {
+                    // Solve complex equations
+                    let x = h[j][j + 1];
+                    let y = h[j + 1][j];
+                    let mut vr = (d[j] - p).powi(2) + e[j].powi(2) - q.powi(2);
+                    let vi = (d[j] - p) * 2. * q;
+
+                    if vr == 0. && vi == 0. {
+                        vr = EPSILON * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                    }
+
+                    let c = Complex::new(x * r - z * ra + q * sa, x * s - z * sa - q * ra)
+                        / Complex::new(vr, vi);
+
+                    h[j][n - 1] = c.re();
+                    h[j][n] = c.im();
+                    if x.abs() > z.abs() + q.abs() {
+                        h[j + 1][n - 1] = (-ra - w * h[j][n - 1] + q * h[j][n]) / x;
+                        h[j + 1][n] = (-sa - w * h[j][n] - q * h[j][n - 1]) / x;
+                    } else {
+                        let c = Complex::new(-r - y * h[j][n - 1], -s - y * h[j][n])
+                            / Complex::new(z, q);
+
+                        h[j + 1][n - 1] = c.re();
+                        h[j + 1][n] = c.im();
+                    }
+                }
+
+                // Overflow control
+                let t = h[j][n - 1].abs().max(h[j][n].abs());
+                if (EPSILON * t) * t > 1. {
+                    for i in j..=n {
+                        h[i][n - 1] /= t;
+                        h[i][n] /= t;
+                    }
+                }
+            }
+        }
+    }
+
+    h
+}
+
+/**
+    Splits eigenvectors packed as real and imaginary columns into
+    normalized real and imaginary parts of every eigenvector
+*/
+fn unpack_vectors(v: &super::Matrix<f64>, e: &[f64]) -> (super::Matrix<f64>, super::Matrix<f64>) {
+    let n = e.len();
+    let mut re = super::Matrix::<f64> {
+        m: vec![0.; n * n],
+        size: [n, n],
+    };
+    let mut im = re.clone();
+
+    let mut i = 0;
+    while i < n {
+        if e[i] == 0. {
+            let norm = (0..n).map(|j| v[j][i].powi(2)).sum::<f64>().sqrt();
+
+            (0..n).for_each(|j| re[j][i] = v[j][i] / norm);
+            i += 1;
+        } else {
+            let norm = (0..n)
+                .map(|j| v[j][i].powi(2) + v[j][i + 1].powi(2))
+                .sum::<f64>()
+                .sqrt();
+
+            for j in 0..n {
+                re[j][i] = v[j][i] / norm;
+                im[j][i] = v[j][i + 1] / norm;
+                re[j][i + 1] = re[j][i];
+                im[j][i + 1] = -im[j][i];
+            }
+            i += 2;
+        }
+    }
+
+    (re, im)
+}
+
+impl<T> crate::linear::EigenDecomposition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn eig(&self) -> crate::error::SlalErr<Eig, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
+
+        match eig(&self.to_f64(), true) {
+            Some(eig) => Ok(eig),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", max_iteration(self.size[0])),
+            )),
+        }
+    }
+
+    fn eigenvalues(
+        &self,
+    ) -> crate::error::SlalErr<(crate::vertex::Vertex<f64>, crate::vertex::Vertex<f64>), T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
+
+        match eig(&self.to_f64(), false) {
+            Some(eig) => Ok((eig.values_re, eig.values_im)),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", max_iteration(self.size[0])),
+            )),
+        }
+    }
+}
+
+/**
+    Eigendecomposition of a symmetric matrix (A = V diag(values) V^T).
+
+    Eigenvalues are real and sorted in ascending order.
+    Column `i` of `V` is the unit eigenvector of eigenvalue `i` and `V` is orthogonal.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEig {
+    values: crate::vertex::Vertex<f64>,
+    vectors: super::Matrix<f64>,
+}
+
+impl SymmetricEig {
+    /**
+    Returns the eigenvalues in ascending order
+
+    # Example
+    ```
+    use slal::linear::SymmetricEigen;
+    use slal::matrix::Matrix;
+    use slal::vertex::Vertex;
+
+    let m = Matrix::new(&[&[3, 0], &[0, 2]]).unwrap();
+
+    assert!(m.symmetric_eigen().unwrap().values() == &Vertex::new(&[2., 3.]));
+    ```
+     */
+    pub fn values(&self) -> &crate::vertex::Vertex<f64> {
+        &self.values
+    }
+
+    /**
+    Returns the orthonormal eigenvectors as columns of a matrix
+
+    # Example
+    ```
+    use slal::linear::SymmetricEigen;
+    use slal::matrix::Matrix;
+
+    let m = Matrix::new(&[&[3, 0], &[0, 2]]).unwrap();
+
+    assert!(m.symmetric_eigen().unwrap().vectors() == &Matrix::new(&[&[0., 1.], &[1., 0.]]).unwrap());
+    ```
+     */
+    pub fn vectors(&self) -> &super::Matrix<f64> {
+        &self.vectors
+    }
+}
+
+/**
+    Reduces a symmetric matrix to tridiagonal form with Householder transformations.
+    Returns the diagonal, the sub-diagonal (shifted by one, `e[0] = 0`)
+    and the accumulated orthogonal transformation.
+*/
+fn tridiagonalize(a: &super::Matrix<f64>) -> (Vec<f64>, Vec<f64>, super::Matrix<f64>) {
+    let n = a.size[0];
+    let mut v = a.clone();
+    let mut d: Vec<f64> = (0..n).map(|i| v[n - 1][i]).collect();
+    let mut e = vec![0.; n];
+
+    for i in (1..n).rev() {
+        // Scale to avoid under/overflow
+        let scale: f64 = d[..i].iter().map(|d| d.abs()).sum();
+        let mut h = 0.;
+
+        if scale == 0. {
+            e[i] = d[i - 1];
+            for j in 0..i {
+                d[j] = v[i - 1][j];
+                v[i][j] = 0.;
+                v[j][i] = 0.;
+            }
+        } else {
+            // Generate Householder vector
+            d[..i].iter_mut().for_each(|d| {
+                *d /= scale;
+                h += *d * *d;
+            });
+
+            let f = d[i - 1];
+            let g = if f > 0. { -h.sqrt() } else { h.sqrt() };
+
+            e[i] = scale * g;
+            h -= f * g;
+            d[i - 1] = f - g;
+            e[..i].iter_mut().for_each(|e| *e = 0.);
+
+            // Apply similarity transformation to remaining columns
+            for j in 0..i {
+                let f = d[j];
+                let mut g = e[j] + v[j][j] * f;
+
+                v[j][i] = f;
+                for k in (j + 1)..i {
+                    g += v[k][j] * d[k];
+                    e[k] += v[k][j] * f;
+                }
+                e[j] = g;
+            }
+
+            let mut f = 0.;
+            for j in 0..i {
+                e[j] /= h;
+                f += e[j] * d[j];
+            }
+
+            let hh = f / (h + h);
+            (0..i).for_each(|j| e[j] -= hh * d[j]);
+
+            for j in 0..i {
+                let (f, g) = (d[j], e[j]);
+
+                for k in j..i {
+                    v[k][j] -= f * e[k] + g * d[k];
+                }
+                d[j] = v[i - 1][j];
+                v[i][j] = 0.;
+            }
+        }
+
+        d[i] = h;
+    }
+
+    // Accumulate transformations
+    for i in 0..(n - 1) {
+        v[n - 1][i] = v[i][i];
+        v[i][i] = 1.;
+
+        let h = d[i + 1];
+        if h != 0. {
+            (0..=i).for_each(|k| d[k] = v[k][i + 1] / h);
+            for j in 0..=i {
+                let g: f64 = (0..=i).map(|k| v[k][i + 1] * v[k][j]).sum();
+
+                (0..=i).for_each(|k| v[k][j] -= g * d[k]);
+            }
+        }
+        (0..=i).for_each(|k| v[k][i + 1] = 0.);
+    }
+    for i in 0..n {
+        d[i] = v[n - 1][i];
+        v[n - 1][i] = 0.;
+    }
+    v[n - 1][n - 1] = 1.;
+    e[0] = 0.;
+
+    (d, e, v)
+}
+
+/**
+    Computes eigenvalues and eigenvectors of a symmetric matrix
+    by tridiagonalization and the implicit QL algorithm.
+    Returns `None` if the QL algorithm did not converge.
+    Assumes the matrix is a non-empty symmetric matrix.
+*/
+pub(crate) fn symmetric_eig(a: &super::Matrix<f64>) -> Option<SymmetricEig> {
+    let n = a.size[0];
+    let (mut d, mut e, mut v) = tridiagonalize(a);
+    let mut f = 0.;
+    let mut tst1: f64 = 0.;
+    let mut total_iteration = 0;
+
+    e.rotate_left(1);
+    e[n - 1] = 0.;
+
+    for l in 0..n {
+        // Find small sub-diagonal element
+        tst1 = tst1.max(d[l].abs() + e[l].abs());
+
+        let m = (l..n)
+            .find(|&m| e[m].abs() <= EPSILON * tst1)
+            .unwrap_or(n - 1);
+
+        // If m == l, d[l] is an eigenvalue, otherwise iterate
+        while m > l && e[l].abs() > EPSILON * tst1 {
+            if total_iteration >= max_iteration(n) {
+                return None;
+            }
+            total_iteration += 1;
+
+            // Compute implicit shift
+            let g = d[l];
+            let mut p = (d[l + 1] - g) / (2. * e[l]);
+            let r = if p < 0. { -p.hypot(1.) } else { p.hypot(1.) };
+
+            d[l] = e[l] / (p + r);
+            d[l + 1] = e[l] * (p + r);
+
+            let dl1 = d[l + 1];
+            let h = g - d[l];
+
+            d[(l + 2)..].iter_mut().for_each(|d| *d -= h);
+            f += h;
+
+            // Implicit QL transformation
+            p = d[m];
+
+            let el1 = e[l + 1];
+            let (mut c, mut c2, mut c3) = (1., 1., 1.);
+            let (mut s, mut s2) = (0., 0.);
+
+            for i in (l..m).rev() {
+                c3 = c2;
+                c2 = c;
+                s2 = s;
+
+                let g = c * e[i];
+                let h = c * p;
+                let r = p.hypot(e[i]);
+
+                e[i + 1] = s * r;
+                s = e[i] / r;
+                c = p / r;
+                p = c * d[i] - s * g;
+                d[i + 1] = h + s * (c * g + s * d[i]);
+
+                // Accumulate transformation
+                for k in 0..n {
+                    let h = v[k][i + 1];
+
+                    v[k][i + 1] = s * v[k][i] + c * h;
+                    v[k][i] = c * v[k][i] - s * h;
+                }
+            }
+
+            p = -s * s2 * c3 * el1 * e[l] / dl1;
+            e[l] = s * p;
+            d[l] = c * p;
+        }
+
+        d[l] += f;
+        e[l] = 0.;
+    }
+
+    // Sort eigenvalues and corresponding vectors in ascending order
+    for i in 0..(n - 1) {
+        let k = ((i + 1)..n).fold(i, |k, j| if d[j] < d[k] { j } else { k });
+
+        if k != i {
+            d.swap(i, k);
+            (0..n).for_each(|j| v[j].swap(i, k));
+        }
+    }
+
+    Some(SymmetricEig {
+        values: crate::vertex::Vertex::new(d.as_slice()),
+        vectors: v,
+    })
+}
+
+impl<T> crate::linear::SymmetricEigen<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn symmetric_eigen(&self) -> crate::error::SlalErr<SymmetricEig, T> {
+        use crate::error::SlalError;
+
+        let m = super::decomposition::symmetric_matrix(self)?;
+
+        match symmetric_eig(&m) {
+            Some(eig) => Ok(eig),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", max_iteration(self.size[0])),
+            )),
+        }
+    }
+}
@@ -0,0 +1,320 @@
+use super::Matrix;
+use crate::error::SlalError;
+use crate::linear::{EigenDecomposition, SymmetricEigen};
+use crate::vertex::Vertex;
+
+const TOLERANCE: f64 = 1e-10;
+
+// Maximum of |Av - λv| over all eigenpairs, computed in complex arithmetic
+fn eigen_residual(a: &Matrix<f64>, eig: &crate::matrix::Eig) -> f64 {
+    let n = a.size[0];
+    let (re, im) = (eig.vectors_re(), eig.vectors_im());
+    let mut residual: f64 = 0.;
+
+    for k in 0..n {
+        let (lr, li) = (eig.values_re()[k], eig.values_im()[k]);
+
+        for j in 0..n {
+            let av_re: f64 = (0..n).map(|i| a[j][i] * re[i][k]).sum();
+            let av_im: f64 = (0..n).map(|i| a[j][i] * im[i][k]).sum();
+
+            residual = residual
+                .max((av_re - (lr * re[j][k] - li * im[j][k])).abs())
+                .max((av_im - (lr * im[j][k] + li * re[j][k])).abs());
+        }
+    }
+
+    residual
+}
+
+fn sorted(v: &Vertex<f64>) -> Vec<f64> {
+    let mut v = v.to_vec();
+
+    v.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    v
+}
+
+#[test]
+fn eig_real() {
+    let m = Matrix::new(&[&[4, 1], &[2, 3]]).unwrap();
+
+    match m.eig() {
+        Ok(eig) => {
+            let values = sorted(eig.values_re());
+
+            assert!((values[0] - 2.).abs() < TOLERANCE);
+            assert!((values[1] - 5.).abs() < TOLERANCE);
+            assert!(eig.is_real());
+            assert!(eigen_residual(&Matrix::<f64>::from(m), &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_complex() {
+    let m = Matrix::new(&[&[0, -1], &[1, 0]]).unwrap();
+
+    match m.eig() {
+        Ok(eig) => {
+            assert!(eig.values_re() == &Vertex::new(&[0., 0.]));
+            assert!(eig.values_im() == &Vertex::new(&[1., -1.]));
+            assert!(!eig.is_real());
+            assert!(eigen_residual(&Matrix::<f64>::from(m), &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_mixed() {
+    let m = Matrix::new(&[
+        &[1., 2., 3., 4., -1.],
+        &[-2., 0., 1., 5., 2.],
+        &[3., -1., 2., 0., 1.],
+        &[0., 4., -3., 1., 2.],
+        &[1., 1., -1., 2., 3.],
+    ])
+    .unwrap();
+
+    match m.eig() {
+        Ok(eig) => {
+            let trace: f64 = (0..5).map(|ij| eig.values_re()[ij]).sum();
+            let imaginary: f64 = (0..5).map(|ij| eig.values_im()[ij]).sum();
+
+            assert!((trace - 7.).abs() < TOLERANCE);
+            assert!(imaginary.abs() < TOLERANCE);
+            assert!(eigen_residual(&m, &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_unit_vectors() {
+    let m = Matrix::new(&[&[2., 1., 0.], &[-1., 2., 1.], &[0., -1., 2.]]).unwrap();
+
+    match m.eig() {
+        Ok(eig) => (0..3).for_each(|i| {
+            let norm: f64 = (0..3)
+                .map(|j| eig.vectors_re()[j][i].powi(2) + eig.vectors_im()[j][i].powi(2))
+                .sum();
+
+            assert!((norm - 1.).abs() < TOLERANCE);
+        }),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_repeated() {
+    let m = Matrix::new(&[&[3, 1, 0], &[0, 3, 0], &[0, 0, 3]]).unwrap();
+
+    match m.eig() {
+        Ok(eig) => {
+            assert!(eig.values_re() == &Vertex::new(&[3., 3., 3.]));
+            assert!(eig.is_real());
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_defective() {
+    use crate::linear::Condition;
+
+    let m = Matrix::new(&[&[1, 1], &[0, 1]]).unwrap();
+    let eig = m.eig().unwrap();
+
+    // Only one independent eigenvector exists, so both columns are (almost) parallel
+    assert!(eig.values_re() == &Vertex::new(&[1., 1.]));
+    assert!(eigen_residual(&m.to_f64(), &eig) < TOLERANCE);
+    assert!(eig.vectors_re().rcond().unwrap() < 1e-10);
+}
+
+#[test]
+fn eig_companion() {
+    // Roots of x^4 - 10x^3 + 35x^2 - 50x + 24 = (x - 1)(x - 2)(x - 3)(x - 4)
+    let m = Matrix::new(&[
+        &[10, -35, 50, -24],
+        &[1, 0, 0, 0],
+        &[0, 1, 0, 0],
+        &[0, 0, 1, 0],
+    ])
+    .unwrap();
+
+    match m.eigenvalues() {
+        Ok((re, im)) => {
+            sorted(&re)
+                .iter()
+                .zip([1., 2., 3., 4.])
+                .for_each(|(value, root)| assert!((value - root).abs() < 1e-8));
+            assert!(im == Vertex::new(&[0., 0., 0., 0.]));
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn eig_deterministic() {
+    let m = Matrix::new(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 10]]).unwrap();
+
+    assert!(m.eig() == m.eig());
+}
+
+#[test]
+fn eigenvalues_match_eig() {
+    let m = Matrix::new(&[&[1, 2, 3], &[-4, 5, 6], &[7, -8, 10]]).unwrap();
+
+    match (m.eig(), m.eigenvalues()) {
+        (Ok(eig), Ok((re, im))) => {
+            assert!(eig.values_re() == &re);
+            assert!(eig.values_im() == &im);
+        }
+        _ => panic!("Failed to compute eigenvalues"),
+    }
+}
+
+#[test]
+fn eig_not_square() {
+    let m = Matrix::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    match m.eig() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
+
+#[test]
+fn eig_empty() {
+    let m = Matrix::<f64>::empty();
+
+    match m.eigenvalues() {
+        Err(SlalError::EmptyMatrix(_)) => (),
+        _ => panic!("Expected EmptyMatrix"),
+    }
+}
+
+// Maximum of |V^T V - I| and |AV - V diag(values)|
+fn symmetric_residual(a: &Matrix<f64>, eig: &crate::matrix::SymmetricEig) -> f64 {
+    let n = a.size[0];
+    let v = eig.vectors();
+    let mut residual: f64 = 0.;
+
+    for j in 0..n {
+        for i in 0..n {
+            let vtv: f64 = (0..n).map(|k| v[k][j] * v[k][i]).sum();
+            let av: f64 = (0..n).map(|k| a[j][k] * v[k][i]).sum();
+
+            residual = residual
+                .max((vtv - if i == j { 1. } else { 0. }).abs())
+                .max((av - v[j][i] * eig.values()[i]).abs());
+        }
+    }
+
+    residual
+}
+
+#[test]
+fn symmetric_eigen() {
+    let m = Matrix::new(&[&[4, 1, 2], &[1, 3, 0], &[2, 0, 5]]).unwrap();
+
+    match m.symmetric_eigen() {
+        Ok(eig) => {
+            let values = eig.values().to_vec();
+
+            assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+            assert!((values.iter().sum::<f64>() - 12.).abs() < TOLERANCE);
+            assert!(symmetric_residual(&Matrix::<f64>::from(m), &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn symmetric_eigen_laplacian() {
+    // Laplacian of path graph with 4 vertices has eigenvalues 2 - 2cos(kπ/4)
+    let m = Matrix::new(&[
+        &[1, -1, 0, 0],
+        &[-1, 2, -1, 0],
+        &[0, -1, 2, -1],
+        &[0, 0, -1, 1],
+    ])
+    .unwrap();
+
+    match m.symmetric_eigen() {
+        Ok(eig) => {
+            (0..4).for_each(|k| {
+                let expected = 2. - 2. * (k as f64 * std::f64::consts::PI / 4.).cos();
+
+                assert!((eig.values()[k] - expected).abs() < TOLERANCE);
+            });
+            assert!(symmetric_residual(&Matrix::<f64>::from(m), &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn symmetric_eigen_repeated() {
+    let m = Matrix::new(&[&[2., 0., 0.], &[0., 2., 0.], &[0., 0., 2.]]).unwrap();
+
+    match m.symmetric_eigen() {
+        Ok(eig) => {
+            assert!(eig.values() == &Vertex::new(&[2., 2., 2.]));
+            assert!(symmetric_residual(&m, &eig) < TOLERANCE);
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn symmetric_eigen_single() {
+    let m = Matrix::new(&[&[-3]]).unwrap();
+
+    match m.symmetric_eigen() {
+        Ok(eig) => {
+            assert!(eig.values() == &Vertex::new(&[-3.]));
+            assert!(eig.vectors() == &Matrix::new(&[&[1.]]).unwrap());
+        }
+        Err(err) => panic!("{}", err),
+    }
+}
+
+#[test]
+fn symmetric_eigen_deterministic() {
+    let m = Matrix::new(&[&[1, 2, 3], &[2, 5, 6], &[3, 6, 10]]).unwrap();
+
+    assert!(m.symmetric_eigen() == m.symmetric_eigen());
+}
+
+#[test]
+fn symmetric_eigen_not_symmetric() {
+    let m = Matrix::new(&[&[1, 2], &[3, 4]]).unwrap();
+
+    match m.symmetric_eigen() {
+        Err(SlalError::NotSymmetricMatrix(_)) => (),
+        _ => panic!("Expected NotSymmetricMatrix"),
+    }
+}
+
+#[test]
+fn symmetric_eigen_not_square() {
+    let m = Matrix::new(&[&[1, 2, 3], &[2, 5, 6]]).unwrap();
+
+    match m.symmetric_eigen() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
+
+#[test]
+fn eig_zero() {
+    let m = Matrix::new(&[&[0, 0], &[0, 0]]).unwrap();
+    let eig = m.eig().unwrap();
+
+    assert!(eig.values_re() == &Vertex::new(&[0., 0.]));
+    assert!(eig.vectors_re().m.iter().all(|v| v.is_finite()));
+    assert!(eigen_residual(&Matrix::<f64>::from(m), &eig) < TOLERANCE);
+}
@@ -0,0 +1,611 @@
+// Padé degrees for the matrix exponential and the largest 1-norms they reach double precision for (Higham 2005)
+const EXPM_THETA: [(usize, f64); 5] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068),
+    (13, 5.371920351148152),
+];
+// Degree of the Padé approximant of log(I + X) and the largest 1-norm of X it is used for
+const LOGM_DEGREE: usize = 8;
+const LOGM_THETA: f64 = 0.25;
+// Maximum number of square roots taken before the Padé approximant of the logarithm
+const LOGM_MAX_SQUARE_ROOT: usize = 64;
+
+/**
+    Coefficients of the numerator of the diagonal Padé approximant of degree `m` to the exponential
+*/
+fn pade_coefficients(m: usize) -> &'static [f64] {
+    match m {
+        3 => &[120., 60., 12., 1.],
+        5 => &[30240., 15120., 3360., 420., 30., 1.],
+        7 => &[
+            17297280., 8648640., 1995840., 277200., 25200., 1512., 56., 1.,
+        ],
+        9 => &[
+            17643225600.,
+            8821612800.,
+            2075673600.,
+            302702400.,
+            30270240.,
+            2162160.,
+            110880.,
+            3960.,
+            90.,
+            1.,
+        ],
+        _ => &[
+            64764752532480000.,
+            32382376266240000.,
+            7771770303897600.,
+            1187353796428800.,
+            129060195264000.,
+            10559470521600.,
+            670442572800.,
+            33522128640.,
+            1323241920.,
+            40840800.,
+            960960.,
+            16380.,
+            182.,
+            1.,
+        ],
+    }
+}
+
+fn identity(n: usize) -> super::Matrix<f64> {
+    let mut m = vec![0.; n * n];
+    (0..n).for_each(|ij| m[ij * n + ij] = 1.);
+
+    super::Matrix { m, size: [n, n] }
+}
+
+/**
+    Product `AB` of square matrices of the same size
+*/
+fn multiply(a: &super::Matrix<f64>, b: &super::Matrix<f64>) -> super::Matrix<f64> {
+    let n = a.size[0];
+    let mut m = vec![0.; n * n];
+
+    crate::parallel::for_each_chunk_mut(&mut m, n, |j, row| {
+        a[j].iter().zip(0..n).for_each(|(a_jk, k)| {
+            if *a_jk != 0. {
+                row.iter_mut()
+                    .zip(b[k].iter())
+                    .for_each(|(m_ji, b_ki)| *m_ji += a_jk * b_ki);
+            }
+        })
+    });
+
+    super::Matrix { m, size: [n, n] }
+}
+
+/**
+    Product `AB^T` of square matrices of the same size
+*/
+fn multiply_transposed(a: &super::Matrix<f64>, b: &super::Matrix<f64>) -> super::Matrix<f64> {
+    let n = a.size[0];
+    let mut m = vec![0.; n * n];
+
+    crate::parallel::for_each_chunk_mut(&mut m, n, |j, row| {
+        row.iter_mut().enumerate().for_each(|(i, m_ji)| {
+            *m_ji = a[j]
+                .iter()
+                .zip(b[i].iter())
+                .map(|(a_jk, b_ik)| a_jk * b_ik)
+                .sum()
+        })
+    });
+
+    super::Matrix { m, size: [n, n] }
+}
+
+/**
+    Linear combination `sum c_k A_k` of matrices of the same size
+*/
+fn combine(terms: &[(f64, &super::Matrix<f64>)]) -> super::Matrix<f64> {
+    let mut m = vec![0.; terms[0].1.m.len()];
+
+    terms.iter().for_each(|(c, a)| {
+        m.iter_mut()
+            .zip(a.m.iter())
+            .for_each(|(m_i, a_i)| *m_i += c * a_i)
+    });
+
+    super::Matrix {
+        m,
+        size: terms[0].1.size,
+    }
+}
+
+/**
+    Diagonal Padé approximant of degree `m` to the exponential
+*/
+fn pade(a: &super::Matrix<f64>, m: usize) -> super::Matrix<f64> {
+    let b = pade_coefficients(m);
+    let identity = identity(a.size[0]);
+    let a2 = multiply(a, a);
+
+    let (u, v) = if m == 13 {
+        let a4 = multiply(&a2, &a2);
+        let a6 = multiply(&a4, &a2);
+        let u = multiply(&a6, &combine(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)]));
+        let u = multiply(
+            a,
+            &combine(&[
+                (1., &u),
+                (b[7], &a6),
+                (b[5], &a4),
+                (b[3], &a2),
+                (b[1], &identity),
+            ]),
+        );
+        let v = multiply(&a6, &combine(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)]));
+        let v = combine(&[
+            (1., &v),
+            (b[6], &a6),
+            (b[4], &a4),
+            (b[2], &a2),
+            (b[0], &identity),
+        ]);
+
+        (u, v)
+    } else {
+        // Even powers I, A^2, ..., A^(m - 1)
+        let mut powers = vec![identity, a2];
+        while powers.len() <= m / 2 {
+            let power = multiply(&powers[powers.len() - 1], &powers[1]);
+
+            powers.push(power);
+        }
+
+        let odd: Vec<(f64, &super::Matrix<f64>)> = powers
+            .iter()
+            .enumerate()
+            .map(|(k, power)| (b[2 * k + 1], power))
+            .collect();
+        let even: Vec<(f64, &super::Matrix<f64>)> = powers
+            .iter()
+            .enumerate()
+            .map(|(k, power)| (b[2 * k], power))
+            .collect();
+
+        (multiply(a, &combine(&odd)), combine(&even))
+    };
+
+    // Solve (V - U)X = V + U
+    super::decomposition::lu(&combine(&[(1., &v), (-1., &u)]))
+        .substitute_matrix(&combine(&[(1., &v), (1., &u)]))
+}
+
+/**
+    Computes the matrix exponential with the scaling and squaring method.
+    Assumes the matrix is a non-empty square matrix.
+*/
+pub(crate) fn expm(a: &super::Matrix<f64>) -> super::Matrix<f64> {
+    let norm = super::norm::norm_1(a);
+
+    if !norm.is_finite() {
+        return super::Matrix {
+            m: vec![f64::NAN; a.m.len()],
+            size: a.size,
+        };
+    }
+
+    let (m, theta) = EXPM_THETA
+        .iter()
+        .find(|(_, theta)| norm <= *theta)
+        .copied()
+        .unwrap_or(EXPM_THETA[EXPM_THETA.len() - 1]);
+    let s = if norm > theta {
+        (norm / theta).log2().ceil() as i32
+    } else {
+        0
+    };
+
+    let mut x = pade(&combine(&[(0.5f64.powi(s), a)]), m);
+    for _ in 0..s {
+        x = multiply(&x, &x);
+    }
+
+    x
+}
+
+/**
+    Diagonal blocks (first index, size) of a quasi upper triangular Schur form
+*/
+fn blocks(values_im: &[f64]) -> Vec<(usize, usize)> {
+    let mut blocks = Vec::new();
+    let mut i = 0;
+
+    while i < values_im.len() {
+        let size = if values_im[i] == 0. { 1 } else { 2 };
+
+        blocks.push((i, size));
+        i += size;
+    }
+
+    blocks
+}
+
+/**
+    Solves a small linear system with Gaussian elimination with partial pivoting.
+    A singular system is only accepted with zero right-hand side, in which case the trivial solution is returned.
+*/
+fn solve_small(mut a: Vec<f64>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+
+    for k in 0..n {
+        let pivot = (k..n).fold(k, |pivot, j| {
+            if a[j * n + k].abs() > a[pivot * n + k].abs() {
+                j
+            } else {
+                pivot
+            }
+        });
+
+        if a[pivot * n + k] == 0. {
+            return if b.iter().all(|b_j| *b_j == 0.) {
+                Some(vec![0.; n])
+            } else {
+                None
+            };
+        }
+
+        (0..n).for_each(|i| a.swap(pivot * n + i, k * n + i));
+        b.swap(pivot, k);
+
+        for j in (k + 1)..n {
+            let factor = a[j * n + k] / a[k * n + k];
+
+            (k..n).for_each(|i| a[j * n + i] -= factor * a[k * n + i]);
+            b[j] -= factor * b[k];
+        }
+    }
+
+    for j in (0..n).rev() {
+        b[j] = (b[j] - ((j + 1)..n).map(|i| a[j * n + i] * b[i]).sum::<f64>()) / a[j * n + j];
+    }
+
+    Some(b)
+}
+
+/**
+    Computes the principal square root of a quasi upper triangular matrix block by block (Higham 1987).
+    Returns `None` if no real principal square root exists.
+*/
+fn sqrt_quasi_triangular(
+    t: &super::Matrix<f64>,
+    blocks: &[(usize, usize)],
+) -> Option<super::Matrix<f64>> {
+    let n = t.size[0];
+    let mut r = super::Matrix::<f64> {
+        m: vec![0.; n * n],
+        size: [n, n],
+    };
+
+    // Diagonal blocks
+    for (i, p) in blocks.iter().copied() {
+        if p == 1 {
+            if t[i][i] < 0. {
+                return None;
+            }
+            r[i][i] = t[i][i].sqrt();
+
+            continue;
+        }
+
+        // Square root alpha + i beta of the eigenvalue theta + i mu of the 2x2 block
+        let theta = (t[i][i] + t[i + 1][i + 1]) / 2.;
+        let mu = (-(t[i][i] - t[i + 1][i + 1]).powi(2) / 4. - t[i][i + 1] * t[i + 1][i])
+            .max(0.)
+            .sqrt();
+        let modulus = theta.hypot(mu);
+        let alpha = if theta >= 0. {
+            ((modulus + theta) / 2.).sqrt()
+        } else {
+            mu / (2. * ((modulus - theta) / 2.).sqrt())
+        };
+
+        if alpha == 0. || !alpha.is_finite() {
+            return None;
+        }
+
+        for j in i..(i + 2) {
+            for k in i..(i + 2) {
+                r[j][k] = if j == k {
+                    alpha + (t[j][k] - theta) / (2. * alpha)
+                } else {
+                    t[j][k] / (2. * alpha)
+                };
+            }
+        }
+    }
+
+    // Off-diagonal blocks from the Sylvester equations R_ii X + X R_jj = T_ij - sum R_ik R_kj
+    for (block_j, (j, q)) in blocks.iter().copied().enumerate() {
+        for (i, p) in blocks[..block_j].iter().copied().rev() {
+            let mut a = vec![0.; p * q * p * q];
+            let mut b = vec![0.; p * q];
+
+            for row in 0..p {
+                for column in 0..q {
+                    let idx = row * q + column;
+
+                    b[idx] = t[i + row][j + column]
+                        - ((i + p)..j)
+                            .map(|k| r[i + row][k] * r[k][j + column])
+                            .sum::<f64>();
+                    (0..p).for_each(|k| a[idx * p * q + k * q + column] += r[i + row][i + k]);
+                    (0..q).for_each(|k| a[idx * p * q + row * q + k] += r[j + k][j + column]);
+                }
+            }
+
+            let x = solve_small(a, b)?;
+            for row in 0..p {
+                for column in 0..q {
+                    r[i + row][j + column] = x[row * q + column];
+                }
+            }
+        }
+    }
+
+    Some(r)
+}
+
+/**
+    Nodes and weights of the Gauss-Legendre quadrature of degree `m` on [0, 1]
+*/
+fn gauss_legendre(m: usize) -> Vec<(f64, f64)> {
+    (0..m)
+        .map(|i| {
+            // Newton's method on the Legendre polynomial P_m on [-1, 1]
+            let mut x = (std::f64::consts::PI * (i as f64 + 0.75) / (m as f64 + 0.5)).cos();
+            let mut derivative = 1.;
+
+            for _ in 0..100 {
+                let (mut p, mut p_previous) = (x, 1.);
+                for k in 2..=m {
+                    (p, p_previous) = (
+                        ((2 * k - 1) as f64 * x * p - (k - 1) as f64 * p_previous) / k as f64,
+                        p,
+                    );
+                }
+                derivative = m as f64 * (x * p - p_previous) / (x.powi(2) - 1.);
+
+                let step = p / derivative;
+                x -= step;
+                if step.abs() <= f64::EPSILON {
+                    break;
+                }
+            }
+
+            ((1. + x) / 2., 1. / ((1. - x.powi(2)) * derivative.powi(2)))
+        })
+        .collect()
+}
+
+/**
+    Computes the principal square root from the real Schur form (A = ZTZ^T).
+    Returns `None` if no real principal square root exists.
+*/
+pub(crate) fn sqrtm(schur: &super::eigen::Schur) -> Option<super::Matrix<f64>> {
+    let r = sqrt_quasi_triangular(&schur.t, &blocks(&schur.values_im))?;
+
+    Some(multiply_transposed(&multiply(&schur.z, &r), &schur.z))
+}
+
+/**
+    Computes the principal logarithm from the real Schur form (A = ZTZ^T)
+    with the inverse scaling and squaring method (Higham 2001).
+    Returns `None` if no real principal logarithm exists.
+*/
+pub(crate) fn logm(schur: &super::eigen::Schur) -> Option<super::Matrix<f64>> {
+    let blocks = blocks(&schur.values_im);
+
+    if blocks.iter().any(|(i, p)| *p == 1 && schur.t[*i][*i] <= 0.) {
+        return None;
+    }
+
+    let identity = identity(schur.t.size[0]);
+    let mut r = schur.t.clone();
+    let mut x = combine(&[(1., &r), (-1., &identity)]);
+    let mut square_roots = 0;
+
+    // T^(1 / 2^k) = I + X with small enough X
+    while super::norm::norm_1(&x) > LOGM_THETA && square_roots < LOGM_MAX_SQUARE_ROOT {
+        r = sqrt_quasi_triangular(&r, &blocks)?;
+        x = combine(&[(1., &r), (-1., &identity)]);
+        square_roots += 1;
+    }
+
+    // Partial fraction form of the Padé approximant of log(I + X)
+    let log =
+        gauss_legendre(LOGM_DEGREE)
+            .iter()
+            .fold(combine(&[(0., &x)]), |log, (node, weight)| {
+                let lu = super::decomposition::lu(&combine(&[(1., &identity), (*node, &x)]));
+
+                combine(&[(1., &log), (*weight, &lu.substitute_matrix(&x))])
+            });
+    let log = combine(&[(2f64.powi(square_roots as i32), &log)]);
+
+    Some(multiply_transposed(&multiply(&schur.z, &log), &schur.z))
+}
+
+/**
+    Computes a real power of a diagonalizable matrix from its eigendecomposition (A^p = V D^p V^-1)
+    with principal powers of the eigenvalues.
+    Returns `None` if the eigenvectors are numerically linearly dependent, as for a defective matrix.
+*/
+pub(crate) fn powf(eig: &super::Eig, p: f64) -> Option<super::Matrix<f64>> {
+    let n = eig.values_re().len();
+    let (v_re, v_im) = (eig.vectors_re(), eig.vectors_im());
+    let powers: Vec<(f64, f64)> = (0..n)
+        .map(|k| {
+            let (re, im) = (eig.values_re()[k], eig.values_im()[k]);
+
+            if re == 0. && im == 0. {
+                return (if p == 0. { 1. } else { 0. }, 0.);
+            }
+
+            let (modulus, argument) = (re.hypot(im).powf(p), im.atan2(re) * p);
+
+            (modulus * argument.cos(), modulus * argument.sin())
+        })
+        .collect();
+
+    // Real form of the complex system V^T X^T = (V D^p)^T
+    let size = 2 * n;
+    let mut a = vec![0.; size * size];
+    let mut b = vec![0.; size * n];
+    for j in 0..n {
+        for k in 0..n {
+            let (power_re, power_im) = powers[k];
+
+            a[k * size + j] = v_re[j][k];
+            a[k * size + n + j] = -v_im[j][k];
+            a[(n + k) * size + j] = v_im[j][k];
+            a[(n + k) * size + n + j] = v_re[j][k];
+            b[k * n + j] = v_re[j][k] * power_re - v_im[j][k] * power_im;
+            b[(n + k) * n + j] = v_re[j][k] * power_im + v_im[j][k] * power_re;
+        }
+    }
+
+    let a = super::Matrix {
+        m: a,
+        size: [size, size],
+    };
+
+    // Real form has the same condition number as V, whose columns are parallel if the matrix is defective
+    if super::norm::rcond(&a) < f64::EPSILON.sqrt() {
+        return None;
+    }
+
+    let y = super::decomposition::lu(&a).substitute_matrix(&super::Matrix {
+        m: b,
+        size: [n, size],
+    });
+
+    // Imaginary part of X vanishes as powers of conjugate eigenvalues are conjugate
+    let mut x = vec![0.; n * n];
+    for j in 0..n {
+        for i in 0..n {
+            x[j * n + i] = y[i][j];
+        }
+    }
+
+    Some(super::Matrix { m: x, size: [n, n] })
+}
+
+/**
+    Converts a matrix into `Matrix<f64>` after checking that it is a non-empty square matrix
+*/
+fn square_matrix<T>(m: &super::Matrix<T>) -> crate::error::SlalErr<super::Matrix<f64>, T>
+where
+    T: crate::scalar::RealScalar,
+{
+    use crate::error::SlalError;
+
+    if m.m.is_empty() {
+        return Err(SlalError::EmptyMatrix(format!("{:?}", *m)));
+    }
+
+    if m.size[0] != m.size[1] {
+        return Err(SlalError::NotSquareMatrix(
+            format!("{:?}", *m),
+            format!("{}", m.size[0]),
+            format!("{}", m.size[1]),
+        ));
+    }
+
+    Ok(m.to_f64())
+}
+
+impl<T> crate::linear::MatrixFunction<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn expm(&self) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        Ok(expm(&square_matrix(self)?))
+    }
+
+    fn logm(&self) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::error::SlalError;
+
+        let schur = match super::eigen::schur(&square_matrix(self)?) {
+            Some(schur) => schur,
+            None => {
+                return Err(SlalError::NotConverged(
+                    format!("{:?}", *self),
+                    format!("{}", super::eigen::max_iteration(self.size[0])),
+                ))
+            }
+        };
+
+        match logm(&schur) {
+            Some(log) => Ok(log),
+            None => Err(SlalError::NoRealLogarithm(format!("{:?}", *self))),
+        }
+    }
+
+    fn sqrtm(&self) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::error::SlalError;
+
+        let schur = match super::eigen::schur(&square_matrix(self)?) {
+            Some(schur) => schur,
+            None => {
+                return Err(SlalError::NotConverged(
+                    format!("{:?}", *self),
+                    format!("{}", super::eigen::max_iteration(self.size[0])),
+                ))
+            }
+        };
+
+        match sqrtm(&schur) {
+            Some(sqrt) => Ok(sqrt),
+            None => Err(SlalError::NoRealSquareRoot(format!("{:?}", *self))),
+        }
+    }
+
+    fn powf(&self, p: f64) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::error::SlalError;
+
+        let eig = match super::eigen::eig(&square_matrix(self)?, true) {
+            Some(eig) => eig,
+            None => {
+                return Err(SlalError::NotConverged(
+                    format!("{:?}", *self),
+                    format!("{}", super::eigen::max_iteration(self.size[0])),
+                ))
+            }
+        };
+        let (values_re, values_im) = (eig.values_re().to_vec(), eig.values_im().to_vec());
+
+        if p < 0.
+            && values_re
+                .iter()
+                .zip(values_im.iter())
+                .any(|(re, im)| *re == 0. && *im == 0.)
+        {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        if p.fract() != 0.
+            && values_re
+                .iter()
+                .zip(values_im.iter())
+                .any(|(re, im)| *re < 0. && *im == 0.)
+        {
+            return Err(SlalError::NoRealPower(
+                format!("{:?}", *self),
+                format!("{}", p),
+            ));
+        }
+
+        match powf(&eig, p) {
+            Some(power) => Ok(power),
+            None => Err(SlalError::NotDiagonalizable(format!("{:?}", *self))),
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use super::Matrix;
+use crate::error::SlalError;
+use crate::linear::MatrixFunction;
+
+const TOLERANCE: f64 = 1e-10;
+
+// Maximum absolute difference of elements relative to the largest element of b
+fn relative_error(a: &Matrix<f64>, b: &Matrix<f64>) -> f64 {
+    let scale = b.m.iter().fold(1f64, |scale, b_i| scale.max(b_i.abs()));
+
+    a.m.iter()
+        .zip(b.m.iter())
+        .fold(0f64, |error, (a_i, b_i)| error.max((a_i - b_i).abs()))
+        / scale
+}
+
+fn square(a: &Matrix<f64>) -> Matrix<f64> {
+    let n = a.size[0];
+    let mut m = vec![0.; n * n];
+    for j in 0..n {
+        for i in 0..n {
+            m[j * n + i] = (0..n).map(|k| a[j][k] * a[k][i]).sum();
+        }
+    }
+
+    Matrix { m, size: [n, n] }
+}
+
+#[test]
+fn expm_zero() {
+    let m = Matrix::new(&[&[0, 0], &[0, 0]]).unwrap();
+
+    assert!(m.expm().unwrap() == Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap());
+}
+
+#[test]
+fn expm_diagonal() {
+    let m = Matrix::new(&[&[1., 0., 0.], &[0., -2., 0.], &[0., 0., 30.]]).unwrap();
+    let expected = Matrix::new(&[
+        &[1f64.exp(), 0., 0.],
+        &[0., (-2f64).exp(), 0.],
+        &[0., 0., 30f64.exp()],
+    ])
+    .unwrap();
+
+    assert!(relative_error(&m.expm().unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn expm_nilpotent() {
+    let m = Matrix::new(&[&[0, 1, 0], &[0, 0, 1], &[0, 0, 0]]).unwrap();
+    let expected = Matrix::new(&[&[1., 1., 0.5], &[0., 1., 1.], &[0., 0., 1.]]).unwrap();
+
+    assert!(relative_error(&m.expm().unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn expm_scaling_and_squaring() {
+    // exp of upper triangular [[a, b], [0, d]] is [[e^a, b (e^a - e^d) / (a - d)], [0, e^d]]
+    let (a, b, d): (f64, f64, f64) = (12., 20., -7.);
+    let m = Matrix::new(&[&[a, b], &[0., d]]).unwrap();
+    let expected = Matrix::new(&[
+        &[a.exp(), b * (a.exp() - d.exp()) / (a - d)],
+        &[0., d.exp()],
+    ])
+    .unwrap();
+
+    assert!(relative_error(&m.expm().unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn expm_rotation() {
+    let theta: f64 = 2.5;
+    let m = Matrix::new(&[&[0., -theta, 0.], &[theta, 0., 0.], &[0., 0., 0.]]).unwrap();
+    let expected = Matrix::new(&[
+        &[theta.cos(), -theta.sin(), 0.],
+        &[theta.sin(), theta.cos(), 0.],
+        &[0., 0., 1.],
+    ])
+    .unwrap();
+
+    assert!(relative_error(&m.expm().unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn logm_inverse_of_expm() {
+    let m = Matrix::new(&[&[0.5, -1.2, 0.3], &[0.8, 0.1, -0.4], &[-0.2, 0.6, 0.9]]).unwrap();
+
+    assert!(relative_error(&m.expm().unwrap().logm().unwrap(), &m) < TOLERANCE);
+}
+
+#[test]
+fn logm_complex_eigenvalues() {
+    // Scaled rotation close to half a turn has eigenvalues near the negative real axis
+    let theta: f64 = 3.;
+    let m = Matrix::new(&[
+        &[2. * theta.cos(), -2. * theta.sin(), 1.],
+        &[2. * theta.sin(), 2. * theta.cos(), 0.],
+        &[0., 0., 5.],
+    ])
+    .unwrap();
+    let log = m.logm().unwrap();
+
+    assert!((log[0][0] - 2f64.ln()).abs() < TOLERANCE);
+    assert!((log[1][0] - theta).abs() < TOLERANCE);
+    assert!(relative_error(&log.expm().unwrap(), &m) < TOLERANCE);
+}
+
+#[test]
+fn logm_large_norm() {
+    let m = Matrix::new(&[&[1e6, 1e5], &[0., 1e-3]]).unwrap();
+
+    assert!(relative_error(&m.logm().unwrap().expm().unwrap(), &m) < 1e-8);
+}
+
+#[test]
+fn logm_no_real_logarithm() {
+    [
+        Matrix::new(&[&[-1, 0], &[0, 1]]).unwrap(),
+        Matrix::new(&[&[0, 1], &[0, 1]]).unwrap(),
+    ]
+    .iter()
+    .for_each(|m| match m.logm() {
+        Err(SlalError::NoRealLogarithm(_)) => (),
+        _ => panic!("Expected NoRealLogarithm"),
+    });
+}
+
+#[test]
+fn sqrtm_symmetric() {
+    let m = Matrix::new(&[&[5, 4], &[4, 5]]).unwrap();
+    let expected = Matrix::new(&[&[2., 1.], &[1., 2.]]).unwrap();
+
+    assert!(relative_error(&m.sqrtm().unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn sqrtm_nonsymmetric() {
+    let m = Matrix::new(&[
+        &[4., 1., -2., 0.5],
+        &[-1., 3., 1., 2.],
+        &[0.5, -2., 5., 1.],
+        &[1., 0., -1., 6.],
+    ])
+    .unwrap();
+    let sqrt = m.sqrtm().unwrap();
+
+    assert!(relative_error(&square(&sqrt), &m) < TOLERANCE);
+}
+
+#[test]
+fn sqrtm_complex_eigenvalues() {
+    let m = Matrix::new(&[&[0, -4, 0], &[4, 0, 0], &[0, 0, 9]]).unwrap();
+    let sqrt = m.sqrtm().unwrap();
+
+    // Principal square root of a rotation by a quarter turn is a rotation by an eighth turn
+    let r = 2. * std::f64::consts::FRAC_1_SQRT_2;
+    assert!((sqrt[0][0] - r).abs() < TOLERANCE && (sqrt[1][0] - r).abs() < TOLERANCE);
+    assert!((sqrt[2][2] - 3.).abs() < TOLERANCE);
+    assert!(relative_error(&square(&sqrt), &Matrix::<f64>::from(m)) < TOLERANCE);
+}
+
+#[test]
+fn sqrtm_singular() {
+    let m = Matrix::new(&[&[0., 1.], &[0., 4.]]).unwrap();
+    let expected = Matrix::new(&[&[0., 0.5], &[0., 2.]]).unwrap();
+
+    assert!(relative_error(&m.sqrtm().unwrap(), &expected) < TOLERANCE);
+    assert!(
+        Matrix::new(&[&[0, 0], &[0, 0]]).unwrap().sqrtm().unwrap()
+            == Matrix::new(&[&[0., 0.], &[0., 0.]]).unwrap()
+    );
+}
+
+#[test]
+fn sqrtm_no_real_square_root() {
+    [
+        Matrix::new(&[&[-1, 0], &[0, 1]]).unwrap(),
+        Matrix::new(&[&[0, 1], &[0, 0]]).unwrap(),
+    ]
+    .iter()
+    .for_each(|m| match m.sqrtm() {
+        Err(SlalError::NoRealSquareRoot(_)) => (),
+        _ => panic!("Expected NoRealSquareRoot"),
+    });
+}
+
+#[test]
+fn not_square() {
+    let m = Matrix::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    match m.expm() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+    match m.logm() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+    match m.sqrtm() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
+
+#[test]
+fn empty() {
+    match Matrix::<f64>::empty().sqrtm() {
+        Err(SlalError::EmptyMatrix(_)) => (),
+        _ => panic!("Expected EmptyMatrix"),
+    }
+}
+
+#[test]
+fn powf_integer_exponent() {
+    let m = Matrix::new(&[&[2., 1., 0.], &[1., 3., 1.], &[0., -1., 4.]]).unwrap();
+
+    assert!(relative_error(&m.powf(2.).unwrap(), &square(&m)) < TOLERANCE);
+    assert!(
+        relative_error(
+            &m.powf(0.).unwrap(),
+            &Matrix::new(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., 1.]]).unwrap()
+        ) < TOLERANCE
+    );
+}
+
+#[test]
+fn powf_inverse() {
+    let m = Matrix::new(&[&[4., 7.], &[2., 6.]]).unwrap();
+    let expected = Matrix::new(&[&[0.6, -0.7], &[-0.2, 0.4]]).unwrap();
+
+    assert!(relative_error(&m.powf(-1.).unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn powf_square_root() {
+    let m = Matrix::new(&[&[5, 4], &[4, 5]]).unwrap();
+
+    assert!(relative_error(&m.powf(0.5).unwrap(), &m.sqrtm().unwrap()) < TOLERANCE);
+}
+
+#[test]
+fn powf_complex_eigenvalues() {
+    // Third power of a rotation by a third of the angle
+    let theta: f64 = 1.2;
+    let m = Matrix::new(&[&[theta.cos(), -theta.sin()], &[theta.sin(), theta.cos()]]).unwrap();
+    let expected = Matrix::new(&[
+        &[(theta / 3.).cos(), -(theta / 3.).sin()],
+        &[(theta / 3.).sin(), (theta / 3.).cos()],
+    ])
+    .unwrap();
+
+    assert!(relative_error(&m.powf(1. / 3.).unwrap(), &expected) < TOLERANCE);
+}
+
+#[test]
+fn powf_negative_eigenvalue() {
+    let m = Matrix::new(&[&[-2, 0], &[1, 3]]).unwrap();
+
+    match m.powf(0.5) {
+        Err(SlalError::NoRealPower(_, _)) => (),
+        _ => panic!("Expected NoRealPower"),
+    }
+    assert!(
+        relative_error(
+            &m.powf(3.).unwrap(),
+            &Matrix::new(&[&[-8., 0.], &[7., 27.]]).unwrap()
+        ) < TOLERANCE
+    );
+}
+
+#[test]
+fn powf_not_diagonalizable() {
+    let m = Matrix::new(&[&[2, 1], &[0, 2]]).unwrap();
+
+    match m.powf(0.5) {
+        Err(SlalError::NotDiagonalizable(_)) => (),
+        _ => panic!("Expected NotDiagonalizable"),
+    }
+}
+
+#[test]
+fn powf_defective() {
+    let m = Matrix::new(&[&[1, 1], &[0, 1]]).unwrap();
+
+    for p in [2., -1., 0.5] {
+        match m.powf(p) {
+            Err(SlalError::NotDiagonalizable(_)) => (),
+            _ => panic!("Expected NotDiagonalizable"),
+        }
+    }
+}
+
+#[test]
+fn powf_singular() {
+    let m = Matrix::new(&[&[1, 2], &[2, 4]]).unwrap();
+
+    match m.powf(-0.5) {
+        Err(SlalError::SingularMatrix(_)) => (),
+        _ => panic!("Expected SingularMatrix"),
+    }
+    assert!(
+        relative_error(
+            &m.powf(2.).unwrap(),
+            &Matrix::new(&[&[5., 10.], &[10., 20.]]).unwrap()
+        ) < TOLERANCE
+    );
+}
@@ -1,429 +1,406 @@
-macro_rules! impl_mul_vertex {
-    ($t:ty) => {
-        impl std::ops::Mul<crate::vertex::Vertex<$t>> for crate::vertex::Vertex<$t> {
-            type Output = super::Matrix<$t>;
-
-            fn mul(self, other: crate::vertex::Vertex<$t>) -> Self::Output {
-                use rayon::prelude::*;
+impl<T> std::ops::Mul<crate::vertex::Vertex<T>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Matrix<T>;
+
+    fn mul(self, other: crate::vertex::Vertex<T>) -> Self::Output {
+        if self.len() != other.len() {
+            panic!("Length of two vectors must match.");
+        } else if self.is_transposed() == other.is_transposed() {
+            if self.is_transposed() {
+                panic!("Cannot multiply vectors that are both vertical.");
+            } else {
+                panic!("Cannot multiply vectors that are both horizontal.")
+            }
+        }
 
-                if self.len() != other.len() {
-                    panic!("Length of two vectors must match.");
-                } else if self.is_transposed() == other.is_transposed() {
-                    if self.is_transposed() {
-                        panic!("Cannot multiply vectors that are both vertical.");
-                    } else {
-                        panic!("Cannot multiply vectors that are both horizontal.")
-                    }
-                }
+        if self.is_transposed() {
+            let mut rv: Vec<T> = vec![T::zero(); self.len() * other.len()];
+            crate::parallel::for_each_mut(&mut rv, |idx, val| {
+                *val = self[idx / self.len()] * other[idx % self.len()];
+            });
 
-                if self.is_transposed() {
-                    let mut rv: Vec<$t> = vec![0 as $t; self.len() * other.len()];
-                    rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                        *val = self[idx / self.len()] * other[idx % self.len()];
-                    });
+            Self::Output {
+                m: rv,
+                size: [other.len(), self.len()],
+            }
+        } else {
+            let rv: T = crate::parallel::map_sum(self.len(), |ij| self[ij] * other[ij]);
 
-                    Self::Output {
-                        m: rv,
-                        size: [other.len(), self.len()],
-                    }
-                } else {
-                    let rv: $t = (0..self.len())
-                        .into_par_iter()
-                        .map(|ij| self[ij] * other[ij])
-                        .sum();
-
-                    Self::Output {
-                        m: vec![rv],
-                        size: [1, 1],
-                    }
-                }
+            Self::Output {
+                m: vec![rv],
+                size: [1, 1],
             }
         }
+    }
+}
 
-        impl crate::linear::Dot<crate::vertex::Vertex<$t>> for crate::vertex::Vertex<$t> {
-            type Output = crate::error::SlalErr<$t, $t>;
-
-            fn dot(
-                &self,
-                other: &Self,
-            ) -> <Self as crate::linear::Dot<crate::vertex::Vertex<$t>>>::Output {
-                use crate::error::SlalError;
-                use rayon::prelude::*;
-
-                if self.len() != other.len() {
-                    return Err(SlalError::UnmatchingVertexLength(
-                        format!("{:?}", *self),
-                        format!("{:?}", *other),
-                    ));
-                }
-                if self.is_transposed() || !other.is_transposed() {
-                    return Err(SlalError::VertexStateError(format!("{:?}", *self,)));
-                }
-
-                let rv: $t = (0..self.len())
-                    .into_par_iter()
-                    .map(|ij| self[ij] * other[ij])
-                    .sum();
-
-                Ok(rv)
-            }
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<T, T>;
+
+    fn dot(&self, other: &Self) -> <Self as crate::linear::Dot<crate::vertex::Vertex<T>>>::Output {
+        use crate::error::SlalError;
+
+        if self.len() != other.len() {
+            return Err(SlalError::UnmatchingVertexLength(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+            ));
+        }
+        if self.is_transposed() || !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!("{:?}", *self,)));
         }
 
-        impl crate::linear::Cross<crate::vertex::Vertex<$t>> for crate::vertex::Vertex<$t> {
-            type Output = crate::error::SlalErr<crate::vertex::Vertex<$t>, $t>;
+        let rv: T = crate::parallel::map_sum(self.len(), |ij| self[ij] * other[ij]);
 
-            fn cross(&self, other: &Self) -> Self::Output {
-                use crate::error::SlalError;
-                use crate::vertex::Vertex;
-                use rayon::prelude::*;
+        Ok(rv)
+    }
+}
 
-                let self_len = self.len();
+impl<T> crate::linear::Cross<crate::vertex::Vertex<T>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
 
-                if self_len != other.len() {
-                    return Err(SlalError::UnmatchingVertexLength(
-                        format!("{:?}", *self),
-                        format!("{:?}", *other),
-                    ));
-                }
+    fn cross(&self, other: &Self) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
 
-                if !self.is_transposed() || other.is_transposed() {
-                    return Err(SlalError::VertexStateError(format!("{:?}", *self,)));
-                }
+        let self_len = self.len();
 
-                let mut rv: Vec<$t> = vec![0 as $t; self_len];
-                rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = self[(idx + 1) % self_len] * other[(idx + 2) % self_len]
-                        - self[(idx + 2) % self_len] * other[(idx + 1) % self_len];
-                });
+        if self_len != other.len() {
+            return Err(SlalError::UnmatchingVertexLength(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+            ));
+        }
 
-                Ok(Vertex::new(rv.as_slice()))
-            }
+        if !self.is_transposed() || other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!("{:?}", *self,)));
         }
-    };
+
+        let mut rv: Vec<T> = vec![T::zero(); self_len];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| {
+            *val = self[(idx + 1) % self_len] * other[(idx + 2) % self_len]
+                - self[(idx + 2) % self_len] * other[(idx + 1) % self_len];
+        });
+
+        Ok(Vertex::new(rv.as_slice()))
+    }
 }
+impl<T> std::ops::Mul<T> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Matrix<T>;
+
+    fn mul(self, other: T) -> Self::Output {
+        let mut rv: Vec<T> = vec![T::zero(); self.size[0] * self.size[1]];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| *val = self.m[idx] * other);
+
+        Self::Output {
+            m: rv,
+            size: self.size,
+        }
+    }
+}
+
+impl<T> crate::linear::Dot<T> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Matrix<T>;
 
-impl_mul_vertex! { i8 }
-impl_mul_vertex! { u8 }
-impl_mul_vertex! { i16 }
-impl_mul_vertex! { u16 }
-impl_mul_vertex! { i32 }
-impl_mul_vertex! { u32 }
-impl_mul_vertex! { i64 }
-impl_mul_vertex! { u64 }
-impl_mul_vertex! { i128 }
-impl_mul_vertex! { u128 }
-impl_mul_vertex! { isize }
-impl_mul_vertex! { usize }
-impl_mul_vertex! { f32 }
-impl_mul_vertex! { f64 }
+    fn dot(&self, other: &T) -> Self::Output {
+        self.clone() * *other
+    }
+}
+
+impl<T> crate::linear::Dot<super::Matrix<T>> for T
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Matrix<T>;
+
+    fn dot(&self, other: &Self::Output) -> Self::Output {
+        other.clone() * *self
+    }
+}
 
 macro_rules! impl_dot_scala {
-    ($t:ty) => {
+    ($($t:ty)*) => ($(
         impl std::ops::Mul<super::Matrix<$t>> for $t {
             type Output = super::Matrix<$t>;
 
             fn mul(self, other: Self::Output) -> Self::Output {
-                use rayon::prelude::*;
-
-                let mut rv: Vec<$t> = vec![0 as $t; other.size[0] * other.size[1]];
-                rv.par_iter_mut()
-                    .enumerate()
-                    .for_each(|(idx, val)| *val = other.m[idx] * self);
-
-                Self::Output {
-                    m: rv,
-                    size: other.size,
-                }
+                other * self
             }
         }
+    )*)
+}
 
-        impl crate::linear::Dot<super::Matrix<$t>> for $t {
-            type Output = super::Matrix<$t>;
+impl_dot_scala! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
 
-            fn dot(&self, other: &Self::Output) -> Self::Output {
-                *self * other.clone()
-            }
+// Vector * Matrix product calculation
+impl<T> std::ops::Mul<super::Matrix<T>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::vertex::Vertex<T>;
+
+    fn mul(self, other: super::Matrix<T>) -> Self::Output {
+        let m_size = other.size();
+        if self.is_transposed() {
+            panic!("Cannot multiply transposed vector with Matrix.");
+        } else if self.len() != m_size.1 {
+            panic!("Length of vector and height of matrix must match.");
         }
-    };
-}
 
-impl_dot_scala! { i8 }
-impl_dot_scala! { u8 }
-impl_dot_scala! { i16 }
-impl_dot_scala! { u16 }
-impl_dot_scala! { i32 }
-impl_dot_scala! { u32 }
-impl_dot_scala! { i64 }
-impl_dot_scala! { u64 }
-impl_dot_scala! { i128 }
-impl_dot_scala! { u128 }
-impl_dot_scala! { isize }
-impl_dot_scala! { usize }
-impl_dot_scala! { f32 }
-impl_dot_scala! { f64 }
-
-macro_rules! impl_dot_with_scala {
-    ($t:ty) => {
-        impl std::ops::Mul<$t> for super::Matrix<$t> {
-            type Output = super::Matrix<$t>;
+        let mut rv: Vec<T> = vec![T::zero(); m_size.0];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| {
+            *val = crate::parallel::map_sum(m_size.1, |inner_idx| {
+                self[inner_idx] * other[inner_idx][idx]
+            });
+        });
 
-            fn mul(self, other: $t) -> Self::Output {
-                other * self
-            }
+        crate::vertex::Vertex::<T>::new(rv.as_slice())
+    }
+}
+
+impl<T> crate::linear::Dot<super::Matrix<T>> for crate::vertex::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<Self, T>;
+
+    fn dot(&self, other: &super::Matrix<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        let m_size = other.size();
+        if self.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must not be transposed when computing product of vertex {:?} and matrix {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.len() != m_size.1 {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+                String::from("while computing product of vertex and matrix"),
+            ));
         }
 
-        impl crate::linear::Dot<$t> for super::Matrix<$t> {
-            type Output = super::Matrix<$t>;
+        let mut rv: Vec<T> = vec![T::zero(); m_size.0];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| {
+            *val = crate::parallel::map_sum(m_size.1, |inner_idx| {
+                self[inner_idx] * other[inner_idx][idx]
+            });
+        });
 
-            fn dot(&self, other: &$t) -> Self::Output {
-                *other * self.clone()
-            }
-        }
-    };
+        Ok(Vertex::<T>::new(rv.as_slice()))
+    }
 }
+impl<T> std::ops::Mul<crate::vertex::Vertex<T>> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::vertex::Vertex<T>;
+
+    fn mul(self, other: Self::Output) -> Self::Output {
+        use crate::vertex::Vertex;
+
+        let m_size = self.size();
+        if !other.is_transposed() {
+            panic!("Vertex not transposed while multiplication of matrix and vertex");
+        } else if m_size.0 != other.len() {
+            panic!("Vertex length does not match the width of matrix while in multiplication of matrix and vertex");
+        }
 
-impl_dot_with_scala! { i8 }
-impl_dot_with_scala! { u8 }
-impl_dot_with_scala! { i16 }
-impl_dot_with_scala! { u16 }
-impl_dot_with_scala! { i32 }
-impl_dot_with_scala! { u32 }
-impl_dot_with_scala! { i64 }
-impl_dot_with_scala! { u64 }
-impl_dot_with_scala! { i128 }
-impl_dot_with_scala! { u128 }
-impl_dot_with_scala! { isize }
-impl_dot_with_scala! { usize }
-impl_dot_with_scala! { f32 }
-impl_dot_with_scala! { f64 }
+        let mut rv_vec: Vec<T> = vec![T::zero(); m_size.1];
+        crate::parallel::for_each_mut(&mut rv_vec, |idx, val| {
+            *val = crate::parallel::map_sum(m_size.0, |inner_idx| {
+                self[idx][inner_idx] * other[inner_idx]
+            });
+        });
+        let mut rv = Vertex::<T>::new(rv_vec.as_slice());
 
-// Vector * Matrix product calculation
-macro_rules! impl_dot_vertex {
-    ($t:ty) => {
-        impl std::ops::Mul<super::Matrix<$t>> for crate::vertex::Vertex<$t> {
-            type Output = crate::vertex::Vertex<$t>;
-
-            fn mul(self, other: super::Matrix<$t>) -> Self::Output {
-                use rayon::prelude::*;
-
-                let m_size = other.size();
-                if self.is_transposed() {
-                    panic!("Cannot multiply transposed vector with Matrix.");
-                } else if self.len() != m_size.1 {
-                    panic!("Length of vector and height of matrix must match.");
-                }
-
-                let mut rv: Vec<$t> = vec![0 as $t; m_size.0];
-                rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = (0..m_size.1)
-                        .into_par_iter()
-                        .map(|inner_idx| self[inner_idx] * other[inner_idx][idx])
-                        .sum();
-                });
-
-                crate::vertex::Vertex::<$t>::new(rv.as_slice())
-            }
-        }
+        rv.t();
 
-        impl crate::linear::Dot<super::Matrix<$t>> for crate::vertex::Vertex<$t> {
-            type Output = crate::error::SlalErr<Self, $t>;
-
-            fn dot(&self, other: &super::Matrix<$t>) -> Self::Output {
-                use crate::error::SlalError;
-                use crate::vertex::Vertex;
-                use rayon::prelude::*;
-
-                let m_size = other.size();
-                if self.is_transposed() {
-                    return Err(SlalError::VertexStateError(format!(
-                        "Vertex must not be transposed when computing product of vertex {:?} and matrix {:?}",
-                        *self,
-                        *other,
-                    )))
-                } else if self.len() != m_size.1 {
-                    return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
-                        format!("{:?}", *self),
-                        format!("{:?}", *other),
-                        String::from("while computing product of vertex and matrix"),
-                    ));
-                }
-
-                let mut rv: Vec<$t> = vec![0 as $t; m_size.0];
-                rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = (0..m_size.1)
-                        .into_par_iter()
-                        .map(|inner_idx| {
-                            self[inner_idx] * other[inner_idx][idx]
-                        })
-                        .sum();
-                });
-
-                Ok(Vertex::<$t>::new(rv.as_slice()))
-            }
-        }
-    };
+        rv
+    }
 }
 
-impl_dot_vertex! { i8 }
-impl_dot_vertex! { u8 }
-impl_dot_vertex! { i16 }
-impl_dot_vertex! { u16 }
-impl_dot_vertex! { i32 }
-impl_dot_vertex! { u32 }
-impl_dot_vertex! { i64 }
-impl_dot_vertex! { u64 }
-impl_dot_vertex! { i128 }
-impl_dot_vertex! { u128 }
-impl_dot_vertex! { isize }
-impl_dot_vertex! { usize }
-impl_dot_vertex! { f32 }
-impl_dot_vertex! { f64 }
-
-macro_rules! impl_dot_with_vertex {
-    ($($t:ty)*) => ($(
-        impl std::ops::Mul<crate::vertex::Vertex<$t>> for super::Matrix<$t> {
-            type Output = crate::vertex::Vertex<$t>;
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        let m_size = self.size();
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of matrix {:?} and vertex {:?}",
+                *self, *other,
+            )));
+        } else if m_size.0 != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of matrix and vertex"),
+            ));
+        }
 
-            fn mul(self, other: Self::Output) -> Self::Output {
-                use crate::vertex::Vertex;
-                use rayon::prelude::*;
-
-                let m_size = self.size();
-                if !other.is_transposed() {
-                    panic!("Vertex not transposed while multiplication of matrix and vertex");
-                } else if m_size.0 != other.len() {
-                    panic!("Vertex length does not match the width of matrix while in multiplication of matrix and vertex");
-                }
-
-                let mut rv_vec: Vec<$t> = vec![0 as $t; m_size.1];
-                rv_vec.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = self[idx]
-                        .par_iter()
-                        .enumerate()
-                        .map(|(inner_idx, m_ij)| *m_ij * other[inner_idx])
-                        .sum();
-                });
-                let mut rv = Vertex::<$t>::new(rv_vec.as_slice());
-
-                rv.t();
-
-                rv
-            }
+        let mut rv_vec: Vec<T> = vec![T::zero(); m_size.1];
+        crate::parallel::for_each_mut(&mut rv_vec, |idx, val| {
+            *val = crate::parallel::map_sum(m_size.0, |inner_idx| {
+                self[idx][inner_idx] * other[inner_idx]
+            });
+        });
+        let mut rv = Vertex::<T>::new(rv_vec.as_slice());
+
+        rv.t();
+
+        Ok(rv)
+    }
+}
+impl<T> std::ops::Mul<super::Matrix<T>> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self::Output) -> Self::Output {
+        let m_size = self.size();
+        let n_size = other.size();
+        if m_size.0 != n_size.1 {
+            panic!(
+                "Width of matrix {:?} and height of matrix {:?} must match while computing product of two matrices",
+                self,
+                other
+            );
         }
 
-        impl crate::linear::Dot<crate::vertex::Vertex<$t>> for super::Matrix<$t> {
-            type Output = crate::error::SlalErr<crate::vertex::Vertex<$t>, $t>;
-
-            fn dot(&self, other: &crate::vertex::Vertex<$t>) -> Self::Output {
-                use crate::vertex::Vertex;
-                use crate::error::SlalError;
-                use rayon::prelude::*;
-
-                let m_size = self.size();
-                if !other.is_transposed() {
-                    return Err(SlalError::VertexStateError(format!(
-                        "Vertex must be transposed when computing product of matrix {:?} and vertex {:?}",
-                        *self,
-                        *other,
-                    )));
-                } else if m_size.0 != other.len() {
-                    return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
-                        format!("{:?}", *other),
-                        format!("{:?}", *self),
-                        String::from("while computing product of matrix and vertex"),
-                    ));
-                }
-
-                let mut rv_vec: Vec<$t> = vec![0 as $t; m_size.1];
-                rv_vec.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = self[idx]
-                        .par_iter()
-                        .enumerate()
-                        .map(|(inner_idx, m_ij)| {
-                            *m_ij * other[inner_idx]
-                        })
-                        .sum();
-                });
-                let mut rv = Vertex::<$t>::new(rv_vec.as_slice());
-
-                rv.t();
-
-                Ok(rv)
-            }
+        let mut rv: Vec<T> = vec![T::zero(); n_size.0 * m_size.1];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| {
+            *val = crate::parallel::map_sum(m_size.0, |inner_idx| {
+                self[idx / n_size.0][inner_idx] * other[inner_idx][idx % n_size.0]
+            });
+        });
+
+        Self::Output {
+            m: rv,
+            size: [n_size.0, m_size.1],
         }
-    )*)
+    }
 }
 
-impl_dot_with_vertex! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
+impl<T> crate::linear::Dot<super::Matrix<T>> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<super::Matrix<T>, T>;
+
+    fn dot(&self, other: &Self) -> Self::Output {
+        use crate::error::SlalError;
+
+        let self_size = self.size();
+        let other_size = other.size();
+        if self_size.0 != other_size.1 {
+            return Err(SlalError::UnmatchingMatrixSize(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+            ));
+        }
 
-macro_rules! impl_dot_matrix {
-    ($($t:ty)*) => ($(
-        impl std::ops::Mul<super::Matrix<$t>> for super::Matrix<$t> {
-            type Output = Self;
+        let mut rv: Vec<T> = vec![T::zero(); self_size.1 * other_size.0];
+        crate::parallel::for_each_mut(&mut rv, |idx, val| {
+            *val = crate::parallel::map_sum(other_size.1, |inner_idx| {
+                self[idx / other_size.0][inner_idx] * other[inner_idx][idx % other_size.0]
+            });
+        });
+
+        Ok(Self {
+            m: rv,
+            size: [other_size.0, self_size.1],
+        })
+    }
+}
+impl<T> crate::linear::Power<T> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn pow(&self, n: u32) -> crate::error::SlalErr<super::Matrix<T>, T> {
+        use crate::error::SlalError;
+
+        if self.m.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
 
-            fn mul(self, other: Self::Output) -> Self::Output {
-                use rayon::prelude::*;
-
-                let m_size = self.size();
-                let n_size = other.size();
-                if m_size.0 != n_size.1 {
-                    panic!(
-                        "Width of matrix {:?} and height of matrix {:?} must match while computing product of two matrices",
-                        self,
-                        other
-                    );
-                }
-
-                let mut rv: Vec<$t> = vec![0 as $t; n_size.0 * m_size.1];
-                rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = (0..m_size.0)
-                        .into_par_iter()
-                        .map(|inner_idx| {
-                            self[idx / m_size.1][inner_idx] * other[inner_idx][idx % m_size.1]
-                        })
-                        .sum();
-                });
-
-                Self::Output {
-                    m: rv,
-                    size: [n_size.0, m_size.1]
-                }
-            }
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
         }
 
-        impl crate::linear::Dot<super::Matrix<$t>> for super::Matrix<$t> {
-            type Output = crate::error::SlalErr<super::Matrix<$t>, $t>;
-
-            fn dot(&self, other: &Self) -> Self::Output {
-                use crate::error::SlalError;
-                use rayon::prelude::*;
-
-                let self_size = self.size();
-                let other_size = other.size();
-                if self_size.0 != other_size.1 {
-                    return Err(SlalError::UnmatchingMatrixSize(
-                            format!("{:?}", *self),
-                            format!("{:?}", *other),
-                    ))
-                }
-
-                let mut rv: Vec<$t> = vec![0 as $t; self_size.1 * other_size.0];
-                rv.par_iter_mut().enumerate().for_each(|(idx, val)| {
-                    *val = (0..other_size.1)
-                        .into_par_iter()
-                        .map(|inner_idx| {
-                            self[idx / self_size.1][inner_idx] * other[inner_idx][idx % self_size.1]
-                        })
-                        .sum();
-                });
-
-                Ok(Self {
-                    m: rv,
-                    size: [other_size.0, self_size.1],
+        let size = self.size[0];
+        let multiply = |a: &[T], b: &[T], product: &mut [T]| {
+            crate::parallel::for_each_chunk_mut(product, size, |j, row| {
+                row.iter_mut().enumerate().for_each(|(i, val)| {
+                    *val = (0..size).map(|k| a[j * size + k] * b[k * size + i]).sum();
                 })
+            })
+        };
+
+        let mut rv: Option<Vec<T>> = None;
+        let mut base = self.m.clone();
+        let mut buffer = vec![T::zero(); size * size];
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                rv = match rv {
+                    Some(mut rv) => {
+                        multiply(&rv, &base, &mut buffer);
+                        std::mem::swap(&mut rv, &mut buffer);
+
+                        Some(rv)
+                    }
+                    None => Some(base.clone()),
+                };
+            }
+
+            n >>= 1;
+
+            // Squaring beyond the highest bit is skipped as it may overflow integers
+            if n > 0 {
+                multiply(&base, &base, &mut buffer);
+                std::mem::swap(&mut base, &mut buffer);
             }
         }
-    )*)
-}
 
-impl_dot_matrix! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
+        let rv = rv.unwrap_or_else(|| {
+            let mut identity = vec![T::zero(); size * size];
+            (0..size).for_each(|ij| identity[ij * size + ij] = T::one());
+
+            identity
+        });
+
+        Ok(Self {
+            m: rv,
+            size: self.size,
+        })
+    }
+}
@@ -1,6 +1,6 @@
 use super::Matrix;
 use crate::error::SlalError;
-use crate::linear::Dot;
+use crate::linear::{Dot, Power};
 use crate::vertex::Vertex;
 
 #[test]
@@ -215,6 +215,17 @@ fn mul_matrix() {
     )
 }
 
+#[test]
+fn mul_matrix_not_square() {
+    let m = Matrix::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+    let n = Matrix::new(&[&[1, 2, 3, 4], &[5, 6, 7, 8], &[9, 10, 11, 12]]).unwrap();
+
+    assert_eq!(
+        m * n,
+        Matrix::new(&[&[38, 44, 50, 56], &[83, 98, 113, 128]]).unwrap()
+    );
+}
+
 #[test]
 #[should_panic]
 fn mul_matrix_invalid() {
@@ -249,6 +260,17 @@ fn dot_matrix() {
     })
 }
 
+#[test]
+fn dot_matrix_not_square() {
+    let m = Matrix::<u64>::new(&[&[1, 2], &[3, 4], &[5, 6]]).unwrap();
+    let n = Matrix::<u64>::new(&[&[1, 2, 3, 4], &[5, 6, 7, 8]]).unwrap();
+
+    assert_eq!(
+        m.dot(&n),
+        Ok(Matrix::new(&[&[11, 14, 17, 20], &[23, 30, 37, 44], &[35, 46, 57, 68]]).unwrap())
+    );
+}
+
 #[test]
 fn dot_matrix_invalid() {
     let m = Matrix::<u32>::new(&[&[0, 1], &[2, 3]]).unwrap();
@@ -259,3 +281,47 @@ fn dot_matrix_invalid() {
         Err(_) => true,
     })
 }
+
+#[test]
+fn pow_fibonacci() {
+    let m = Matrix::<u64>::new(&[&[1, 1], &[1, 0]]).unwrap();
+
+    // F(93) is the largest Fibonacci number that fits in u64
+    assert_eq!(
+        m.pow(92),
+        Ok(Matrix::new(&[
+            &[12200160415121876738, 7540113804746346429],
+            &[7540113804746346429, 4660046610375530309]
+        ])
+        .unwrap())
+    );
+}
+
+#[test]
+fn pow_repeated_dot() {
+    let m = Matrix::<i32>::new(&[&[1, -2, 0], &[3, 1, -1], &[0, 2, 1]]).unwrap();
+    let mut expected = m.clone();
+    for n in 1..=7 {
+        assert_eq!(m.pow(n), Ok(expected.clone()));
+
+        expected = expected.dot(&m).unwrap();
+    }
+}
+
+#[test]
+fn pow_zero() {
+    let m = Matrix::<f64>::new(&[&[0.5, 2.], &[-1., 3.]]).unwrap();
+
+    assert_eq!(m.pow(0), Ok(Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap()));
+    assert_eq!(m.pow(1), Ok(m));
+}
+
+#[test]
+fn pow_not_square() {
+    let m = Matrix::<u8>::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    match m.pow(2) {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
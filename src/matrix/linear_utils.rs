@@ -76,201 +76,181 @@ fn upper_triangular(m: &super::Matrix<f64>) -> crate::error::SlalErr<super::Matr
     })
 }
 
-macro_rules! impl_triangular_matrix {
-    ($($t:ty)*) => ($(
-        impl crate::linear::TriangularMatrix for super::Matrix<$t> {
-            type Output = crate::error::SlalErr<super::Matrix<f64>, f64>;
+impl<T> crate::linear::TriangularMatrix for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<super::Matrix<f64>, f64>;
+
+    fn is_lower_triangular(&self) -> bool {
+        let size = self.size();
 
-            fn is_lower_triangular(&self) -> bool {
-                let size = self.size();
+        if size.0 != size.1 {
+            return false;
+        }
 
-                if size.0 != size.1 {
+        for j in 0..size.1 {
+            for i in (j + 1)..size.0 {
+                if self[j][i] != T::zero() {
                     return false;
                 }
+            }
+        }
 
-                for j in 0..size.1 {
-                    for i in (j+1)..size.0 {
-                        if self[j][i] != 0 as $t {
-                            return false;
-                        }
-                    }
-                }
+        true
+    }
 
-                true
-            }
+    fn is_upper_triangular(&self) -> bool {
+        let size = self.size();
 
-            fn is_upper_triangular(&self) -> bool {
-                let size = self.size();
+        if size.0 != size.1 {
+            return false;
+        }
 
-                if size.0 != size.1 {
+        for j in 0..size.1 {
+            for i in 0..j {
+                if self[j][i] != T::zero() {
                     return false;
                 }
-
-                for j in 0..size.1 {
-                    for i in 0..j {
-                        if self[j][i] != 0 as $t {
-                            return false;
-                        }
-                    }
-                }
-
-                true
             }
+        }
 
-            fn lower_triangular(&self) -> Self::Output {
-                let mut l = match self.upper_triangular() {
-                    Ok(l_matrix) => l_matrix,
-                    Err(err) => return Err(err),
-                };
-
-                l.t();
+        true
+    }
 
-                Ok(l)
-            }
+    fn lower_triangular(&self) -> Self::Output {
+        let mut l = self.upper_triangular()?;
 
-            fn upper_triangular(&self) -> Self::Output {
-                use crate::error::SlalError;
+        l.t();
 
-                let size = self.size();
+        Ok(l)
+    }
 
-                if size.0 != size.1 {
-                    return Err(SlalError::NotSquareMatrix(
-                            format!("{:?}", *self),
-                            format!("{}", size.0),
-                            format!("{}", size.1),
-                    ));
-                }
+    fn upper_triangular(&self) -> Self::Output {
+        use crate::error::SlalError;
 
-                let m: super::Matrix<f64> = super::Matrix::from(self.clone());
+        let size = self.size();
 
-                upper_triangular(&m)
-            }
+        if size.0 != size.1 {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", size.0),
+                format!("{}", size.1),
+            ));
         }
-    )*)
-}
 
-impl_triangular_matrix! { i8 u8 i16 u16 i32 u32 f32 f64 }
+        let m = self.to_f64();
 
-macro_rules! impl_diagonal_matrix {
-    ($($t:ty)*) => ($(
-        impl crate::linear::DiagonalMatrix<$t> for super::Matrix<$t> {
-            fn diagonal(diagonal: &[$t]) -> super::Matrix<$t> {
-                let mut m: Vec<$t> = Vec::with_capacity(diagonal.len().pow(2));
-                (0..diagonal.len()).for_each(|j| {
-                    (0..diagonal.len()).for_each(|i| {
-                        if i == j {
-                            m.push(diagonal[i]);
-                        } else {
-                            m.push(0 as $t);
-                        }
-                    })
-                });
+        upper_triangular(&m)
+    }
+}
 
-                super::Matrix {
-                    m: m,
-                    size: [diagonal.len(), diagonal.len()],
+impl<T> crate::linear::DiagonalMatrix<T> for super::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn diagonal(diagonal: &[T]) -> super::Matrix<T> {
+        let mut m: Vec<T> = Vec::with_capacity(diagonal.len().pow(2));
+        (0..diagonal.len()).for_each(|j| {
+            (0..diagonal.len()).for_each(|i| {
+                if i == j {
+                    m.push(diagonal[i]);
+                } else {
+                    m.push(T::zero());
                 }
-            }
+            })
+        });
 
-            fn is_diagonal(&self) -> bool {
-                let size = self.size();
+        super::Matrix {
+            m: m,
+            size: [diagonal.len(), diagonal.len()],
+        }
+    }
 
-                if size.0 != size.1 {
-                    return false;
-                }
+    fn is_diagonal(&self) -> bool {
+        let size = self.size();
 
-                let zero = 0 as $t;
-                for j in 0..size.1 {
-                    for i in j..size.0 {
-                        if i == j {
-                            continue;
-                        }
+        if size.0 != size.1 {
+            return false;
+        }
 
-                        if self[j][i] != zero || self[i][j] != zero {
-                            return false;
-                        }
-                    }
+        let zero = T::zero();
+        for j in 0..size.1 {
+            for i in j..size.0 {
+                if i == j {
+                    continue;
                 }
 
-                true
+                if self[j][i] != zero || self[i][j] != zero {
+                    return false;
+                }
             }
         }
-    )*)
+
+        true
+    }
 }
 
-impl_diagonal_matrix! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
+// Determinant of matrix with size (4, 4) and above is computed from its LU decomposition
+impl<T> crate::linear::Determinant<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn det(&self) -> crate::error::SlalErr<f64, T> {
+        use crate::error::SlalError;
+        use crate::linear::TriangularMatrix;
 
-// Add a method to compute triangular matrix of a matrix with size (2, 2) and above
-// Necessary for computing determinant of matrix with size (4, 4) and above
-macro_rules! impl_determinant {
-    ($($t:ty)*) => ($(
-        impl crate::linear::Determinant<$t> for super::Matrix<$t> {
-            fn det(&self) -> crate::error::SlalErr<f64, $t> {
-                use crate::error::SlalError;
-                use crate::linear::TriangularMatrix;
+        let size = self.size();
 
-                let size = self.size();
-
-                if self.is_empty() {
-                    return Err(SlalError::EmptyMatrix(format!("{:?}", self.clone())));
-                }
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", self.clone())));
+        }
 
-                if size.0 != size.1 {
-                    return Err(SlalError::NotSquareMatrix(
-                        format!("{:?}", *self),
-                        format!("{}", size.0),
-                        format!("{}", size.1)
-                    ))
-                }
+        if size.0 != size.1 {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", size.0),
+                format!("{}", size.1),
+            ));
+        }
 
-                if self.is_upper_triangular() || self.is_lower_triangular() {
-                    let mut rv: $t = 1 as $t;
+        if self.is_upper_triangular() || self.is_lower_triangular() {
+            let mut rv: T = T::one();
 
-                    (0..size.0).for_each(|idx| rv *= self[idx][idx]);
+            (0..size.0).for_each(|idx| rv *= self[idx][idx]);
 
-                    return Ok(rv as f64)
-                }
+            return Ok(rv.to_f64());
+        }
 
-                match size {
-                    (0, 0) => Err(SlalError::EmptyMatrix(String::from(
-                        "Cannot caluculate determinant for empty matrix"
-                    ))),
-                    (1, 1) => Ok(self[0][0] as f64),
-                    (2, 2) => {
-                        let rv = (self[0][0] * self[1][1]) as f64 - (self[1][0] * self[0][1]) as f64;
+        match size {
+            (0, 0) => Err(SlalError::EmptyMatrix(String::from(
+                "Cannot caluculate determinant for empty matrix",
+            ))),
+            (1, 1) => Ok(self[0][0].to_f64()),
+            (2, 2) => {
+                let rv = (self[0][0] * self[1][1]).to_f64() - (self[1][0] * self[0][1]).to_f64();
 
-                        Ok(rv)
-                    }
-                    (3, 3) => {
-                        let m_1 = self[0][0] as f64 * ((self[1][1] * self[2][2]) as f64 - (self[2][1] * self[1][2]) as f64);
-                        let m_2 = self[1][0] as f64 * ((self[0][1] * self[2][2]) as f64 - (self[2][1] * self[0][2]) as f64);
-                        let m_3 = self[2][0] as f64 * ((self[0][1] * self[1][2]) as f64 - (self[1][1] * self[0][2]) as f64);
+                Ok(rv)
+            }
+            (3, 3) => {
+                let m_1 = self[0][0].to_f64()
+                    * ((self[1][1] * self[2][2]).to_f64() - (self[2][1] * self[1][2]).to_f64());
+                let m_2 = self[1][0].to_f64()
+                    * ((self[0][1] * self[2][2]).to_f64() - (self[2][1] * self[0][2]).to_f64());
+                let m_3 = self[2][0].to_f64()
+                    * ((self[0][1] * self[1][2]).to_f64() - (self[1][1] * self[0][2]).to_f64());
+
+                Ok(m_1 - m_2 + m_3)
+            }
+            _ => {
+                use crate::linear::LuDecomposition;
 
-                        Ok(m_1 - m_2 + m_3)
-                    }
-                    _ => {
-                        let u = match self.upper_triangular() {
-                            Ok(m) => m,
-                            Err(_) => return Err(SlalError::TriangularMatrixNotExist(
-                                self.clone()
-                            )),
-                        };
-                        let mut det = 1.;
-                        (0..size.1)
-                            .for_each(|ij| {
-                                det *= u[ij][ij];
-                            });
-
-                        Ok(det)
-                    },
-                }
+                Ok(self.lu()?.det())
             }
         }
-    )*)
+    }
 }
 
-impl_determinant! { i8 u8 i16 u16 i32 u32 f32 f64 }
-
 fn minor<T: Copy>(mtx: &super::Matrix<T>, row: usize, column: usize) -> super::Matrix<T> {
     use super::Matrix;
 
@@ -292,141 +272,244 @@ fn minor<T: Copy>(mtx: &super::Matrix<T>, row: usize, column: usize) -> super::M
     }
 }
 
-macro_rules! impl_cofactor {
-    ($($t:ty)*) => ($(
-
-        impl crate::linear::Cofactor<$t> for super::Matrix<$t> {
-            type Output = super::Matrix<f64>;
-
-            fn cofactor(&self) -> crate::error::SlalErr<Self::Output, $t> {
-                use crate::error::SlalError;
-                use crate::linear::Determinant;
-                use rayon::prelude::*;
-
-                if self.size[0] != self.size[1] {
-                    return Err(SlalError::NotSquareMatrix(
-                        format!("{:?}", self.clone()),
-                        format!("{}", self.size[0]),
-                        format!("{}", self.size[1]),
-                    ));
-                }
+impl<T> crate::linear::Cofactor<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = super::Matrix<f64>;
+
+    fn cofactor(&self) -> crate::error::SlalErr<Self::Output, T> {
+        use crate::error::SlalError;
+        use crate::linear::Determinant;
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", self.clone()),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
 
-                match self.size {
-                    [0, 0] => Err(SlalError::EmptyMatrix(format!("{:?}", self.clone()))),
-                    [1, 1] => Ok(Self::Output {
-                        m: vec![self[0][0] as f64],
-                        size: self.size,
-                    }),
-                    [2, 2] => Ok(Self::Output {
-                        m: vec![
-                            self[0][0] as f64,
-                            -(self[1][0] as f64),
-                            -(self[0][1] as f64),
-                            self[1][1] as f64,
-                        ],
-                        size: self.size,
-                    }),
-                    [3, 3] => {
-                        let m_11 = f64::from(self[1][1] * self[2][2]) -
-                            f64::from(self[2][1] * self[1][2]);
-                        let m_12 = f64::from(self[1][0] * self[2][2]) -
-                            f64::from(self[2][0] * self[1][2]);
-                        let m_13 = f64::from(self[1][0] * self[2][1]) -
-                            f64::from(self[2][0] * self[1][1]);
-                        let m_21 = f64::from(self[0][1] * self[2][2]) -
-                            f64::from(self[2][1] * self[0][2]);
-                        let m_22 = f64::from(self[0][0] * self[2][2]) -
-                            f64::from(self[2][0] * self[0][2]);
-                        let m_23 = f64::from(self[0][0] * self[2][1]) -
-                            f64::from(self[2][0] * self[0][1]);
-                        let m_31 = f64::from(self[0][1] * self[1][2]) -
-                            f64::from(self[1][1] * self[0][2]);
-                        let m_32 = f64::from(self[0][0] * self[1][2]) -
-                            f64::from(self[1][0] * self[0][2]);
-                        let m_33 = f64::from(self[0][0] * self[1][1]) -
-                            f64::from(self[1][0] * self[0][1]);
-
-                        Ok(Self::Output {
-                            m: vec![
-                                m_11, -m_12, m_13,
-                                -m_21, m_22, -m_23,
-                                m_31, -m_32, m_33,
-                            ],
-                            size: self.size,
-                        })
-                    },
-                    _ => {
-                        let mut m: Vec<f64> = vec![0.; self.size[0] * self.size[1]];
-                        let rv_err = m.par_iter_mut().enumerate().try_for_each(|(idx, val)| {
-                            let j = idx / self.size[1];
-                            let i = idx % self.size[0];
-
-                            match minor(self, j, i).det() {
-                                Ok(det) => {
-                                    if (j%2 == 1 && i%2 == 0) || (j%2 == 0 && i%2 == 1) {
-                                        *val = -det;
-                                    } else {
-                                        *val = det;
-                                    }
-
-                                    Ok(())
-                                },
-                                Err(err) => return Err(err),
+        match self.size {
+            [0, 0] => Err(SlalError::EmptyMatrix(format!("{:?}", self.clone()))),
+            [1, 1] => Ok(Self::Output {
+                m: vec![self[0][0].to_f64()],
+                size: self.size,
+            }),
+            [2, 2] => Ok(Self::Output {
+                m: vec![
+                    self[0][0].to_f64(),
+                    -self[1][0].to_f64(),
+                    -self[0][1].to_f64(),
+                    self[1][1].to_f64(),
+                ],
+                size: self.size,
+            }),
+            [3, 3] => {
+                let m_11 = (self[1][1] * self[2][2]).to_f64() - (self[2][1] * self[1][2]).to_f64();
+                let m_12 = (self[1][0] * self[2][2]).to_f64() - (self[2][0] * self[1][2]).to_f64();
+                let m_13 = (self[1][0] * self[2][1]).to_f64() - (self[2][0] * self[1][1]).to_f64();
+                let m_21 = (self[0][1] * self[2][2]).to_f64() - (self[2][1] * self[0][2]).to_f64();
+                let m_22 = (self[0][0] * self[2][2]).to_f64() - (self[2][0] * self[0][2]).to_f64();
+                let m_23 = (self[0][0] * self[2][1]).to_f64() - (self[2][0] * self[0][1]).to_f64();
+                let m_31 = (self[0][1] * self[1][2]).to_f64() - (self[1][1] * self[0][2]).to_f64();
+                let m_32 = (self[0][0] * self[1][2]).to_f64() - (self[1][0] * self[0][2]).to_f64();
+                let m_33 = (self[0][0] * self[1][1]).to_f64() - (self[1][0] * self[0][1]).to_f64();
+
+                Ok(Self::Output {
+                    m: vec![m_11, -m_12, m_13, -m_21, m_22, -m_23, m_31, -m_32, m_33],
+                    size: self.size,
+                })
+            }
+            _ => {
+                let mut m: Vec<f64> = vec![0.; self.size[0] * self.size[1]];
+                let rv_err = crate::parallel::try_for_each_mut(&mut m, |idx, val| {
+                    let j = idx / self.size[1];
+                    let i = idx % self.size[0];
+
+                    match minor(self, j, i).det() {
+                        Ok(det) => {
+                            if (i + j) % 2 == 1 {
+                                *val = -det;
+                            } else {
+                                *val = det;
                             }
-                        });
 
-                        match rv_err {
-                            Ok(_) => Ok(Self::Output { m, size: self.size }),
-                            Err(err) => Err(err),
+                            Ok(())
                         }
+                        Err(err) => Err(err),
                     }
+                });
+
+                match rv_err {
+                    Ok(_) => Ok(Self::Output { m, size: self.size }),
+                    Err(err) => Err(err),
                 }
             }
         }
-    )*)
+    }
 }
 
-impl_cofactor! { i8 u8 i16 u16 i32 u32 f32 f64 }
+impl<T> crate::linear::Inverse<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn inverse(&self) -> crate::error::SlalErr<Self::Output, T> {
+        use crate::error::SlalError;
+        use crate::linear::{Cofactor, Determinant};
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
 
-macro_rules! impl_inverse {
-    ($($t:ty)*) => ($(
-        impl crate::linear::Inverse<$t> for super::Matrix<$t> {
-            fn inverse(&self) -> crate::error::SlalErr<Self::Output, $t> {
-                use crate::error::SlalError;
-                use crate::linear::{Determinant, Cofactor};
-
-                if self.size[0] != self.size[1] {
-                    return Err(SlalError::NotSquareMatrix(
-                        format!("{:?}", *self),
-                        format!("{}", self.size[0]),
-                        format!("{}", self.size[1]),
-                    ));
-                }
+        let det = self.det().unwrap_or(0.);
 
-                let det = match self.det() {
-                    Ok(det) => det,
-                    Err(_) => 0.
-                };
+        if det == 0. {
+            return Err(SlalError::DeterminantZero(self.clone()));
+        }
 
-                if det == 0. {
-                    return Err(SlalError::DeterminantZero(self.clone()))
-                }
+        let mut cof: super::Matrix<f64>;
+        match self.cofactor() {
+            Ok(cof_m) => cof = cof_m,
+            Err(err) => return Err(err),
+        };
 
-                let mut cof: super::Matrix<f64>;
-                match self.cofactor() {
-                    Ok(cof_m) => cof = cof_m,
-                    Err(err) => return Err(err),
-                };
+        cof.t();
 
-                cof.t();
+        Ok((1. / det) * cof)
+    }
+}
 
-                Ok((1./det) * cof)
-            }
+impl<T> crate::linear::Solve<crate::vertex::Vertex<T>> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::linear::LuDecomposition;
+        use crate::vertex::Vertex;
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != self.size[1] {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving linear system"),
+            ));
         }
-    )*)
+
+        let lu = self.lu()?;
+
+        if lu.is_singular() {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        let mut x = b.to_f64().to_vec();
+
+        lu.substitute(&mut x);
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Solve<super::Matrix<T>> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<super::Matrix<f64>, T>;
+
+    fn solve(&self, b: &super::Matrix<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::linear::LuDecomposition;
+
+        if b.size[1] != self.size[1] {
+            return Err(SlalError::UnmatchingMatrixSize(
+                format!("{:?}", *self),
+                format!("{:?}", *b),
+            ));
+        }
+
+        let lu = self.lu()?;
+
+        if lu.is_singular() {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(lu.substitute_matrix(&b.to_f64()))
+    }
+}
+
+impl<T> crate::linear::PseudoInverse<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn pinv(&self, tol: f64) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::linear::SingularValueDecomposition;
+
+        Ok(self.svd()?.pinv(tol))
+    }
+}
+
+impl<T> crate::linear::LeastSquares<crate::vertex::Vertex<T>> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<super::Lstsq, T>;
+
+    fn lstsq(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::linear::SingularValueDecomposition;
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving least squares problem of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != self.size[1] {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving least squares problem"),
+            ));
+        }
+
+        let svd = self.svd()?;
+
+        Ok(super::decomposition::lstsq(
+            &self.to_f64(),
+            &svd,
+            b.to_f64().to_vec().as_slice(),
+        ))
+    }
 }
 
-impl_inverse! { i8 u8 i16 u16 i32 u32 f32 f64 }
+impl crate::linear::LinearOperator for super::Matrix<f64> {
+    fn rows(&self) -> usize {
+        self.size[1]
+    }
+
+    fn columns(&self) -> usize {
+        self.size[0]
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        crate::parallel::for_each_mut(y, |j, y_j| {
+            *y_j = self[j].iter().zip(x.iter()).map(|(a, x)| a * x).sum()
+        });
+    }
+}
 
 macro_rules! impl_random_signed {
     ($($t:ty)*) => ($(
@@ -436,12 +519,11 @@ macro_rules! impl_random_signed {
 
             fn rand(size: Self::Size) -> Self::Output {
                 use rand::{Rng, self};
-                use rayon::prelude::*;
 
                 const DELTA: f64 = 1e-6;
 
                 let mut m = vec![0 as $t; size[0] * size[1]];
-                m.par_iter_mut().for_each(|m_ji| {
+                crate::parallel::for_each_mut(&mut m, |_, m_ji| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -465,12 +547,11 @@ macro_rules! impl_random_signed {
 
             fn rand_transposed(size: Self::Size) -> Self::Output {
                 use rand::{Rng, self};
-                use rayon::prelude::*;
 
                 const DELTA: f64 = 1e-6;
 
                 let mut m = vec![0 as $t; size[0] * size[1]];
-                m.par_iter_mut().for_each(|m_ji| {
+                crate::parallel::for_each_mut(&mut m, |_, m_ji| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -505,10 +586,9 @@ macro_rules! impl_random_unsigned {
 
             fn rand(size: Self::Size) -> Self::Output {
                 use rand::{Rng, self};
-                use rayon::prelude::*;
 
                 let mut m = vec![0 as $t; size[0] * size[1]];
-                m.par_iter_mut().for_each(|m_ji| {
+                crate::parallel::for_each_mut(&mut m, |_, m_ji| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -528,10 +608,9 @@ macro_rules! impl_random_unsigned {
 
             fn rand_transposed(size: Self::Size) -> Self::Output {
                 use rand::{Rng, self};
-                use rayon::prelude::*;
 
                 let mut m = vec![0 as $t; size[0] * size[1]];
-                m.par_iter_mut().for_each(|m_ji| {
+                crate::parallel::for_each_mut(&mut m, |_, m_ji| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -554,102 +633,88 @@ macro_rules! impl_random_unsigned {
 
 impl_random_unsigned! { u8 u16 u32 u64 u128 usize }
 
-macro_rules! impl_normlize {
-    ($($t:ty)*) => ($(
-        impl crate::linear::Normalize for super::Matrix<$t> {
-            type Output = super::Matrix<f64>;
-
-            fn norm(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                // normalization scala for individual rows
-                let mut norm_scalas = vec![0.; self.size[1]];
-                norm_scalas.par_iter_mut().enumerate().for_each(|(j, scala)| {
-                    *scala = (0..self.size[0])
-                        .into_par_iter()
-                        .map(|i| (self[j][i] as f64).powi(2))
-                        .sum::<f64>()
-                        .sqrt();
-                });
-
-                let mut m = vec![0.; self.size[0] * self.size[1]];
-                m.par_iter_mut().enumerate().for_each(|(idx, m_ji)| {
-                    *m_ji = self.m[idx] as f64 / norm_scalas[idx / self.size[0]];
-                });
-
-                Self::Output {
-                    m,
-                    size: self.size,
-                }
-            }
-        }
-    )*)
+impl<T> crate::linear::Normalize for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = super::Matrix<f64>;
+
+    fn norm(&self) -> Self::Output {
+        // normalization scala for individual rows
+        let mut norm_scalas = vec![0.; self.size[1]];
+        crate::parallel::for_each_mut(&mut norm_scalas, |j, scala| {
+            *scala = crate::parallel::map_sum(self.size[0], |i| self[j][i].to_f64().powi(2)).sqrt();
+        });
+
+        let mut m = vec![0.; self.size[0] * self.size[1]];
+        crate::parallel::for_each_mut(&mut m, |idx, m_ji| {
+            *m_ji = self.m[idx].to_f64() / norm_scalas[idx / self.size[0]];
+        });
+
+        Self::Output { m, size: self.size }
+    }
 }
 
-impl_normlize! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
-
-macro_rules! impl_eigen {
-    ($($t:ty)*) => ($(
-        impl crate::linear::Eigen for super::Matrix<$t> {
-            type Output = f64;
-
-            fn eigen(
-                &self,
-            ) -> crate::error::SlalErr<(crate::vertex::Vertex<Self::Output>, Self::Output), Self::Output> {
-                use crate::linear::{Normalize, Random, Dot};
-                use crate::vertex::Vertex;
-                use crate::error::SlalError;
-
-                const MAX_ITERATION: usize = 100;
-                const TOLERANCE: f64 = 1e-10;
-
-                if self.size[0] != self.size[1] {
-                    return Err(SlalError::NotSquareMatrix(
-                        format!("{:?}", *self),
-                        format!("{}", self.size[0]),
-                        format!("{}", self.size[1]),
-                    ));
-                }
-
-                let m = super::Matrix::<f64>::from(self.clone());
-
-                let mut eigen_v = Vertex::<$t>::rand_transposed(self.size[1]).norm();
-                let mut lambda: f64 = 0.;
-                for _ in 0..MAX_ITERATION {
-                    let a_v = if eigen_v.is_transposed() {
-                        m.dot(&eigen_v)?
-                    } else {
-                        eigen_v.t();
-                        m.dot(&eigen_v)?
-                    };
-                    let v_new = a_v.norm();
-                    let lambda_new = if eigen_v.is_transposed() {
-                        let eigen_tmp = eigen_v.clone();
-
-                        eigen_v.t();
-                        eigen_v.dot(&a_v)? / eigen_v.dot(&eigen_tmp)?
-                    } else {
-                        let mut eigen_tmp = eigen_v.clone();
-
-                        eigen_tmp.t();
-                        eigen_v.dot(&a_v)? / eigen_v.dot(&eigen_tmp)?
-                    };
-
-                    if (lambda_new - lambda).abs() < TOLERANCE {
-                        break;
-                    }
-
-                    eigen_v = v_new;
-                    lambda = lambda_new;
-                }
+impl<T> crate::linear::Eigen for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn eigen(
+        &self,
+    ) -> crate::error::SlalErr<(crate::vertex::Vertex<Self::Output>, Self::Output), Self::Output>
+    {
+        use crate::error::SlalError;
+        use crate::linear::{Dot, Normalize, Random};
+        use crate::vertex::Vertex;
+
+        const MAX_ITERATION: usize = 100;
+        const TOLERANCE: f64 = 1e-10;
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
 
-                Ok((eigen_v, lambda))
+        let m = self.to_f64();
+
+        let mut eigen_v = Vertex::<f64>::rand_transposed(self.size[1]).norm();
+        let mut lambda: f64 = 0.;
+        for _ in 0..MAX_ITERATION {
+            let a_v = if eigen_v.is_transposed() {
+                m.dot(&eigen_v)?
+            } else {
+                eigen_v.t();
+                m.dot(&eigen_v)?
+            };
+            let v_new = a_v.norm();
+            let lambda_new = if eigen_v.is_transposed() {
+                let eigen_tmp = eigen_v.clone();
+
+                eigen_v.t();
+                eigen_v.dot(&a_v)? / eigen_v.dot(&eigen_tmp)?
+            } else {
+                let mut eigen_tmp = eigen_v.clone();
+
+                eigen_tmp.t();
+                eigen_v.dot(&a_v)? / eigen_v.dot(&eigen_tmp)?
+            };
+
+            if (lambda_new - lambda).abs() < TOLERANCE {
+                break;
             }
+
+            eigen_v = v_new;
+            lambda = lambda_new;
         }
-    )*)
-}
 
-impl_eigen! { i8 u8 i16 u16 i32 u32 f32 f64 }
+        Ok((eigen_v, lambda))
+    }
+}
 
 macro_rules! impl_inner_prod_i32_or_smaller {
     ($($t:ty)*) => ($(
@@ -658,7 +723,6 @@ macro_rules! impl_inner_prod_i32_or_smaller {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = super::Matrix::<i32>::from(self.clone());
@@ -669,7 +733,7 @@ macro_rules! impl_inner_prod_i32_or_smaller {
                 };
 
                 match m.dot(&super::Matrix::<i32>::from(self.clone())) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
@@ -686,7 +750,6 @@ macro_rules! impl_inner_prod_u32_or_smaller {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = super::Matrix::<u32>::from(self.clone());
@@ -697,7 +760,7 @@ macro_rules! impl_inner_prod_u32_or_smaller {
                 };
 
                 match m.dot(&super::Matrix::<u32>::from(self.clone())) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
@@ -714,7 +777,6 @@ macro_rules! impl_inner_prod_large_signed_int {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = super::Matrix::<i128>::from(self.clone());
@@ -725,7 +787,7 @@ macro_rules! impl_inner_prod_large_signed_int {
                 };
 
                 match m.dot(&super::Matrix::<i128>::from(self.clone())) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
@@ -742,7 +804,6 @@ macro_rules! impl_inner_prod_large_unsigned_int {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = super::Matrix::<u128>::from(self.clone());
@@ -753,7 +814,7 @@ macro_rules! impl_inner_prod_large_unsigned_int {
                 };
 
                 match m.dot(&super::Matrix::<u128>::from(self.clone())) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
@@ -770,7 +831,6 @@ macro_rules! impl_inner_prod_size {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = self.clone();
@@ -781,7 +841,7 @@ macro_rules! impl_inner_prod_size {
                 };
 
                 match m.dot(self) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
@@ -798,7 +858,6 @@ macro_rules! impl_inner_prod_float {
 
             fn inner(&self) -> Self::Output {
                 use crate::linear::Dot;
-                use rayon::prelude::*;
 
                 let m = {
                     let mut m = super::Matrix::<f64>::from(self.clone());
@@ -809,7 +868,7 @@ macro_rules! impl_inner_prod_float {
                 };
 
                 match m.dot(&super::Matrix::<f64>::from(self.clone())) {
-                    Ok(mtx) => Ok(mtx.m.par_iter().map(|m_ji| *m_ji).sum()),
+                    Ok(mtx) => Ok(crate::parallel::map_sum(mtx.m.len(), |idx| mtx.m[idx])),
                     Err(err) => Err(err)
                 }
             }
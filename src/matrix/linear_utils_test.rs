@@ -1,9 +1,10 @@
 use super::Matrix;
-use crate::error::SlalErr;
+use crate::error::{SlalErr, SlalError};
 use crate::linear::{
-    Cofactor, Determinant, DiagonalMatrix, Eigen, InnerProduct, Inverse, Normalize, Random,
-    TriangularMatrix,
+    Cofactor, Determinant, DiagonalMatrix, Dot, Eigen, InnerProduct, Inverse, LeastSquares,
+    Normalize, PseudoInverse, Random, Solve, TriangularMatrix,
 };
+use crate::vertex::Vertex;
 
 #[test]
 fn is_upper_triangular() {
@@ -228,21 +229,54 @@ fn determinant_4d_and_above() {
     ])
     .unwrap();
 
-    let triangular = m.upper_triangular().unwrap();
-    let triangular_vec = triangular.to_vec();
-
+    // Rows are linearly dependent
     match m.det() {
-        Ok(det) => {
-            let mut excepted = 1.;
-            let size = triangular.size().1;
-            (0..size).for_each(|i| excepted *= triangular_vec[i][i]);
-
-            assert!(det == excepted);
-        }
+        Ok(det) => assert!(det == 0.),
         Err(_) => assert!(false),
     }
 }
 
+#[test]
+fn determinant_4d_pivoting() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[0, 1, 1, 0], &[1, 0, 0, 1], &[2, 3, 1, 0], &[0, 2, 1, 4]])?;
+
+    assert!((m.det()? - 10.).abs() < 1e-10);
+
+    Ok(())
+}
+
+#[test]
+fn determinant_4d_small_entries() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[
+        &[4e-11, 1e-11, 0., 0.],
+        &[1e-11, 4e-11, 1e-11, 0.],
+        &[0., 1e-11, 4e-11, 1e-11],
+        &[0., 0., 1e-11, 4e-11],
+    ])?;
+
+    // Same as the determinant of [[4, 1, 0, 0], [1, 4, 1, 0], [0, 1, 4, 1], [0, 0, 1, 4]] scaled by 1e-44
+    assert!((m.det()? - 209e-44).abs() < 1e-50);
+
+    Ok(())
+}
+
+#[test]
+fn determinant_wide_integers() -> SlalErr<(), i64> {
+    let m = Matrix::<i64>::new(&[&[0, 1, 1, 0], &[1, 0, 0, 1], &[2, 3, 1, 0], &[0, 2, 1, 4]])?;
+
+    assert!((m.det()? - 10.).abs() < 1e-10);
+    assert!(Matrix::<u64>::new(&[&[1, 2], &[3, 4]]).unwrap().det() == Ok(-2.));
+    assert!(
+        Matrix::<i128>::new(&[&[2, 0, 0], &[0, 3, 0], &[0, 0, 4]])
+            .unwrap()
+            .det()
+            == Ok(24.)
+    );
+    assert!(Matrix::<usize>::new(&[&[9]]).unwrap().det() == Ok(9.));
+
+    Ok(())
+}
+
 #[test]
 fn determinant_triangular() {
     let m = Matrix::new(&[&[1., 2.], &[0., 2.]]).unwrap();
@@ -474,7 +508,7 @@ fn cofactor_empty() {
 }
 
 #[test]
-fn cofactor_determinant_not_exist() {
+fn cofactor_singular() -> SlalErr<(), u8> {
     let m = Matrix::<u8> {
         m: vec![
             0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
@@ -483,7 +517,10 @@ fn cofactor_determinant_not_exist() {
         size: [5, 5],
     };
 
-    assert!(m.cofactor().is_err())
+    // Matrix has rank 2, so every minor of size (4, 4) is zero
+    assert!(m.cofactor()?.m.iter().all(|c_ji| c_ji.abs() < 1e-10));
+
+    Ok(())
 }
 
 #[test]
@@ -502,6 +539,16 @@ fn inverse() {
     }
 }
 
+#[test]
+fn inverse_wide_integers() -> SlalErr<(), i64> {
+    let m = Matrix::<i64>::new(&[&[2, 0, 1], &[1, 1, 0], &[0, 1, 3]])?;
+    let expected = Matrix::new(&[&[3., 1., -1.], &[-3., 6., 1.], &[1., -2., 2.]]).unwrap();
+
+    assert!(approx_eq(&m.inverse()?, &((1. / 7.) * expected)));
+
+    Ok(())
+}
+
 #[test]
 fn inverse_not_square() {
     let m = Matrix::<u16> {
@@ -528,6 +575,247 @@ fn inverse_determinant_zero_value() {
     }
 }
 
+#[test]
+fn solve_vertex() -> SlalErr<(), i32> {
+    // x + 2y + 3z = 14
+    // 2x - y + z = 3
+    // 3x + y - z = 2
+    let m = Matrix::<i32>::new(&[&[1, 2, 3], &[2, -1, 1], &[3, 1, -1]])?;
+    let b = Vertex::<i32>::new_transposed(&[14, 3, 2]);
+    let x = m.solve(&b)?;
+
+    assert!(x.is_transposed());
+    assert!(x
+        .to_vec()
+        .iter()
+        .zip([1., 2., 3.].iter())
+        .all(|(x_i, ans)| (x_i - ans).abs() < 1e-10));
+
+    Ok(())
+}
+
+#[test]
+fn solve_vertex_wide_integers() -> SlalErr<(), u64> {
+    let m = Matrix::<u64>::new(&[&[1, 2, 3], &[2, 1, 1], &[3, 1, 2]])?;
+    let b = Vertex::<u64>::new_transposed(&[14, 7, 11]);
+    let x = m.solve(&b)?;
+
+    assert!(x
+        .to_vec()
+        .iter()
+        .zip([1., 2., 3.].iter())
+        .all(|(x_i, ans)| (x_i - ans).abs() < 1e-10));
+
+    Ok(())
+}
+
+#[test]
+fn solve_vertex_pivoting() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[0., 1.], &[1., 0.]])?;
+    let b = Vertex::<f64>::new_transposed(&[2., 3.]);
+
+    assert_eq!(m.solve(&b)?, Vertex::new_transposed(&[3., 2.]));
+
+    Ok(())
+}
+
+#[test]
+fn solve_vertex_small_coefficients() -> SlalErr<(), f64> {
+    // 2e-11x + 1e-11y = 4e-11
+    // 1e-11x + 2e-11y = 5e-11
+    let m = Matrix::<f64>::new(&[&[2e-11, 1e-11], &[1e-11, 2e-11]])?;
+    let b = Vertex::<f64>::new_transposed(&[4e-11, 5e-11]);
+    let x = m.solve(&b)?;
+
+    assert!(x
+        .to_vec()
+        .iter()
+        .zip([1., 2.].iter())
+        .all(|(x_i, ans)| (x_i - ans).abs() < 1e-10));
+
+    Ok(())
+}
+
+#[test]
+fn solve_matrix() -> SlalErr<(), u8> {
+    let m = Matrix::<u8>::new(&[&[2, 1], &[1, 3]])?;
+    let b = Matrix::<u8>::new(&[&[3, 2, 1], &[5, 1, 3]])?;
+    let x = m.solve(&b)?;
+    let ans = [[0.8, 1., 0.], [1.4, 0., 1.]];
+
+    assert_eq!(x.size(), (3, 2));
+    assert!((0..2).all(|j| (0..3).all(|i| (x[j][i] - ans[j][i]).abs() < 1e-10)));
+
+    Ok(())
+}
+
+#[test]
+fn solve_singular() {
+    let m = Matrix::<i16>::new(&[&[1, 2], &[2, 4]]).unwrap();
+    let b = Vertex::<i16>::new_transposed(&[1, 2]);
+
+    assert_eq!(
+        m.solve(&b),
+        Err(SlalError::SingularMatrix(format!("{:?}", m)))
+    );
+}
+
+#[test]
+fn solve_vertex_not_transposed() {
+    let m = Matrix::<f32>::new(&[&[1., 2.], &[3., 4.]]).unwrap();
+    let b = Vertex::<f32>::new(&[1., 2.]);
+
+    assert!(m.solve(&b).is_err());
+}
+
+#[test]
+fn solve_unmatching_size() {
+    let m = Matrix::<u16>::new(&[&[1, 2], &[3, 4]]).unwrap();
+    let b = Vertex::<u16>::new_transposed(&[1, 2, 3]);
+    let n = Matrix::<u16>::new(&[&[1], &[2], &[3]]).unwrap();
+
+    assert!(m.solve(&b).is_err());
+    assert!(m.solve(&n).is_err());
+}
+
+#[test]
+fn solve_not_square() {
+    let m = Matrix::<u32>::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+    let b = Vertex::<u32>::new_transposed(&[1, 2]);
+
+    assert!(m.solve(&b).is_err());
+}
+
+fn approx_eq(m: &Matrix<f64>, n: &Matrix<f64>) -> bool {
+    m.size == n.size
+        && m.m
+            .iter()
+            .zip(n.m.iter())
+            .all(|(a, b)| (a - b).abs() < 1e-10)
+}
+
+#[test]
+fn pinv_invertible() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[4., 7.], &[2., 6.]]).unwrap();
+
+    assert!(approx_eq(
+        &m.pinv(1e-10)?,
+        &Matrix::new(&[&[0.6, -0.7], &[-0.2, 0.4]]).unwrap()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn pinv_tall() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[1., 2.], &[3., 4.], &[5., 6.]]).unwrap();
+    let pinv = m.pinv(1e-10)?;
+
+    assert!(pinv.size() == (3, 2));
+    // Left inverse of matrix with full column rank
+    assert!(approx_eq(
+        &pinv.dot(&m)?,
+        &Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap()
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn pinv_rank_deficient() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, 2, 3], &[2, 4, 6]]).unwrap();
+    let m_f64 = Matrix::<f64>::from(m.clone());
+    let pinv = m.pinv(1e-10)?;
+
+    // Moore-Penrose conditions AXA = A and XAX = X
+    match (m_f64.dot(&pinv), pinv.dot(&m_f64)) {
+        (Ok(a_x), Ok(x_a)) => {
+            assert!(approx_eq(&a_x.dot(&m_f64).unwrap(), &m_f64));
+            assert!(approx_eq(&pinv.dot(&a_x).unwrap(), &pinv));
+            assert!(approx_eq(&x_a.dot(&pinv).unwrap(), &pinv));
+        }
+        _ => panic!("Failed to compute product with pseudo-inverse"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn pinv_empty() {
+    let m = Matrix::<f64>::empty();
+
+    assert!(m.pinv(1e-10).is_err());
+}
+
+#[test]
+fn lstsq_overdetermined() -> SlalErr<(), i32> {
+    let a = Matrix::<i32>::new(&[&[1, 1], &[1, 2], &[1, 3], &[1, 4]]).unwrap();
+    let b = Vertex::<i32>::new_transposed(&[6, 5, 7, 10]);
+    let lstsq = a.lstsq(&b)?;
+
+    // Regression line y = 3.5 + 1.4x
+    assert!((lstsq.x()[0] - 3.5).abs() < 1e-10);
+    assert!((lstsq.x()[1] - 1.4).abs() < 1e-10);
+    assert!((lstsq.residual() - 4.2f64.sqrt()).abs() < 1e-10);
+    assert!(lstsq.rank() == 2);
+    assert!(lstsq.x().is_transposed());
+
+    Ok(())
+}
+
+#[test]
+fn lstsq_underdetermined() -> SlalErr<(), f64> {
+    let a = Matrix::<f64>::new(&[&[1., 2., 2.]]).unwrap();
+    let b = Vertex::<f64>::new_transposed(&[9.]);
+    let lstsq = a.lstsq(&b)?;
+
+    // Minimum-norm solution is parallel to the row
+    [1., 2., 2.]
+        .iter()
+        .enumerate()
+        .for_each(|(idx, x)| assert!((lstsq.x()[idx] - x).abs() < 1e-10));
+    assert!(lstsq.residual() < 1e-10);
+    assert!(lstsq.rank() == 1);
+
+    Ok(())
+}
+
+#[test]
+fn lstsq_rank_deficient() -> SlalErr<(), u8> {
+    let a = Matrix::<u8>::new(&[&[1, 1], &[1, 1]]).unwrap();
+    let b = Vertex::<u8>::new_transposed(&[1, 3]);
+    let lstsq = a.lstsq(&b)?;
+
+    assert!((lstsq.x()[0] - 1.).abs() < 1e-10);
+    assert!((lstsq.x()[1] - 1.).abs() < 1e-10);
+    assert!((lstsq.residual() - 2f64.sqrt()).abs() < 1e-10);
+    assert!(lstsq.rank() == 1);
+
+    Ok(())
+}
+
+#[test]
+fn lstsq_vertex_not_transposed() {
+    let a = Matrix::<f32>::new(&[&[1., 2.], &[3., 4.], &[5., 6.]]).unwrap();
+    let b = Vertex::<f32>::new(&[1., 2., 3.]);
+
+    match a.lstsq(&b) {
+        Err(SlalError::VertexStateError(_)) => (),
+        _ => panic!("Expected VertexStateError"),
+    }
+}
+
+#[test]
+fn lstsq_unmatching_size() {
+    let a = Matrix::<f32>::new(&[&[1., 2.], &[3., 4.], &[5., 6.]]).unwrap();
+    let b = Vertex::<f32>::new_transposed(&[1., 2.]);
+
+    match a.lstsq(&b) {
+        Err(SlalError::VertexLengthAndMatrixHeightNotMatch(_, _, _)) => (),
+        _ => panic!("Expected VertexLengthAndMatrixHeightNotMatch"),
+    }
+}
+
 #[test]
 fn norm() {
     let m = Matrix::<u32> {
@@ -611,3 +899,105 @@ fn inner() {
         Err(_) => assert!(false),
     }
 }
+
+// Fixed point number with three decimal places, standing in for a scalar type defined downstream
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fixed(i64);
+
+impl std::ops::Add for Fixed {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Fixed(self.0 + other.0)
+    }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Fixed(self.0 - other.0)
+    }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Fixed(self.0 * other.0 / 1000)
+    }
+}
+
+impl std::ops::Div for Fixed {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Fixed(self.0 * 1000 / other.0)
+    }
+}
+
+impl std::ops::AddAssign for Fixed {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl std::ops::SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl std::ops::MulAssign for Fixed {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl std::iter::Sum for Fixed {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Fixed(0), |acc, x| acc + x)
+    }
+}
+
+impl crate::scalar::Scalar for Fixed {
+    fn zero() -> Self {
+        Fixed(0)
+    }
+
+    fn one() -> Self {
+        Fixed(1000)
+    }
+}
+
+impl crate::scalar::RealScalar for Fixed {
+    fn to_f64(self) -> f64 {
+        self.0 as f64 / 1000.
+    }
+}
+
+#[test]
+fn custom_scalar() -> SlalErr<(), Fixed> {
+    // | 1.5 0.0 0.5 |
+    // | 0.5 1.0 0.0 |
+    // | 0.0 1.0 2.0 |
+    let m = Matrix::new(&[
+        &[Fixed(1500), Fixed(0), Fixed(500)],
+        &[Fixed(500), Fixed(1000), Fixed(0)],
+        &[Fixed(0), Fixed(1000), Fixed(2000)],
+    ])?;
+    let ones = Vertex::new_transposed(&[Fixed(1000), Fixed(1000), Fixed(1000)]);
+    let b = Vertex::new_transposed(&[Fixed(2000), Fixed(1500), Fixed(3000)]);
+    let expected = Matrix::new(&[&[8., 2., -2.], &[-4., 12., 1.], &[2., -6., 6.]]).unwrap();
+
+    assert!((m.det()? - 3.25).abs() < 1e-10);
+    assert!(m.dot(&ones)? == b);
+    assert!(m
+        .solve(&b)?
+        .to_vec()
+        .iter()
+        .all(|x_i| (x_i - 1.).abs() < 1e-10));
+    assert!(approx_eq(&m.inverse()?, &((1. / 13.) * expected)));
+
+    Ok(())
+}
@@ -1,9 +1,20 @@
 mod convert;
+mod decomposition;
+mod eigen;
+mod function;
 mod linear_arithmatic;
 mod linear_utils;
 mod math;
+mod norm;
+mod subspace;
 mod utils;
 
+#[cfg(test)]
+mod decomposition_test;
+#[cfg(test)]
+mod eigen_test;
+#[cfg(test)]
+mod function_test;
 #[cfg(test)]
 mod linear_arithmatic_test;
 #[cfg(test)]
@@ -11,9 +22,16 @@ mod linear_utils_test;
 #[cfg(test)]
 mod math_test;
 #[cfg(test)]
+mod norm_test;
+#[cfg(test)]
+mod subspace_test;
+#[cfg(test)]
 mod utils_test;
 
 pub use crate::linear::{Cross, Determinant, DiagonalMatrix, Dot, Magnitude, TriangularMatrix};
+pub(crate) use decomposition::{eliminate, pivot_tolerance};
+pub use decomposition::{Cholesky, Ldlt, Lstsq, Lu, Qr, Svd};
+pub use eigen::{Eig, SymmetricEig};
 pub use linear_arithmatic::*;
 pub use linear_utils::*;
 pub use math::*;
@@ -131,6 +149,25 @@ where
         }
     }
 
+    /**
+    Creates matrix of size (x, y) from its elements in row-major order.
+    Assumes `m` holds exactly `size[0] * size[1]` elements.
+     */
+    pub(crate) fn from_vec(m: Vec<T>, size: [usize; 2]) -> Self {
+        if m.is_empty() {
+            return Self::empty();
+        }
+
+        Self { m, size }
+    }
+
+    /**
+    Returns the elements of matrix in row-major order
+     */
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.m
+    }
+
     /**
     Checks if matrix is empty
 
@@ -296,6 +333,30 @@ where
     }
 }
 
+impl<T> Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts every element of the matrix to f64
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+
+    let m = Matrix::<i64>::new(&[&[1, 2], &[3, 4]]).unwrap();
+
+    assert!(m.to_f64() == Matrix::new(&[&[1., 2.], &[3., 4.]]).unwrap());
+    ```
+     */
+    pub fn to_f64(&self) -> Matrix<f64> {
+        Matrix {
+            m: crate::parallel::map_collect(self.m.len(), |idx| self.m[idx].to_f64()),
+            size: self.size,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Matrix;
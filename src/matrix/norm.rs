@@ -0,0 +1,210 @@
+// Maximum number of iterations for estimating the 1-norm of the inverse
+const MAX_ESTIMATION: usize = 5;
+
+/**
+    Maximum absolute column sum
+*/
+pub(crate) fn norm_1(a: &super::Matrix<f64>) -> f64 {
+    let [n, m] = a.size;
+
+    crate::parallel::map_reduce(
+        n,
+        |i| (0..m).map(|j| a[j][i].abs()).sum::<f64>(),
+        || 0.,
+        f64::max,
+    )
+}
+
+/**
+    Maximum absolute row sum
+*/
+pub(crate) fn norm_inf(a: &super::Matrix<f64>) -> f64 {
+    crate::parallel::map_reduce(
+        a.size[1],
+        |j| a[j].iter().map(|a_ji| a_ji.abs()).sum::<f64>(),
+        || 0.,
+        f64::max,
+    )
+}
+
+/**
+    Estimates the 1-norm of the inverse of a non-singular matrix from its LU decomposition
+    with Hager's method, improved with Higham's alternating sign vector.
+*/
+fn inverse_norm_1(lu: &super::Lu) -> f64 {
+    let n = lu.p().len();
+    let mut x = vec![1. / n as f64; n];
+    let mut estimate = 0.;
+
+    for iteration in 0..MAX_ESTIMATION {
+        // y = A^-1 x
+        let mut y = x.clone();
+        lu.substitute(&mut y);
+        estimate = y.iter().map(|y_i| y_i.abs()).sum::<f64>();
+
+        // z = A^-T sign(y)
+        let mut z: Vec<f64> = y
+            .iter()
+            .map(|y_i| if *y_i >= 0. { 1. } else { -1. })
+            .collect();
+        lu.substitute_transposed(&mut z);
+
+        let (j, z_max) = z.iter().enumerate().fold((0, 0.), |(j, z_max), (i, z_i)| {
+            if z_i.abs() > z_max {
+                (i, z_i.abs())
+            } else {
+                (j, z_max)
+            }
+        });
+        let z_x: f64 = z.iter().zip(x.iter()).map(|(z_i, x_i)| z_i * x_i).sum();
+
+        if iteration > 0 && z_max <= z_x {
+            break;
+        }
+
+        x = vec![0.; n];
+        x[j] = 1.;
+    }
+
+    // Alternating sign vector catches cases where the gradient method stalls
+    let mut x: Vec<f64> = (0..n)
+        .map(|i| {
+            let sign = if i % 2 == 0 { 1. } else { -1. };
+
+            sign * (1. + i as f64 / (n.max(2) - 1) as f64)
+        })
+        .collect();
+    lu.substitute(&mut x);
+
+    estimate.max(2. * x.iter().map(|x_i| x_i.abs()).sum::<f64>() / (3. * n as f64))
+}
+
+/**
+    Estimates the reciprocal condition number `1 / (|A|_1 |A^-1|_1)` of a square matrix.
+    Returns 0 for singular matrices.
+*/
+pub(crate) fn rcond(a: &super::Matrix<f64>) -> f64 {
+    let lu = super::decomposition::lu(a);
+    let norm = norm_1(a);
+
+    if norm == 0. || lu.is_singular() {
+        return 0.;
+    }
+
+    1. / (norm * inverse_norm_1(&lu))
+}
+
+impl<T> crate::linear::Norm<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<f64, T>;
+
+    fn norm_1(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(norm_1(&self.to_f64()))
+    }
+
+    fn norm_2(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        match super::decomposition::singular_values(&self.to_f64()) {
+            Some(s) => Ok(s[0]),
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", super::decomposition::SVD_MAX_SWEEP),
+            )),
+        }
+    }
+
+    fn norm_inf(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(norm_inf(&self.to_f64()))
+    }
+
+    fn norm_fro(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(crate::parallel::map_sum(self.m.len(), |idx| self.m[idx].to_f64().powi(2)).sqrt())
+    }
+
+    fn norm_max(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        Ok(crate::parallel::map_reduce(
+            self.m.len(),
+            |idx| self.m[idx].to_f64().abs(),
+            || 0.,
+            f64::max,
+        ))
+    }
+}
+
+impl<T> crate::linear::Condition<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn cond(&self) -> crate::error::SlalErr<f64, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        match super::decomposition::singular_values(&self.to_f64()) {
+            Some(s) => {
+                let s_min = s[s.len() - 1];
+
+                Ok(if s_min == 0. {
+                    f64::INFINITY
+                } else {
+                    s[0] / s_min
+                })
+            }
+            None => Err(SlalError::NotConverged(
+                format!("{:?}", *self),
+                format!("{}", super::decomposition::SVD_MAX_SWEEP),
+            )),
+        }
+    }
+
+    fn rcond(&self) -> crate::error::SlalErr<f64, T> {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        if self.size[0] != self.size[1] {
+            return Err(SlalError::NotSquareMatrix(
+                format!("{:?}", *self),
+                format!("{}", self.size[0]),
+                format!("{}", self.size[1]),
+            ));
+        }
+
+        Ok(rcond(&self.to_f64()))
+    }
+}
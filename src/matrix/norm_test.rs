@@ -0,0 +1,144 @@
+use super::Matrix;
+use crate::error::{SlalErr, SlalError};
+use crate::linear::{Condition, Inverse, Norm};
+
+#[test]
+fn norm_1() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, -7, 2], &[-3, 4, 0]]).unwrap();
+
+    assert!(m.norm_1()? == 11.);
+
+    Ok(())
+}
+
+#[test]
+fn norm_inf() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, -7, 2], &[-3, 4, 0]]).unwrap();
+
+    assert!(m.norm_inf()? == 10.);
+
+    Ok(())
+}
+
+#[test]
+fn norm_fro() -> SlalErr<(), u64> {
+    let m = Matrix::<u64>::new(&[&[1, 2], &[2, 4], &[0, 0]]).unwrap();
+
+    assert!(m.norm_fro()? == 5.);
+
+    Ok(())
+}
+
+#[test]
+fn norm_max() -> SlalErr<(), f32> {
+    let m = Matrix::<f32>::new(&[&[1.5, -2.5], &[0.5, 2.]]).unwrap();
+
+    assert!(m.norm_max()? == 2.5);
+
+    Ok(())
+}
+
+#[test]
+fn norm_2() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[3., 2., 2.], &[2., 3., -2.]]).unwrap();
+
+    assert!((m.norm_2()? - 5.).abs() < 1e-10);
+
+    Ok(())
+}
+
+#[test]
+fn norm_2_bounded_by_frobenius() -> SlalErr<(), i16> {
+    let m = Matrix::<i16>::new(&[&[1, 2, 3], &[4, 5, 6], &[7, 8, 10]]).unwrap();
+    let norm_2 = m.norm_2()?;
+
+    assert!(norm_2 <= m.norm_fro()?);
+    assert!(norm_2 * norm_2 <= m.norm_1()? * m.norm_inf()? + 1e-10);
+
+    Ok(())
+}
+
+#[test]
+fn norm_empty() {
+    let m = Matrix::<f64>::empty();
+
+    match (m.norm_1(), m.norm_2(), m.norm_fro()) {
+        (
+            Err(SlalError::EmptyMatrix(_)),
+            Err(SlalError::EmptyMatrix(_)),
+            Err(SlalError::EmptyMatrix(_)),
+        ) => (),
+        _ => panic!("Expected EmptyMatrix"),
+    }
+}
+
+#[test]
+fn cond() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[1., 0.], &[0., 1e-3]]).unwrap();
+
+    assert!((m.cond()? - 1e3).abs() < 1e-7);
+
+    Ok(())
+}
+
+#[test]
+fn cond_singular() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, 2], &[2, 4]]).unwrap();
+
+    assert!(m.cond()? == f64::INFINITY);
+
+    Ok(())
+}
+
+#[test]
+fn rcond() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[1., 2., 0.], &[3., 1., 4.], &[0., 5., 1.]]).unwrap();
+    let inverse = m.inverse()?;
+    let norm_inverse: f64 = (0..3)
+        .map(|i| (0..3).map(|j| inverse[j][i].abs()).sum::<f64>())
+        .fold(0., f64::max);
+    let exact = 1. / (m.norm_1()? * norm_inverse);
+
+    // The estimate of |A^-1| never exceeds the exact value
+    let rcond = m.rcond()?;
+    assert!(rcond >= exact - 1e-12);
+    assert!(rcond <= 3. * exact);
+
+    Ok(())
+}
+
+#[test]
+fn rcond_hilbert() -> SlalErr<(), f64> {
+    let n = 6;
+    let m = Matrix::<f64> {
+        m: (0..n * n)
+            .map(|idx| 1. / ((idx / n + idx % n + 1) as f64))
+            .collect(),
+        size: [n, n],
+    };
+
+    // Hilbert matrix of size 6 has condition number around 3e7 in the 1-norm
+    let rcond = m.rcond()?;
+    assert!(rcond > 1e-8 && rcond < 1e-6);
+
+    Ok(())
+}
+
+#[test]
+fn rcond_singular() -> SlalErr<(), u8> {
+    let m = Matrix::<u8>::new(&[&[1, 2, 3], &[2, 4, 6], &[1, 1, 1]]).unwrap();
+
+    assert!(m.rcond()? == 0.);
+
+    Ok(())
+}
+
+#[test]
+fn rcond_not_square() {
+    let m = Matrix::<i32>::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    match m.rcond() {
+        Err(SlalError::NotSquareMatrix(_, _, _)) => (),
+        _ => panic!("Expected NotSquareMatrix"),
+    }
+}
@@ -0,0 +1,195 @@
+/**
+    Computes an orthonormal basis of the null space of a matrix with size (n, m)
+    from its singular value decomposition, treating singular values not above `tol` as 0.
+    Basis vectors are stored as columns of a matrix with size (n - rank, n).
+*/
+pub(crate) fn null_space(svd: &super::Svd, tol: f64) -> super::Matrix<f64> {
+    let rank = svd.rank(tol);
+    let n = svd.v_t().size[0];
+
+    if rank == n {
+        return super::Matrix::empty();
+    }
+
+    // Null space is the orthogonal complement of the row space
+    let mut basis: Vec<Vec<f64>> = (0..rank)
+        .map(|j| (0..n).map(|i| svd.v_t()[j][i]).collect())
+        .collect();
+    while basis.len() < n {
+        basis.push(super::decomposition::orthonormal_complement(&basis, n));
+    }
+
+    super::Matrix {
+        m: (0..n)
+            .flat_map(|j| basis[rank..].iter().map(move |b| b[j]))
+            .collect(),
+        size: [n - rank, n],
+    }
+}
+
+/**
+    Computes an orthonormal basis of the column space of a matrix with size (n, m)
+    from its singular value decomposition, treating singular values not above `tol` as 0.
+    Basis vectors are stored as columns of a matrix with size (rank, m).
+*/
+pub(crate) fn column_space(svd: &super::Svd, tol: f64) -> super::Matrix<f64> {
+    let rank = svd.rank(tol);
+    let m = svd.u().size[1];
+
+    if rank == 0 {
+        return super::Matrix::empty();
+    }
+
+    super::Matrix {
+        m: (0..m)
+            .flat_map(|j| (0..rank).map(move |i| svd.u()[j][i]))
+            .collect(),
+        size: [rank, m],
+    }
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.abs(), b.abs());
+
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/**
+    Computes the reduced row echelon form with fraction-free Gauss-Jordan elimination.
+    Every row is kept as integers divided by their greatest common divisor,
+    and pivot rows are divided by their pivots into exact rational numbers at the end.
+    Returns `None` on overflow.
+*/
+pub(crate) fn rref(
+    a: &super::Matrix<i128>,
+) -> Option<(super::Matrix<crate::rational::Rational<i128>>, Vec<usize>)> {
+    use crate::rational::Rational;
+
+    let [n, m] = a.size;
+    let mut rows: Vec<Vec<i128>> = (0..m).map(|j| a.m[j * n..(j + 1) * n].to_vec()).collect();
+    let mut pivots: Vec<usize> = Vec::with_capacity(n.min(m));
+
+    for i in 0..n {
+        let row = pivots.len();
+
+        if row == m {
+            break;
+        }
+
+        let pivot_row = match (row..m).find(|j| rows[*j][i] != 0) {
+            Some(j) => j,
+            None => continue,
+        };
+        rows.swap(row, pivot_row);
+        if rows[row][i] < 0 {
+            for x in rows[row].iter_mut() {
+                *x = x.checked_neg()?;
+            }
+        }
+
+        let pivot = rows[row].clone();
+        for (j, r) in rows.iter_mut().enumerate() {
+            if j == row || r[i] == 0 {
+                continue;
+            }
+
+            // r = pivot[i] * r - r[i] * pivot
+            let factor = r[i];
+            for (x, p) in r.iter_mut().zip(pivot.iter()) {
+                *x = pivot[i]
+                    .checked_mul(*x)?
+                    .checked_sub(factor.checked_mul(*p)?)?;
+            }
+
+            let divisor = r.iter().fold(0, |d, x| gcd(d, *x));
+            if divisor > 1 {
+                r.iter_mut().for_each(|x| *x /= divisor);
+            }
+        }
+
+        pivots.push(i);
+    }
+
+    let mut rv: Vec<Rational<i128>> = Vec::with_capacity(n * m);
+    for (j, r) in rows.iter().enumerate() {
+        let pivot = pivots.get(j).map(|i| r[*i]).unwrap_or(1);
+
+        for x in r.iter() {
+            rv.push(Rational::checked_new(*x, pivot)?);
+        }
+    }
+
+    Some((
+        super::Matrix {
+            m: rv,
+            size: [n, m],
+        },
+        pivots,
+    ))
+}
+
+impl<T> crate::linear::Rank<T> for super::Matrix<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn rank(&self, tol: f64) -> crate::error::SlalErr<usize, T> {
+        use crate::linear::SingularValueDecomposition;
+
+        Ok(self
+            .singular_values()?
+            .to_vec()
+            .iter()
+            .filter(|s| **s > tol)
+            .count())
+    }
+
+    fn null_space(&self, tol: f64) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::linear::SingularValueDecomposition;
+
+        Ok(null_space(&self.svd()?, tol))
+    }
+
+    fn column_space(&self, tol: f64) -> crate::error::SlalErr<super::Matrix<f64>, T> {
+        use crate::linear::SingularValueDecomposition;
+
+        Ok(column_space(&self.svd()?, tol))
+    }
+}
+
+macro_rules! impl_reduced_row_echelon {
+    ($($t:ty)*) => ($(
+        impl crate::linear::ReducedRowEchelon<$t> for super::Matrix<$t> {
+            type Output = crate::error::SlalErr<
+                (super::Matrix<crate::rational::Rational<i128>>, Vec<usize>),
+                $t,
+            >;
+
+            fn rref(&self) -> Self::Output {
+                use crate::error::SlalError;
+
+                if self.is_empty() {
+                    return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+                }
+
+                let m = super::Matrix::<i128> {
+                    m: self.m.iter().map(|x| *x as i128).collect(),
+                    size: self.size,
+                };
+
+                match rref(&m) {
+                    Some(rref) => Ok(rref),
+                    None => Err(SlalError::ArithmeticOverflow(format!(
+                        "while computing reduced row echelon form of matrix {:?}",
+                        *self
+                    ))),
+                }
+            }
+        }
+    )*)
+}
+
+impl_reduced_row_echelon! { i8 u8 i16 u16 i32 u32 i64 u64 isize usize }
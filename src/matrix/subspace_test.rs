@@ -0,0 +1,242 @@
+use super::Matrix;
+use crate::error::{SlalErr, SlalError};
+use crate::linear::{Dot, Rank, ReducedRowEchelon};
+use crate::rational::Rational;
+
+const TOLERANCE: f64 = 1e-10;
+
+// Builds an exact rational matrix from rows of (numerator, denominator) pairs
+fn exact(rows: &[&[(i128, i128)]]) -> Matrix<Rational<i128>> {
+    let rows: Vec<Vec<Rational<i128>>> = rows
+        .iter()
+        .map(|row| row.iter().map(|(p, q)| Rational::new(*p, *q)).collect())
+        .collect();
+    let rows: Vec<&[Rational<i128>]> = rows.iter().map(|row| row.as_slice()).collect();
+
+    Matrix::new(&rows).unwrap()
+}
+
+// Checks that columns of the matrix are orthonormal
+fn is_orthonormal(m: &Matrix<f64>) -> bool {
+    let [n, len] = m.size;
+
+    (0..n).all(|j| {
+        (0..n).all(|i| {
+            let dot: f64 = (0..len).map(|k| m[k][j] * m[k][i]).sum();
+
+            (dot - if i == j { 1. } else { 0. }).abs() < TOLERANCE
+        })
+    })
+}
+
+#[test]
+fn rank() -> SlalErr<(), i32> {
+    let full = Matrix::<i32>::new(&[&[1, 2], &[3, 4]]).unwrap();
+    let deficient = Matrix::<i32>::new(&[&[1, 2, 3], &[2, 4, 6], &[3, 6, 9]]).unwrap();
+    let zero = Matrix::<i32>::new(&[&[0, 0], &[0, 0], &[0, 0]]).unwrap();
+
+    assert!(full.rank(TOLERANCE)? == 2);
+    assert!(deficient.rank(TOLERANCE)? == 1);
+    assert!(zero.rank(TOLERANCE)? == 0);
+
+    Ok(())
+}
+
+#[test]
+fn rank_tolerance() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[1., 0.], &[0., 1e-8]]).unwrap();
+
+    assert!(m.rank(1e-10)? == 2);
+    assert!(m.rank(1e-6)? == 1);
+
+    Ok(())
+}
+
+#[test]
+fn rank_empty() {
+    match Matrix::<f64>::empty().rank(TOLERANCE) {
+        Err(SlalError::EmptyMatrix(_)) => (),
+        _ => panic!("Expected EmptyMatrix"),
+    }
+}
+
+#[test]
+fn null_space() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, 2, 3, 4], &[2, 4, 6, 8], &[1, 0, 1, 0]]).unwrap();
+    let null_space = m.null_space(TOLERANCE)?;
+
+    // rank 2, so the null space of 4 columns has dimension 2
+    assert!(null_space.size() == (2, 4));
+    assert!(is_orthonormal(&null_space));
+    match Matrix::<f64>::from(m).dot(&null_space) {
+        Ok(product) => assert!(product.m.iter().all(|x| x.abs() < TOLERANCE)),
+        Err(err) => panic!("{}", err),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn null_space_tall() -> SlalErr<(), f64> {
+    let m = Matrix::<f64>::new(&[&[1., 1.], &[2., 2.], &[3., 3.]]).unwrap();
+    let null_space = m.null_space(TOLERANCE)?;
+
+    assert!(null_space.size() == (1, 2));
+    assert!((null_space[0][0] + null_space[1][0]).abs() < TOLERANCE);
+    assert!(is_orthonormal(&null_space));
+
+    Ok(())
+}
+
+#[test]
+fn null_space_trivial() -> SlalErr<(), u8> {
+    let m = Matrix::<u8>::new(&[&[2, 1], &[1, 2]]).unwrap();
+
+    assert!(m.null_space(TOLERANCE)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn column_space() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, 2, 3], &[2, 4, 6], &[1, 0, 1]]).unwrap();
+    let column_space = m.column_space(TOLERANCE)?;
+
+    assert!(column_space.size() == (2, 3));
+    assert!(is_orthonormal(&column_space));
+
+    // Every column of the matrix is in the column space
+    let m = Matrix::<f64>::from(m);
+    (0..3).for_each(|i| {
+        let coefficients: Vec<f64> = (0..2)
+            .map(|k| (0..3).map(|j| column_space[j][k] * m[j][i]).sum())
+            .collect();
+
+        (0..3).for_each(|j| {
+            let projected: f64 = (0..2).map(|k| column_space[j][k] * coefficients[k]).sum();
+
+            assert!((projected - m[j][i]).abs() < TOLERANCE);
+        });
+    });
+
+    Ok(())
+}
+
+#[test]
+fn column_space_zero() -> SlalErr<(), f32> {
+    let m = Matrix::<f32>::new(&[&[0., 0.], &[0., 0.]]).unwrap();
+
+    assert!(m.column_space(TOLERANCE)?.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn rref() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[2, 4, -2, 2], &[4, 9, -3, 8], &[-2, -3, 7, 10]]).unwrap();
+    let (rref, pivots) = m.rref()?;
+
+    assert!(
+        rref == exact(&[
+            &[(1, 1), (0, 1), (0, 1), (-1, 1)],
+            &[(0, 1), (1, 1), (0, 1), (2, 1)],
+            &[(0, 1), (0, 1), (1, 1), (2, 1)],
+        ])
+    );
+    assert!(pivots == vec![0, 1, 2]);
+
+    Ok(())
+}
+
+#[test]
+fn rref_fraction() -> SlalErr<(), i64> {
+    let m = Matrix::<i64>::new(&[&[3, 1], &[0, 0], &[6, 2]]).unwrap();
+    let (rref, pivots) = m.rref()?;
+
+    assert!(rref == exact(&[&[(1, 1), (1, 3)], &[(0, 1), (0, 1)], &[(0, 1), (0, 1)]]));
+    assert!(rref[0][1] == Rational::new(1, 3));
+    assert!(pivots == vec![0]);
+
+    Ok(())
+}
+
+#[test]
+fn rref_rank_deficient() -> SlalErr<(), u32> {
+    let m = Matrix::<u32>::new(&[&[0, 2, 4, 2], &[0, 1, 2, 3], &[0, 0, 0, 0]]).unwrap();
+    let (rref, pivots) = m.rref()?;
+
+    assert!(
+        rref == exact(&[
+            &[(0, 1), (1, 1), (2, 1), (0, 1)],
+            &[(0, 1), (0, 1), (0, 1), (1, 1)],
+            &[(0, 1), (0, 1), (0, 1), (0, 1)],
+        ])
+    );
+    assert!(pivots == vec![1, 3]);
+
+    Ok(())
+}
+
+#[test]
+fn rref_overflow() {
+    let m = Matrix::<i64>::new(&[
+        &[i64::MAX, 3, 5],
+        &[7, i64::MAX - 1, 11],
+        &[13, 17, i64::MAX - 2],
+    ])
+    .unwrap();
+
+    match m.rref() {
+        Err(SlalError::ArithmeticOverflow(_)) => (),
+        _ => panic!("Expected ArithmeticOverflow"),
+    }
+}
+
+#[test]
+fn rref_exact_fractions() -> SlalErr<(), i32> {
+    // Last column becomes [[2, 1], [1, 3]]^-1 [1, 2] = [1/5, 3/5]
+    let m = Matrix::<i32>::new(&[&[2, 1, 1], &[1, 3, 2]]).unwrap();
+    let (rref, pivots) = m.rref()?;
+
+    assert!(rref == exact(&[&[(1, 1), (0, 1), (1, 5)], &[(0, 1), (1, 1), (3, 5)]]));
+    assert!(pivots == vec![0, 1]);
+
+    let m = Matrix::<i32>::new(&[&[3, 0, 1], &[0, 7, 2]]).unwrap();
+    let (rref, _) = m.rref()?;
+
+    assert!(rref == exact(&[&[(1, 1), (0, 1), (1, 3)], &[(0, 1), (1, 1), (2, 7)]]));
+
+    Ok(())
+}
+
+#[test]
+fn rref_rational() {
+    let m = exact(&[&[(1, 2), (1, 3), (1, 1)], &[(1, 4), (1, 5), (2, 1)]]);
+    let (rref, pivots) = m.rref().unwrap();
+
+    // [[1/2, 1/3], [1/4, 1/5]]^-1 [1, 2] = [-28, 45]
+    assert!(rref == exact(&[&[(1, 1), (0, 1), (-28, 1)], &[(0, 1), (1, 1), (45, 1)]]));
+    assert!(pivots == vec![0, 1]);
+}
+
+#[test]
+fn rref_rational_overflow() {
+    let m = Matrix::new(&[
+        &[Rational::new(1, i64::MAX), Rational::new(1, 1)],
+        &[Rational::new(1, i64::MAX - 1), Rational::new(2, 1)],
+    ])
+    .unwrap();
+
+    match m.rref() {
+        Err(SlalError::ArithmeticOverflow(_)) => (),
+        _ => panic!("Expected ArithmeticOverflow"),
+    }
+}
+
+#[test]
+fn rref_empty() {
+    match Matrix::<i32>::empty().rref() {
+        Err(SlalError::EmptyMatrix(_)) => (),
+        _ => panic!("Expected EmptyMatrix"),
+    }
+}
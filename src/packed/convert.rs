@@ -0,0 +1,47 @@
+impl<T> From<super::Symmetric<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Symmetric<T>) -> Self {
+        let n = m.n;
+        let mut dense = vec![T::zero(); n * n];
+
+        (0..n).for_each(|j| {
+            (0..=j).for_each(|i| {
+                dense[j * n + i] = m.m[super::lower_index(j, i)];
+                dense[i * n + j] = m.m[super::lower_index(j, i)];
+            })
+        });
+
+        Self::from_vec(dense, [n, n])
+    }
+}
+
+impl<T> From<super::UpperTriangular<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::UpperTriangular<T>) -> Self {
+        let n = m.n;
+        let mut dense = vec![T::zero(); n * n];
+
+        (0..n)
+            .for_each(|j| (j..n).for_each(|i| dense[j * n + i] = m.m[super::upper_index(n, j, i)]));
+
+        Self::from_vec(dense, [n, n])
+    }
+}
+
+impl<T> From<super::LowerTriangular<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::LowerTriangular<T>) -> Self {
+        let n = m.n;
+        let mut dense = vec![T::zero(); n * n];
+
+        (0..n).for_each(|j| (0..=j).for_each(|i| dense[j * n + i] = m.m[super::lower_index(j, i)]));
+
+        Self::from_vec(dense, [n, n])
+    }
+}
@@ -0,0 +1,101 @@
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Symmetric<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of packed matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.n != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of packed matrix and vertex"),
+            ));
+        }
+
+        let rv: Vec<T> = crate::parallel::map_collect(self.n, |j| {
+            (0..self.n)
+                .map(|i| self.m[super::lower_index(j.max(i), j.min(i))] * other[i])
+                .sum()
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::UpperTriangular<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of packed matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.n != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of packed matrix and vertex"),
+            ));
+        }
+
+        let rv: Vec<T> = crate::parallel::map_collect(self.n, |j| {
+            (j..self.n)
+                .map(|i| self.m[super::upper_index(self.n, j, i)] * other[i])
+                .sum()
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::LowerTriangular<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of packed matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.n != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of packed matrix and vertex"),
+            ));
+        }
+
+        let rv: Vec<T> = crate::parallel::map_collect(self.n, |j| {
+            (0..=j)
+                .map(|i| self.m[super::lower_index(j, i)] * other[i])
+                .sum()
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
@@ -0,0 +1,50 @@
+use super::{LowerTriangular, Symmetric, UpperTriangular};
+use crate::error::SlalError;
+use crate::linear::Dot;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+#[test]
+fn dot_vertex_symmetric() {
+    let m = Symmetric::new(&[&[1], &[2, 3], &[4, 5, 6]]).unwrap();
+    let v = Vertex::new_transposed(&[1, -1, 2]);
+    let expected = Matrix::<i32>::from(m.clone()).dot(&v).unwrap();
+
+    assert_eq!(m.dot(&v), Ok(expected));
+}
+
+#[test]
+fn dot_vertex_upper_triangular() {
+    let m = UpperTriangular::new(&[&[1., 2., 3.], &[4., 5.], &[6.]]).unwrap();
+    let v = Vertex::new_transposed(&[1., -1., 2.]);
+
+    assert_eq!(m.dot(&v), Ok(Vertex::new_transposed(&[5., 6., 12.])));
+}
+
+#[test]
+fn dot_vertex_lower_triangular() {
+    let m = LowerTriangular::new(&[&[1u8], &[2, 3], &[4, 5, 6]]).unwrap();
+    let v = Vertex::new_transposed(&[1, 1, 2]);
+
+    assert_eq!(m.dot(&v), Ok(Vertex::new_transposed(&[1, 5, 21])));
+}
+
+#[test]
+fn dot_vertex_not_transposed() {
+    let m = Symmetric::new(&[&[1], &[2, 3]]).unwrap();
+
+    assert!(matches!(
+        m.dot(&Vertex::new(&[1, 2])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn dot_vertex_length_not_match() {
+    let m = UpperTriangular::new(&[&[1, 2], &[3]]).unwrap();
+
+    assert!(matches!(
+        m.dot(&Vertex::new_transposed(&[1, 2, 3])),
+        Err(SlalError::VertexLengthAndMatrixWidthNotMatch(_, _, _))
+    ));
+}
@@ -0,0 +1,191 @@
+impl<T> crate::linear::TriangularMatrix for super::Symmetric<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::matrix::Matrix<f64>, f64>;
+
+    fn is_lower_triangular(&self) -> bool {
+        (0..self.n).all(|j| (0..j).all(|i| self.m[super::lower_index(j, i)] == T::zero()))
+    }
+
+    fn is_upper_triangular(&self) -> bool {
+        self.is_lower_triangular()
+    }
+
+    fn upper_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).upper_triangular()
+    }
+
+    fn lower_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).lower_triangular()
+    }
+}
+
+impl<T> crate::linear::TriangularMatrix for super::UpperTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::matrix::Matrix<f64>, f64>;
+
+    fn is_lower_triangular(&self) -> bool {
+        (0..self.n).all(|j| {
+            ((j + 1)..self.n).all(|i| self.m[super::upper_index(self.n, j, i)] == T::zero())
+        })
+    }
+
+    fn is_upper_triangular(&self) -> bool {
+        true
+    }
+
+    fn upper_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).upper_triangular()
+    }
+
+    fn lower_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).lower_triangular()
+    }
+}
+
+impl<T> crate::linear::TriangularMatrix for super::LowerTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::matrix::Matrix<f64>, f64>;
+
+    fn is_lower_triangular(&self) -> bool {
+        true
+    }
+
+    fn is_upper_triangular(&self) -> bool {
+        (0..self.n).all(|j| (0..j).all(|i| self.m[super::lower_index(j, i)] == T::zero()))
+    }
+
+    fn upper_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).upper_triangular()
+    }
+
+    fn lower_triangular(&self) -> Self::Output {
+        crate::matrix::Matrix::<T>::from(self.clone()).lower_triangular()
+    }
+}
+
+impl<T> crate::linear::Determinant<T> for super::Symmetric<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn det(&self) -> crate::error::SlalErr<f64, T> {
+        use crate::linear::TriangularMatrix;
+
+        if self.is_lower_triangular() {
+            return Ok((0..self.n)
+                .map(|j| self.m[super::lower_index(j, j)].to_f64())
+                .product());
+        }
+
+        crate::matrix::Matrix::<T>::from(self.clone()).det()
+    }
+}
+
+impl<T> crate::linear::Determinant<T> for super::UpperTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn det(&self) -> crate::error::SlalErr<f64, T> {
+        Ok((0..self.n)
+            .map(|j| self.m[super::upper_index(self.n, j, j)].to_f64())
+            .product())
+    }
+}
+
+impl<T> crate::linear::Determinant<T> for super::LowerTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    fn det(&self) -> crate::error::SlalErr<f64, T> {
+        Ok((0..self.n)
+            .map(|j| self.m[super::lower_index(j, j)].to_f64())
+            .product())
+    }
+}
+
+impl<T> crate::linear::Solve<crate::vertex::Vertex<T>> for super::UpperTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != self.n {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving linear system"),
+            ));
+        } else if (0..self.n).any(|j| self.m[super::upper_index(self.n, j, j)] == T::zero()) {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        // Back substitution
+        let mut x = b.to_f64().to_vec();
+
+        for j in (0..self.n).rev() {
+            let s: f64 = ((j + 1)..self.n)
+                .map(|i| self.m[super::upper_index(self.n, j, i)].to_f64() * x[i])
+                .sum();
+
+            x[j] = (x[j] - s) / self.m[super::upper_index(self.n, j, j)].to_f64();
+        }
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Solve<crate::vertex::Vertex<T>> for super::LowerTriangular<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<f64>, T>;
+
+    fn solve(&self, b: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !b.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when solving linear system of matrix {:?} and vertex {:?}",
+                *self,
+                *b,
+            )));
+        } else if b.len() != self.n {
+            return Err(SlalError::VertexLengthAndMatrixHeightNotMatch(
+                format!("{:?}", *b),
+                format!("{:?}", *self),
+                String::from("while solving linear system"),
+            ));
+        } else if (0..self.n).any(|j| self.m[super::lower_index(j, j)] == T::zero()) {
+            return Err(SlalError::SingularMatrix(format!("{:?}", *self)));
+        }
+
+        // Forward substitution
+        let mut x = b.to_f64().to_vec();
+
+        for j in 0..self.n {
+            let s: f64 = (0..j)
+                .map(|i| self.m[super::lower_index(j, i)].to_f64() * x[i])
+                .sum();
+
+            x[j] = (x[j] - s) / self.m[super::lower_index(j, j)].to_f64();
+        }
+
+        Ok(Vertex::new_transposed(x.as_slice()))
+    }
+}
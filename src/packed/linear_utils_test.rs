@@ -0,0 +1,77 @@
+use super::{LowerTriangular, Symmetric, UpperTriangular};
+use crate::error::SlalError;
+use crate::linear::{Determinant, Dot, Solve, TriangularMatrix};
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+#[test]
+fn det_triangular() {
+    let u = UpperTriangular::new(&[&[2, 7, 1], &[-3, 4], &[5]]).unwrap();
+    let l = LowerTriangular::new(&[&[2.5], &[1., -2.], &[8., 3., 4.]]).unwrap();
+
+    assert_eq!(u.det(), Ok(-30.));
+    assert_eq!(l.det(), Ok(-20.));
+}
+
+#[test]
+fn det_symmetric() {
+    let m = Symmetric::new(&[&[4], &[2, 5], &[1, 3, 6], &[0, 1, 2, 7]]).unwrap();
+    let expected = Matrix::<i32>::from(m.clone()).det().unwrap();
+
+    assert!((m.det().unwrap() - expected).abs() < 1e-9);
+    assert_eq!(Symmetric::new(&[&[2], &[0, 3]]).unwrap().det(), Ok(6.));
+}
+
+#[test]
+fn triangular() {
+    let u = UpperTriangular::new(&[&[1, 0], &[2]]).unwrap();
+    let l = LowerTriangular::new(&[&[1], &[3, 2]]).unwrap();
+    let s = Symmetric::new(&[&[1], &[3, 2]]).unwrap();
+
+    assert!(u.is_upper_triangular() && u.is_lower_triangular());
+    assert!(l.is_lower_triangular() && !l.is_upper_triangular());
+    assert!(!s.is_lower_triangular() && !s.is_upper_triangular());
+}
+
+#[test]
+fn solve_upper_triangular() {
+    let u = UpperTriangular::<f64>::new(&[&[2., 1., -1.], &[3., 2.], &[4.]]).unwrap();
+    let b = Vertex::new_transposed(&[3., 13., 8.]);
+    let x = u.solve(&b).unwrap();
+
+    assert_eq!(x, Vertex::new_transposed(&[1., 3., 2.]));
+    assert_eq!(u.dot(&x), Ok(b));
+}
+
+#[test]
+fn solve_lower_triangular() {
+    let l = LowerTriangular::<i32>::new(&[&[2], &[1, 4], &[-1, 3, 5]]).unwrap();
+    let b = Vertex::new_transposed(&[2, 9, 20]);
+
+    assert_eq!(l.solve(&b), Ok(Vertex::new_transposed(&[1., 2., 3.])));
+}
+
+#[test]
+fn solve_singular() {
+    let u = UpperTriangular::<i32>::new(&[&[1, 2], &[0]]).unwrap();
+    let l = LowerTriangular::<f64>::new(&[&[0.], &[1., 1.]]).unwrap();
+
+    assert!(matches!(
+        u.solve(&Vertex::new_transposed(&[1, 1])),
+        Err(SlalError::SingularMatrix(_))
+    ));
+    assert!(matches!(
+        l.solve(&Vertex::new_transposed(&[1., 1.])),
+        Err(SlalError::SingularMatrix(_))
+    ));
+}
+
+#[test]
+fn solve_length_not_match() {
+    let l = LowerTriangular::<f32>::new(&[&[1.], &[1., 1.]]).unwrap();
+
+    assert!(matches!(
+        l.solve(&Vertex::new_transposed(&[1.])),
+        Err(SlalError::VertexLengthAndMatrixHeightNotMatch(_, _, _))
+    ));
+}
@@ -0,0 +1,512 @@
+mod convert;
+mod linear_arithmatic;
+mod linear_utils;
+
+#[cfg(test)]
+mod linear_arithmatic_test;
+#[cfg(test)]
+mod linear_utils_test;
+
+pub use crate::linear::{Determinant, Dot, Solve};
+
+/**
+    Square symmetric matrix in packed storage.
+
+    Holds only the lower triangle of an n x n matrix row by row, so that
+    element (row, column) and (column, row) share the same storage.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Symmetric<T> {
+    m: Vec<T>,
+    n: usize,
+}
+
+/**
+    Square upper triangular matrix in packed storage.
+
+    Holds row j of an n x n matrix from column j to column n - 1,
+    so that elements below the diagonal are always zero.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpperTriangular<T> {
+    m: Vec<T>,
+    n: usize,
+}
+
+/**
+    Square lower triangular matrix in packed storage.
+
+    Holds row j of an n x n matrix from column 0 to column j,
+    so that elements above the diagonal are always zero.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct LowerTriangular<T> {
+    m: Vec<T>,
+    n: usize,
+}
+
+/**
+    Index of element at (row, column) with column <= row in lower packed storage
+*/
+fn lower_index(row: usize, column: usize) -> usize {
+    row * (row + 1) / 2 + column
+}
+
+/**
+    Index of element at (row, column) with row <= column in upper packed storage of size (n, n)
+*/
+fn upper_index(n: usize, row: usize, column: usize) -> usize {
+    row * (2 * n - row + 1) / 2 + column - row
+}
+
+/**
+    Packs rows whose lengths are given by `len(j)` for a matrix of size (n, n)
+*/
+fn pack<T, F>(rows: &[&[T]], len: F) -> crate::error::SlalErr<Vec<T>, T>
+where
+    T: Copy,
+    F: Fn(usize) -> usize,
+{
+    use crate::error::SlalError;
+
+    if rows.is_empty() {
+        return Err(SlalError::EmptyMatrix(String::from(
+            "Cannot create packed matrix of size (0, 0)",
+        )));
+    }
+
+    let mut m = Vec::with_capacity(rows.len() * (rows.len() + 1) / 2);
+
+    for (j, row) in rows.iter().enumerate() {
+        if row.len() != len(j) {
+            return Err(SlalError::MatrixInitializationError(format!(
+                "Row {} of packed matrix must have {} elements but has {} elements",
+                j,
+                len(j),
+                row.len(),
+            )));
+        }
+        m.extend_from_slice(row);
+    }
+
+    Ok(m)
+}
+
+/**
+    Checks that dense matrix `m` is square and not empty
+*/
+fn check_square<T>(m: &crate::matrix::Matrix<T>) -> crate::error::SlalErr<usize, T>
+where
+    T: Copy + std::fmt::Debug + std::ops::Add + std::ops::Sub + std::ops::Mul + std::ops::Div,
+{
+    use crate::error::SlalError;
+
+    let (x, y) = m.size();
+
+    if m.is_empty() {
+        return Err(SlalError::EmptyMatrix(format!("{:?}", *m)));
+    } else if x != y {
+        return Err(SlalError::NotSquareMatrix(
+            format!("{:?}", *m),
+            format!("{}", x),
+            format!("{}", y),
+        ));
+    }
+
+    Ok(x)
+}
+
+impl<T> Symmetric<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new symmetric matrix from the rows of its lower triangle, where row j has j + 1 elements
+
+    # Example
+    ```
+    use slal::packed::Symmetric;
+
+    //  | 1 2 4 |
+    //  | 2 3 5 |
+    //  | 4 5 6 |
+    let m = Symmetric::new(&[&[1], &[2, 3], &[4, 5, 6]]).unwrap();
+
+    assert!(m.size() == (3, 3));
+    assert!(Symmetric::new(&[&[1, 2], &[3, 4]]).is_err());
+    ```
+     */
+    pub fn new(rows: &[&[T]]) -> crate::error::SlalErr<Self, T> {
+        Ok(Self {
+            m: pack(rows, |j| j + 1)?,
+            n: rows.len(),
+        })
+    }
+
+    /**
+    Returns the size of symmetric matrix
+
+    # Example
+    ```
+    use slal::packed::Symmetric;
+
+    let m = Symmetric::new(&[&[1.], &[2., 3.]]).unwrap();
+    let (m_hor, m_vert) = m.size();     // returns (2, 2)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /**
+    Returns the element at (row, column), or `None` if it is outside of the matrix
+
+    # Example
+    ```
+    use slal::packed::Symmetric;
+
+    let m = Symmetric::new(&[&[1], &[2, 3]]).unwrap();
+
+    assert!(m.get(0, 1) == Some(&2) && m.get(1, 0) == Some(&2));
+    assert!(m.get(2, 0) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row >= self.n || column >= self.n {
+            return None;
+        }
+
+        Some(&self.m[lower_index(row.max(column), row.min(column))])
+    }
+}
+
+impl<T> UpperTriangular<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new upper triangular matrix from the rows of its upper triangle, where row j has n - j elements
+
+    # Example
+    ```
+    use slal::packed::UpperTriangular;
+
+    //  | 1 2 3 |
+    //  | 0 4 5 |
+    //  | 0 0 6 |
+    let m = UpperTriangular::new(&[&[1, 2, 3], &[4, 5], &[6]]).unwrap();
+
+    assert!(m.size() == (3, 3));
+    assert!(UpperTriangular::new(&[&[1], &[2, 3]]).is_err());
+    ```
+     */
+    pub fn new(rows: &[&[T]]) -> crate::error::SlalErr<Self, T> {
+        let n = rows.len();
+
+        Ok(Self {
+            m: pack(rows, |j| n - j)?,
+            n,
+        })
+    }
+
+    /**
+    Returns the size of upper triangular matrix
+
+    # Example
+    ```
+    use slal::packed::UpperTriangular;
+
+    let m = UpperTriangular::new(&[&[1., 2.], &[3.]]).unwrap();
+    let (m_hor, m_vert) = m.size();     // returns (2, 2)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /**
+    Returns the element at (row, column), or `None` if it is below the diagonal or outside of the matrix
+
+    # Example
+    ```
+    use slal::packed::UpperTriangular;
+
+    let m = UpperTriangular::new(&[&[1, 2], &[3]]).unwrap();
+
+    assert!(m.get(0, 1) == Some(&2));
+    assert!(m.get(1, 0) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if row > column || column >= self.n {
+            return None;
+        }
+
+        Some(&self.m[upper_index(self.n, row, column)])
+    }
+}
+
+impl<T> LowerTriangular<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new lower triangular matrix from the rows of its lower triangle, where row j has j + 1 elements
+
+    # Example
+    ```
+    use slal::packed::LowerTriangular;
+
+    //  | 1 0 0 |
+    //  | 2 3 0 |
+    //  | 4 5 6 |
+    let m = LowerTriangular::new(&[&[1], &[2, 3], &[4, 5, 6]]).unwrap();
+
+    assert!(m.size() == (3, 3));
+    assert!(LowerTriangular::new(&[&[1, 2], &[3]]).is_err());
+    ```
+     */
+    pub fn new(rows: &[&[T]]) -> crate::error::SlalErr<Self, T> {
+        Ok(Self {
+            m: pack(rows, |j| j + 1)?,
+            n: rows.len(),
+        })
+    }
+
+    /**
+    Returns the size of lower triangular matrix
+
+    # Example
+    ```
+    use slal::packed::LowerTriangular;
+
+    let m = LowerTriangular::new(&[&[1.], &[2., 3.]]).unwrap();
+    let (m_hor, m_vert) = m.size();     // returns (2, 2)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.n, self.n)
+    }
+
+    /**
+    Returns the element at (row, column), or `None` if it is above the diagonal or outside of the matrix
+
+    # Example
+    ```
+    use slal::packed::LowerTriangular;
+
+    let m = LowerTriangular::new(&[&[1], &[2, 3]]).unwrap();
+
+    assert!(m.get(1, 0) == Some(&2));
+    assert!(m.get(0, 1) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        if column > row || row >= self.n {
+            return None;
+        }
+
+        Some(&self.m[lower_index(row, column)])
+    }
+}
+
+impl<T> Symmetric<T>
+where
+    T: Copy
+        + Default
+        + PartialEq
+        + std::fmt::Debug
+        + std::ops::Add
+        + std::ops::Sub
+        + std::ops::Mul
+        + std::ops::Div,
+{
+    /**
+    Packs the lower triangle of a dense matrix after checking that it is symmetric
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::packed::Symmetric;
+
+    let m = Matrix::new(&[&[1, 2], &[2, 3]]).unwrap();
+
+    assert!(Symmetric::from_matrix(&m) == Symmetric::new(&[&[1], &[2, 3]]));
+    assert!(Symmetric::from_matrix(&Matrix::new(&[&[1, 2], &[3, 4]]).unwrap()).is_err());
+    ```
+     */
+    pub fn from_matrix(m: &crate::matrix::Matrix<T>) -> crate::error::SlalErr<Self, T> {
+        use crate::error::SlalError;
+
+        let n = check_square(m)?;
+        let rows = m.to_vec();
+
+        if (0..n).any(|j| (0..j).any(|i| rows[j][i] != rows[i][j])) {
+            return Err(SlalError::NotSymmetricMatrix(format!("{:?}", *m)));
+        }
+
+        Ok(Self {
+            m: (0..n).flat_map(|j| rows[j][..=j].to_vec()).collect(),
+            n,
+        })
+    }
+}
+
+impl<T> UpperTriangular<T>
+where
+    T: Copy
+        + Default
+        + PartialEq
+        + std::fmt::Debug
+        + std::ops::Add
+        + std::ops::Sub
+        + std::ops::Mul
+        + std::ops::Div,
+{
+    /**
+    Packs the upper triangle of a dense matrix after checking that it is upper triangular
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::packed::UpperTriangular;
+
+    let m = Matrix::new(&[&[1, 2], &[0, 3]]).unwrap();
+
+    assert!(UpperTriangular::from_matrix(&m) == UpperTriangular::new(&[&[1, 2], &[3]]));
+    assert!(UpperTriangular::from_matrix(&Matrix::new(&[&[1, 2], &[3, 4]]).unwrap()).is_err());
+    ```
+     */
+    pub fn from_matrix(m: &crate::matrix::Matrix<T>) -> crate::error::SlalErr<Self, T> {
+        use crate::error::SlalError;
+
+        let n = check_square(m)?;
+        let rows = m.to_vec();
+
+        if (0..n).any(|j| (0..j).any(|i| rows[j][i] != T::default())) {
+            return Err(SlalError::MatrixInitializationError(format!(
+                "Matrix {:?} is not upper triangular",
+                *m
+            )));
+        }
+
+        Ok(Self {
+            m: (0..n).flat_map(|j| rows[j][j..].to_vec()).collect(),
+            n,
+        })
+    }
+}
+
+impl<T> LowerTriangular<T>
+where
+    T: Copy
+        + Default
+        + PartialEq
+        + std::fmt::Debug
+        + std::ops::Add
+        + std::ops::Sub
+        + std::ops::Mul
+        + std::ops::Div,
+{
+    /**
+    Packs the lower triangle of a dense matrix after checking that it is lower triangular
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::packed::LowerTriangular;
+
+    let m = Matrix::new(&[&[1, 0], &[2, 3]]).unwrap();
+
+    assert!(LowerTriangular::from_matrix(&m) == LowerTriangular::new(&[&[1], &[2, 3]]));
+    assert!(LowerTriangular::from_matrix(&Matrix::new(&[&[1, 2], &[3, 4]]).unwrap()).is_err());
+    ```
+     */
+    pub fn from_matrix(m: &crate::matrix::Matrix<T>) -> crate::error::SlalErr<Self, T> {
+        use crate::error::SlalError;
+
+        let n = check_square(m)?;
+        let rows = m.to_vec();
+
+        if (0..n).any(|j| ((j + 1)..n).any(|i| rows[j][i] != T::default())) {
+            return Err(SlalError::MatrixInitializationError(format!(
+                "Matrix {:?} is not lower triangular",
+                *m
+            )));
+        }
+
+        Ok(Self {
+            m: (0..n).flat_map(|j| rows[j][..=j].to_vec()).collect(),
+            n,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{LowerTriangular, Symmetric, UpperTriangular};
+    use crate::error::SlalError;
+    use crate::matrix::Matrix;
+
+    #[test]
+    fn symmetric_round_trip() {
+        let dense = Matrix::new(&[&[1, 2, 4], &[2, 3, 5], &[4, 5, 6]]).unwrap();
+        let m = Symmetric::from_matrix(&dense).unwrap();
+
+        assert_eq!(m, Symmetric::new(&[&[1], &[2, 3], &[4, 5, 6]]).unwrap());
+        assert_eq!(m.get(0, 2), Some(&4));
+        assert_eq!(Matrix::from(m), dense);
+    }
+
+    #[test]
+    fn upper_triangular_round_trip() {
+        let dense = Matrix::new(&[&[1., 2., 3.], &[0., 4., 5.], &[0., 0., 6.]]).unwrap();
+        let m = UpperTriangular::from_matrix(&dense).unwrap();
+
+        assert_eq!(
+            m,
+            UpperTriangular::new(&[&[1., 2., 3.], &[4., 5.], &[6.]]).unwrap()
+        );
+        assert_eq!(m.get(1, 2), Some(&5.));
+        assert_eq!(Matrix::from(m), dense);
+    }
+
+    #[test]
+    fn lower_triangular_round_trip() {
+        let dense = Matrix::new(&[&[1, 0, 0], &[2, 3, 0], &[4, 5, 6]]).unwrap();
+        let m = LowerTriangular::from_matrix(&dense).unwrap();
+
+        assert_eq!(
+            m,
+            LowerTriangular::new(&[&[1], &[2, 3], &[4, 5, 6]]).unwrap()
+        );
+        assert_eq!(m.get(2, 1), Some(&5));
+        assert_eq!(Matrix::from(m), dense);
+    }
+
+    #[test]
+    fn from_matrix_not_structured() {
+        let dense = Matrix::new(&[&[1, 2], &[3, 4]]).unwrap();
+
+        assert!(matches!(
+            Symmetric::from_matrix(&dense),
+            Err(SlalError::NotSymmetricMatrix(_))
+        ));
+        assert!(matches!(
+            UpperTriangular::from_matrix(&dense),
+            Err(SlalError::MatrixInitializationError(_))
+        ));
+        assert!(matches!(
+            LowerTriangular::from_matrix(&Matrix::new(&[&[1, 2, 3]]).unwrap()),
+            Err(SlalError::NotSquareMatrix(_, _, _))
+        ));
+    }
+
+    #[test]
+    fn new_empty() {
+        assert!(matches!(
+            Symmetric::<f64>::new(&[]),
+            Err(SlalError::EmptyMatrix(_))
+        ));
+    }
+}
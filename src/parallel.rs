@@ -0,0 +1,274 @@
+/**
+    Number of elements below which operations run serially by default
+*/
+pub const DEFAULT_THRESHOLD: usize = 4096;
+
+static THRESHOLD: std::sync::atomic::AtomicUsize =
+    std::sync::atomic::AtomicUsize::new(DEFAULT_THRESHOLD);
+
+#[cfg(feature = "parallel")]
+static THREAD_POOL: std::sync::RwLock<Option<std::sync::Arc<rayon::ThreadPool>>> =
+    std::sync::RwLock::new(None);
+
+/**
+    Sets the number of elements below which operations run serially.
+    Operations iterating over at least `len` elements run in parallel,
+    so `0` always runs in parallel and `usize::MAX` never does.
+    Without the `parallel` feature every operation runs serially regardless of the threshold.
+
+    # Example
+    ```
+    use slal::parallel;
+
+    parallel::set_threshold(1 << 16);
+
+    assert!(parallel::threshold() == 1 << 16);
+    # parallel::set_threshold(parallel::DEFAULT_THRESHOLD);
+    ```
+*/
+pub fn set_threshold(len: usize) {
+    THRESHOLD.store(len, std::sync::atomic::Ordering::Relaxed);
+}
+
+/**
+    Returns the number of elements below which operations run serially
+
+    # Example
+    ```
+    use slal::parallel;
+
+    assert!(parallel::threshold() == parallel::DEFAULT_THRESHOLD);
+    ```
+*/
+pub fn threshold() -> usize {
+    THRESHOLD.load(std::sync::atomic::Ordering::Relaxed)
+}
+
+/**
+    Sets the thread pool that parallel operations run on, or resets to the global rayon pool with `None`.
+    Operations called from a thread of any rayon pool keep running on that pool,
+    so work inside `pool.install` always stays in `pool`.
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::parallel;
+    use std::sync::Arc;
+
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+
+    parallel::set_thread_pool(Some(Arc::new(pool)));
+
+    let m = Matrix::new(&[&[1, 2], &[3, 4]]).unwrap();
+    assert!(m.clone() + m == Matrix::new(&[&[2, 4], &[6, 8]]).unwrap());
+
+    parallel::set_thread_pool(None);
+    ```
+*/
+#[cfg(feature = "parallel")]
+pub fn set_thread_pool(pool: Option<std::sync::Arc<rayon::ThreadPool>>) {
+    *THREAD_POOL
+        .write()
+        .unwrap_or_else(std::sync::PoisonError::into_inner) = pool;
+}
+
+/**
+    Checks if an operation over `len` elements should run in parallel
+*/
+#[cfg(feature = "parallel")]
+fn is_parallel(len: usize) -> bool {
+    len >= threshold()
+}
+
+/**
+    Runs `op` on the configured thread pool, unless already on a rayon thread
+*/
+#[cfg(feature = "parallel")]
+fn install<R, OP>(op: OP) -> R
+where
+    R: Send,
+    OP: FnOnce() -> R + Send,
+{
+    if rayon::current_thread_index().is_some() {
+        return op();
+    }
+
+    let pool = THREAD_POOL
+        .read()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .clone();
+
+    match pool {
+        Some(pool) => pool.install(op),
+        None => op(),
+    }
+}
+
+/**
+    Collects `f(0), ..., f(len - 1)` into a vector
+*/
+pub(crate) fn map_collect<R, F>(len: usize, f: F) -> Vec<R>
+where
+    R: Send,
+    F: Fn(usize) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(len) {
+        use rayon::prelude::*;
+
+        return install(|| (0..len).into_par_iter().map(f).collect());
+    }
+
+    (0..len).map(f).collect()
+}
+
+/**
+    Sums `f(0), ..., f(len - 1)`
+*/
+pub(crate) fn map_sum<R, F>(len: usize, f: F) -> R
+where
+    R: Send + std::iter::Sum,
+    F: Fn(usize) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(len) {
+        use rayon::prelude::*;
+
+        return install(|| (0..len).into_par_iter().map(f).sum());
+    }
+
+    (0..len).map(f).sum()
+}
+
+/**
+    Reduces `f(0), ..., f(len - 1)` with the associative operation `op`, starting from `identity()`
+*/
+pub(crate) fn map_reduce<R, F, ID, OP>(len: usize, f: F, identity: ID, op: OP) -> R
+where
+    R: Send,
+    F: Fn(usize) -> R + Sync + Send,
+    ID: Fn() -> R + Sync + Send,
+    OP: Fn(R, R) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(len) {
+        use rayon::prelude::*;
+
+        return install(|| (0..len).into_par_iter().map(f).reduce(identity, op));
+    }
+
+    (0..len).map(f).fold(identity(), op)
+}
+
+/**
+    Calls `f(idx, value)` for every element of `slice`
+*/
+pub(crate) fn for_each_mut<T, F>(slice: &mut [T], f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut T) + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(slice.len()) {
+        use rayon::prelude::*;
+
+        return install(|| {
+            slice
+                .par_iter_mut()
+                .enumerate()
+                .for_each(|(idx, val)| f(idx, val))
+        });
+    }
+
+    slice
+        .iter_mut()
+        .enumerate()
+        .for_each(|(idx, val)| f(idx, val));
+}
+
+/**
+    Calls `f(idx, value)` for every element of `slice` until it returns an error
+*/
+pub(crate) fn try_for_each_mut<T, E, F>(slice: &mut [T], f: F) -> Result<(), E>
+where
+    T: Send,
+    E: Send,
+    F: Fn(usize, &mut T) -> Result<(), E> + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(slice.len()) {
+        use rayon::prelude::*;
+
+        return install(|| {
+            slice
+                .par_iter_mut()
+                .enumerate()
+                .try_for_each(|(idx, val)| f(idx, val))
+        });
+    }
+
+    slice
+        .iter_mut()
+        .enumerate()
+        .try_for_each(|(idx, val)| f(idx, val))
+}
+
+/**
+    Calls `f(idx, chunk)` for every chunk of `chunk_size` elements of `slice`
+*/
+pub(crate) fn for_each_chunk_mut<T, F>(slice: &mut [T], chunk_size: usize, f: F)
+where
+    T: Send,
+    F: Fn(usize, &mut [T]) + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if is_parallel(slice.len()) {
+        use rayon::prelude::*;
+
+        return install(|| {
+            slice
+                .par_chunks_mut(chunk_size)
+                .enumerate()
+                .for_each(|(idx, chunk)| f(idx, chunk))
+        });
+    }
+
+    slice
+        .chunks_mut(chunk_size)
+        .enumerate()
+        .for_each(|(idx, chunk)| f(idx, chunk));
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Reads the default threshold only, tests that change the global settings live in tests/parallel.rs
+    #[test]
+    fn serial_and_parallel_match() {
+        for len in [DEFAULT_THRESHOLD / 2, 2 * DEFAULT_THRESHOLD] {
+            let squares = map_collect(len, |i| (i * i) as u64);
+            let mut doubled = vec![0_u64; len];
+            let mut rows = vec![0_u64; len];
+
+            for_each_mut(&mut doubled, |idx, val| *val = 2 * squares[idx]);
+            for_each_chunk_mut(&mut rows, 64, |j, row| {
+                row.iter_mut().for_each(|v| *v = j as u64)
+            });
+
+            assert_eq!(squares[len - 1], ((len - 1) * (len - 1)) as u64);
+            assert_eq!(doubled[123], 2 * 123 * 123);
+            assert_eq!(rows[len - 1], ((len - 1) / 64) as u64);
+            assert_eq!(map_sum(len, |i| i as u64), (len * (len - 1) / 2) as u64);
+            assert_eq!(map_reduce(len, |i| i % 1_000, || 0, usize::max), 999);
+            assert_eq!(
+                try_for_each_mut(&mut doubled, |idx, _| if idx == 1_500 {
+                    Err(idx)
+                } else {
+                    Ok(())
+                }),
+                Err(1_500)
+            );
+        }
+    }
+}
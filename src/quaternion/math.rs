@@ -0,0 +1,151 @@
+impl<T> std::ops::Add for super::Quaternion<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w + other.w,
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl<T> std::ops::Sub for super::Quaternion<T>
+where
+    T: Copy + std::ops::Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w - other.w,
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl<T> std::ops::Mul for super::Quaternion<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+{
+    type Output = Self;
+
+    /**
+    Hamilton product, following i^2 = j^2 = k^2 = ijk = -1
+     */
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            w: self.w * other.w - self.x * other.x - self.y * other.y - self.z * other.z,
+            x: self.w * other.x + self.x * other.w + self.y * other.z - self.z * other.y,
+            y: self.w * other.y - self.x * other.z + self.y * other.w + self.z * other.x,
+            z: self.w * other.z + self.x * other.y - self.y * other.x + self.z * other.w,
+        }
+    }
+}
+
+impl<T> std::ops::Neg for super::Quaternion<T>
+where
+    T: Copy + std::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            w: -self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> std::ops::Mul<T> for super::Quaternion<T>
+where
+    T: Copy + std::ops::Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> Self::Output {
+        Self {
+            w: self.w * other,
+            x: self.x * other,
+            y: self.y * other,
+            z: self.z * other,
+        }
+    }
+}
+
+impl<T> std::ops::Div<T> for super::Quaternion<T>
+where
+    T: Copy + std::ops::Div<Output = T>,
+{
+    type Output = Self;
+
+    fn div(self, other: T) -> Self::Output {
+        Self {
+            w: self.w / other,
+            x: self.x / other,
+            y: self.y / other,
+            z: self.z / other,
+        }
+    }
+}
+
+impl<T> std::ops::AddAssign for super::Quaternion<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T> std::ops::SubAssign for super::Quaternion<T>
+where
+    T: Copy + std::ops::Sub<Output = T>,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> std::ops::MulAssign for super::Quaternion<T>
+where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Sub<Output = T> + std::ops::Mul<Output = T>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> std::iter::Sum for super::Quaternion<T>
+where
+    T: Copy + Default + std::ops::Add<Output = T>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = Self>,
+    {
+        iter.fold(Self::default(), |acc, q| acc + q)
+    }
+}
+
+macro_rules! impl_mul_real {
+    ($($t:ty)*) => ($(
+        impl std::ops::Mul<super::Quaternion<$t>> for $t {
+            type Output = super::Quaternion<$t>;
+
+            fn mul(self, other: super::Quaternion<$t>) -> Self::Output {
+                other * self
+            }
+        }
+    )*)
+}
+
+impl_mul_real! { i8 i16 i32 i64 i128 isize f32 f64 }
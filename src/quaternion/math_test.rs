@@ -0,0 +1,69 @@
+use super::Quaternion;
+use crate::linear::{Magnitude, Normalize};
+
+#[test]
+fn add_sub() {
+    let p = Quaternion::new(1, 2, 3, 4);
+    let q = Quaternion::new(-3, 5, 0, 1);
+
+    assert_eq!(p + q, Quaternion::new(-2, 7, 3, 5));
+    assert_eq!(p - q, Quaternion::new(4, -3, 3, 3));
+    assert_eq!(-p, Quaternion::new(-1, -2, -3, -4));
+}
+
+#[test]
+fn hamilton_product() {
+    let i = Quaternion::new(0, 1, 0, 0);
+    let j = Quaternion::new(0, 0, 1, 0);
+    let k = Quaternion::new(0, 0, 0, 1);
+    let minus_one = Quaternion::new(-1, 0, 0, 0);
+
+    assert_eq!(i * i, minus_one);
+    assert_eq!(j * j, minus_one);
+    assert_eq!(k * k, minus_one);
+    assert_eq!(i * j * k, minus_one);
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+
+    // (1 + 2i + 3j + 4k)(5 + 6i + 7j + 8k) = -60 + 12i + 30j + 24k
+    assert_eq!(
+        Quaternion::new(1, 2, 3, 4) * Quaternion::new(5, 6, 7, 8),
+        Quaternion::new(-60, 12, 30, 24)
+    );
+}
+
+#[test]
+fn scalar_mul_div() {
+    let q = Quaternion::new(2., -4., 6., 8.);
+
+    assert_eq!(q * 0.5, Quaternion::new(1., -2., 3., 4.));
+    assert_eq!(2. * q, Quaternion::new(4., -8., 12., 16.));
+    assert_eq!(q / 2., Quaternion::new(1., -2., 3., 4.));
+}
+
+#[test]
+fn assign_sum() {
+    let mut q = Quaternion::new(1, 0, 0, 0);
+
+    q += Quaternion::new(0, 1, 0, 0);
+    q -= Quaternion::new(0, 0, 1, 0);
+    q *= Quaternion::new(0, 0, 0, 1);
+    assert_eq!(q, Quaternion::new(0, -1, -1, 1));
+
+    let sum: Quaternion<i32> = (1..4).map(|k| Quaternion::new(k, k, -k, 0)).sum();
+    assert_eq!(sum, Quaternion::new(6, 6, -6, 0));
+}
+
+#[test]
+fn conj_norm() {
+    let q = Quaternion::new(1, 2, 3, 4);
+
+    // q q* = |q|^2
+    assert_eq!(q * q.conj(), Quaternion::new(30, 0, 0, 0));
+    assert_eq!(q.magnitude(), 30_f64.sqrt());
+    assert!((Quaternion::new(0, 3, 0, -4).norm().magnitude() - 1.).abs() < 1e-15);
+    assert_eq!(
+        Quaternion::new(0, 3, 0, -4).norm(),
+        Quaternion::new(0., 0.6, 0., -0.8)
+    );
+}
@@ -0,0 +1,243 @@
+mod math;
+mod rotation;
+
+#[cfg(test)]
+mod math_test;
+#[cfg(test)]
+mod rotation_test;
+
+pub use crate::linear::{Magnitude, Normalize};
+
+/**
+    Quaternion `w + x * i + y * j + z * k`.
+
+    Multiplication is the Hamilton product, which is not commutative.
+    Unit quaternions represent rotations in 3 dimensions, where `q` and `-q` are the same rotation.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Quaternion<T> {
+    w: T,
+    x: T,
+    y: T,
+    z: T,
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new quaternion from its real part `w` and its imaginary parts `x`, `y` and `z`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    // 1 + 2i + 3j + 4k
+    let q = Quaternion::new(1, 2, 3, 4);
+    ```
+     */
+    pub fn new(w: T, x: T, y: T, z: T) -> Self {
+        Self { w, x, y, z }
+    }
+
+    /**
+    Returns the real (scalar) part
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).w() == 1);
+    ```
+     */
+    pub fn w(&self) -> T {
+        self.w
+    }
+
+    /**
+    Returns the coefficient of `i`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).x() == 2);
+    ```
+     */
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    /**
+    Returns the coefficient of `j`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).y() == 3);
+    ```
+     */
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    /**
+    Returns the coefficient of `k`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).z() == 4);
+    ```
+     */
+    pub fn z(&self) -> T {
+        self.z
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: crate::scalar::Scalar,
+{
+    /**
+    Creates new quaternion with real part `w` and the imaginary parts from a vertex of length 3
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+    use slal::vertex::Vertex;
+
+    let q = Quaternion::from_parts(1, &Vertex::new(&[2, 3, 4])).unwrap();
+
+    assert!(q == Quaternion::new(1, 2, 3, 4));
+    ```
+     */
+    pub fn from_parts(w: T, v: &crate::vertex::Vertex<T>) -> crate::error::SlalErr<Self, T> {
+        rotation::check_length(v)?;
+
+        Ok(Self {
+            w,
+            x: v[0],
+            y: v[1],
+            z: v[2],
+        })
+    }
+
+    /**
+    Returns the imaginary (vector) part as a vertex of length 3
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+    use slal::vertex::Vertex;
+
+    assert!(Quaternion::new(1, 2, 3, 4).vector() == Vertex::new(&[2, 3, 4]));
+    ```
+     */
+    pub fn vector(&self) -> crate::vertex::Vertex<T> {
+        crate::vertex::Vertex::new(&[self.x, self.y, self.z])
+    }
+
+    /**
+    Returns the squared norm `w^2 + x^2 + y^2 + z^2`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).norm_sqr() == 30);
+    ```
+     */
+    pub fn norm_sqr(&self) -> T {
+        self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    /**
+    Returns the 4 dimensional dot product `w1 w2 + x1 x2 + y1 y2 + z1 z2`
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).dot(&Quaternion::new(1, 0, -1, 1)) == 2);
+    ```
+     */
+    pub fn dot(&self, other: &Self) -> T {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: Copy + std::ops::Neg<Output = T>,
+{
+    /**
+    Returns the conjugate `w - x * i - y * j - z * k`, which is the inverse of a unit quaternion
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::new(1, 2, 3, 4).conj() == Quaternion::new(1, -2, -3, -4));
+    ```
+     */
+    pub fn conj(&self) -> Self {
+        Self {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> Quaternion<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts every part of the quaternion to f64
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+
+    assert!(Quaternion::<i32>::new(1, -2, 3, -4).to_f64() == Quaternion::new(1., -2., 3., -4.));
+    ```
+     */
+    pub fn to_f64(&self) -> Quaternion<f64> {
+        Quaternion {
+            w: self.w.to_f64(),
+            x: self.x.to_f64(),
+            y: self.y.to_f64(),
+            z: self.z.to_f64(),
+        }
+    }
+}
+
+impl<T> crate::linear::Magnitude for Quaternion<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn magnitude(&self) -> Self::Output {
+        self.to_f64().norm_sqr().sqrt()
+    }
+}
+
+impl<T> crate::linear::Normalize for Quaternion<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = Quaternion<f64>;
+
+    fn norm(&self) -> Self::Output {
+        use crate::linear::Magnitude;
+
+        self.to_f64() / self.magnitude()
+    }
+}
@@ -0,0 +1,279 @@
+use super::Quaternion;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+/**
+    Checks that vertex `v` has length 3
+*/
+pub(super) fn check_length<T>(v: &Vertex<T>) -> crate::error::SlalErr<(), T>
+where
+    T: crate::scalar::Scalar,
+{
+    use crate::error::SlalError;
+
+    if v.len() != 3 {
+        return Err(SlalError::VertexStateError(format!(
+            "Vertex {:?} must have length 3 to be used with quaternion",
+            *v
+        )));
+    }
+
+    Ok(())
+}
+
+impl<T> Quaternion<T>
+where
+    T: crate::scalar::Scalar + std::ops::Neg<Output = T>,
+{
+    /**
+    Rotates a vertex of length 3 by computing the vector part of `q v q*`.
+    The result is scaled by the squared norm of the quaternion, so `q` should be a unit quaternion.
+    Orientation of the vertex is kept.
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+    use slal::vertex::Vertex;
+
+    // Half turn about the Z axis
+    let q = Quaternion::new(0, 0, 0, 1);
+
+    assert!(q.rotate(&Vertex::new(&[1, 2, 3])).unwrap() == Vertex::new(&[-1, -2, 3]));
+    ```
+     */
+    pub fn rotate(&self, v: &Vertex<T>) -> crate::error::SlalErr<Vertex<T>, T> {
+        check_length(v)?;
+
+        let p = *self * Self::new(T::zero(), v[0], v[1], v[2]) * self.conj();
+        let rv = [p.x, p.y, p.z];
+
+        if v.is_transposed() {
+            Ok(Vertex::new_transposed(&rv))
+        } else {
+            Ok(Vertex::new(&rv))
+        }
+    }
+}
+
+macro_rules! impl_quaternion_float {
+    ($($t:ty)*) => ($(
+        impl Quaternion<$t> {
+            /**
+            Returns the identity rotation `1 + 0i + 0j + 0k`
+
+            # Example
+            ```
+            use slal::quaternion::Quaternion;
+
+            assert!(Quaternion::<f64>::identity() == Quaternion::new(1., 0., 0., 0.));
+            ```
+             */
+            pub fn identity() -> Self {
+                Self::new(1., 0., 0., 0.)
+            }
+
+            /**
+            Returns the multiplicative inverse `q* / |q|^2`, or `None` for the zero quaternion
+
+            # Example
+            ```
+            use slal::quaternion::Quaternion;
+
+            let q = Quaternion::new(1_f64, 1., 1., 1.);
+
+            assert!(q * q.inverse().unwrap() == Quaternion::<f64>::identity());
+            assert!(Quaternion::<f64>::default().inverse() == None);
+            ```
+             */
+            pub fn inverse(&self) -> Option<Self> {
+                let norm_sqr = self.norm_sqr();
+
+                if norm_sqr == 0. {
+                    return None;
+                }
+
+                Some(self.conj() / norm_sqr)
+            }
+
+            /**
+            Creates unit quaternion that rotates by `angle` radians about `axis`, following the right-hand rule.
+            The axis does not need to be normalized but must be a non-zero vertex of length 3.
+
+            # Example
+            ```
+            use slal::quaternion::Quaternion;
+            use slal::vertex::Vertex;
+
+            let q = Quaternion::<f64>::from_axis_angle(&Vertex::new(&[0., 0., 2.]), std::f64::consts::PI).unwrap();
+
+            assert!(q.z() == 1. && q.w().abs() < 1e-15);
+            ```
+             */
+            pub fn from_axis_angle(axis: &Vertex<$t>, angle: $t) -> crate::error::SlalErr<Self, $t> {
+                use crate::error::SlalError;
+
+                check_length(axis)?;
+
+                let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+                if norm == 0. {
+                    return Err(SlalError::VertexStateError(format!(
+                        "Axis of rotation {:?} must not be a zero vertex",
+                        *axis
+                    )));
+                }
+
+                let (s, c) = (angle / 2.).sin_cos();
+
+                Ok(Self::new(
+                    c,
+                    axis[0] / norm * s,
+                    axis[1] / norm * s,
+                    axis[2] / norm * s,
+                ))
+            }
+
+            /**
+            Returns the unit axis and the angle in [0, π] of the rotation.
+            The identity rotation has no axis, so the X axis is returned with angle 0.
+
+            # Example
+            ```
+            use slal::quaternion::Quaternion;
+            use slal::vertex::Vertex;
+
+            let axis = Vertex::new(&[0., 1., 0.]);
+            let q = Quaternion::<f64>::from_axis_angle(&axis, 0.5).unwrap();
+            let (v, angle) = q.axis_angle();
+
+            assert!((angle - 0.5).abs() < 1e-15 && (v[1] - 1.).abs() < 1e-15);
+            ```
+             */
+            pub fn axis_angle(&self) -> (Vertex<$t>, $t) {
+                // q and -q are the same rotation, pick the one with the smaller angle
+                let q = if self.w < 0. { -*self } else { *self };
+                let norm = (q.x * q.x + q.y * q.y + q.z * q.z).sqrt();
+
+                if norm == 0. {
+                    return (Vertex::new(&[1., 0., 0.]), 0.);
+                }
+
+                (
+                    Vertex::new(&[q.x / norm, q.y / norm, q.z / norm]),
+                    2. * norm.atan2(q.w),
+                )
+            }
+
+            /**
+            Spherical linear interpolation between unit quaternions `self` (`t = 0`) and `other` (`t = 1`).
+            Interpolates along the shorter arc with constant angular velocity,
+            and falls back to normalized linear interpolation when both rotations are nearly equal.
+
+            # Example
+            ```
+            use slal::quaternion::Quaternion;
+            use slal::vertex::Vertex;
+
+            let z = Vertex::new(&[0., 0., 1.]);
+            let p = Quaternion::<f64>::identity();
+            let q = Quaternion::<f64>::from_axis_angle(&z, 1.).unwrap();
+            let (_, angle) = p.slerp(&q, 0.25).axis_angle();
+
+            assert!((angle - 0.25).abs() < 1e-12);
+            ```
+             */
+            pub fn slerp(&self, other: &Self, t: $t) -> Self {
+                let mut dot = self.dot(other);
+                let mut other = *other;
+
+                if dot < 0. {
+                    other = -other;
+                    dot = -dot;
+                }
+
+                let rv = if dot > 0.9995 {
+                    *self + (other - *self) * t
+                } else {
+                    let theta = dot.acos();
+
+                    (*self * ((1. - t) * theta).sin() + other * (t * theta).sin()) / theta.sin()
+                };
+
+                rv / rv.norm_sqr().sqrt()
+            }
+
+            /**
+            Converts the rotation to a 3 x 3 rotation matrix.
+            The quaternion is normalized first, so the zero quaternion results in NaN.
+
+            # Example
+            ```
+            use slal::matrix::Matrix;
+            use slal::quaternion::Quaternion;
+            use slal::vertex::Vertex;
+
+            let z = Vertex::new(&[0., 0., 1.]);
+            let m = Quaternion::<f64>::from_axis_angle(&z, 0.3).unwrap().rotation_matrix();
+            let expected = Matrix::rotation_z(0.3);
+
+            assert!((0..3).all(|j| (0..3).all(|i| (m[j][i] - expected[j][i]).abs() < 1e-15)));
+            ```
+             */
+            pub fn rotation_matrix(&self) -> Matrix<f64> {
+                let (w, x, y, z) = (self.w as f64, self.x as f64, self.y as f64, self.z as f64);
+                let s = 2. / (w * w + x * x + y * y + z * z);
+
+                Matrix::new(&[
+                    &[1. - s * (y * y + z * z), s * (x * y - w * z), s * (x * z + w * y)],
+                    &[s * (x * y + w * z), 1. - s * (x * x + z * z), s * (y * z - w * x)],
+                    &[s * (x * z - w * y), s * (y * z + w * x), 1. - s * (x * x + y * y)],
+                ])
+                .unwrap()
+            }
+
+            /**
+            Creates unit quaternion with non-negative real part from a 3 x 3 rotation matrix.
+            Returns `SlalError::NotRotationMatrix` unless the matrix is orthonormal and has determinant 1.
+
+            # Example
+            ```
+            use slal::matrix::Matrix;
+            use slal::quaternion::Quaternion;
+
+            let q = Quaternion::<f64>::from_rotation_matrix(&Matrix::rotation_x(0.7)).unwrap();
+            let (axis, angle) = q.axis_angle();
+
+            assert!((angle - 0.7).abs() < 1e-12 && (axis[0] - 1.).abs() < 1e-12);
+            ```
+             */
+            pub fn from_rotation_matrix(m: &Matrix<f64>) -> crate::error::SlalErr<Self, f64> {
+                let r = crate::rotation::check_rotation(m)?;
+                let trace = r[0][0] + r[1][1] + r[2][2];
+
+                // Divide by the largest of 4w^2, 4x^2, 4y^2 and 4z^2 for numerical stability
+                let (w, x, y, z) = if trace > 0. {
+                    let s = (trace + 1.).sqrt() * 2.;
+
+                    (s / 4., (r[2][1] - r[1][2]) / s, (r[0][2] - r[2][0]) / s, (r[1][0] - r[0][1]) / s)
+                } else if r[0][0] > r[1][1] && r[0][0] > r[2][2] {
+                    let s = (1. + r[0][0] - r[1][1] - r[2][2]).sqrt() * 2.;
+
+                    ((r[2][1] - r[1][2]) / s, s / 4., (r[0][1] + r[1][0]) / s, (r[0][2] + r[2][0]) / s)
+                } else if r[1][1] > r[2][2] {
+                    let s = (1. + r[1][1] - r[0][0] - r[2][2]).sqrt() * 2.;
+
+                    ((r[0][2] - r[2][0]) / s, (r[0][1] + r[1][0]) / s, s / 4., (r[1][2] + r[2][1]) / s)
+                } else {
+                    let s = (1. + r[2][2] - r[0][0] - r[1][1]).sqrt() * 2.;
+
+                    ((r[1][0] - r[0][1]) / s, (r[0][2] + r[2][0]) / s, (r[1][2] + r[2][1]) / s, s / 4.)
+                };
+
+                let q = Self::new(w as $t, x as $t, y as $t, z as $t);
+
+                Ok(if q.w < 0. { -q } else { q })
+            }
+        }
+    )*)
+}
+
+impl_quaternion_float! { f32 f64 }
@@ -0,0 +1,128 @@
+use super::Quaternion;
+use crate::error::SlalError;
+use crate::linear::Dot;
+use crate::matrix::Matrix;
+use crate::rotation::EulerOrder;
+use crate::vertex::Vertex;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn approx_eq(p: &Quaternion<f64>, q: &Quaternion<f64>) -> bool {
+    (*p - *q).norm_sqr() < 1e-24
+}
+
+#[test]
+fn rotate_vertex() {
+    let q = Quaternion::<f64>::from_axis_angle(&Vertex::new(&[0., 0., 1.]), FRAC_PI_2).unwrap();
+    let v = q.rotate(&Vertex::new_transposed(&[1., 2., 3.])).unwrap();
+
+    assert!(v.is_transposed());
+    assert!((v[0] + 2.).abs() < 1e-15 && (v[1] - 1.).abs() < 1e-15 && v[2] == 3.);
+
+    // Rotating with a quaternion is the same as multiplying with its rotation matrix
+    let q = Quaternion::new(0.3_f64, -0.1, 0.8, 0.5)
+        / Quaternion::new(0.3_f64, -0.1, 0.8, 0.5).norm_sqr().sqrt();
+    let v = Vertex::new_transposed(&[0.4, -1.2, 2.5]);
+    let expected = q.rotation_matrix().dot(&v).unwrap();
+    let rotated = q.rotate(&v).unwrap();
+
+    (0..3).for_each(|i| assert!((rotated[i] - expected[i]).abs() < 1e-12));
+}
+
+#[test]
+fn rotate_invalid_length() {
+    let q = Quaternion::new(1, 0, 0, 0);
+
+    assert!(matches!(
+        q.rotate(&Vertex::new(&[1, 2])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn axis_angle() {
+    let axis = Vertex::new(&[1., -2., 2.]);
+    let q = Quaternion::<f64>::from_axis_angle(&axis, 2.5).unwrap();
+    let (v, angle) = q.axis_angle();
+
+    assert!((q.norm_sqr() - 1.).abs() < 1e-15);
+    assert!((angle - 2.5).abs() < 1e-12);
+    assert!((v[0] - 1. / 3.).abs() < 1e-12 && (v[1] + 2. / 3.).abs() < 1e-12);
+
+    // Rotating backwards is the same as rotating forwards about the opposite axis
+    let (v, angle) = Quaternion::<f64>::from_axis_angle(&axis, -1.)
+        .unwrap()
+        .axis_angle();
+    assert!((angle - 1.).abs() < 1e-12 && (v[2] + 2. / 3.).abs() < 1e-12);
+
+    assert_eq!(
+        Quaternion::<f64>::identity().axis_angle(),
+        (Vertex::new(&[1., 0., 0.]), 0.)
+    );
+    assert!(matches!(
+        Quaternion::<f32>::from_axis_angle(&Vertex::new(&[0., 0., 0.]), 1.),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn inverse() {
+    let q = Quaternion::new(1_f64, 2., -1., 3.);
+
+    assert!(approx_eq(
+        &(q * q.inverse().unwrap()),
+        &Quaternion::<f64>::identity()
+    ));
+    assert!(approx_eq(
+        &(q.inverse().unwrap() * q),
+        &Quaternion::<f64>::identity()
+    ));
+}
+
+#[test]
+fn rotation_matrix_round_trip() {
+    // Cover every branch of the conversion: large w, x, y and z
+    let matrices = [
+        Matrix::from_rpy(0.1, 0.2, 0.3),
+        Matrix::rotation_x(3.),
+        Matrix::rotation_y(-3.),
+        Matrix::rotation_z(PI),
+        Matrix::from_euler(EulerOrder::Zxz, [2.1, 2.9, -0.4]),
+    ];
+
+    for m in matrices {
+        let q = Quaternion::<f64>::from_rotation_matrix(&m).unwrap();
+        let back = q.rotation_matrix();
+
+        assert!(q.w() >= 0. && (q.norm_sqr() - 1.).abs() < 1e-12);
+        (0..3).for_each(|j| (0..3).for_each(|i| assert!((back[j][i] - m[j][i]).abs() < 1e-12)));
+    }
+
+    assert!(matches!(
+        Quaternion::<f64>::from_rotation_matrix(&(Matrix::rotation_x(1.) * 3.)),
+        Err(SlalError::NotRotationMatrix(_))
+    ));
+}
+
+#[test]
+fn slerp() {
+    let z = Vertex::new(&[0., 0., 1.]);
+    let p = Quaternion::<f64>::from_axis_angle(&z, 0.2).unwrap();
+    let q = Quaternion::<f64>::from_axis_angle(&z, 1.4).unwrap();
+
+    assert!(approx_eq(&p.slerp(&q, 0.), &p));
+    assert!(approx_eq(&p.slerp(&q, 1.), &q));
+    assert!(approx_eq(
+        &p.slerp(&q, 0.5),
+        &Quaternion::<f64>::from_axis_angle(&z, 0.8).unwrap()
+    ));
+
+    // -q is the same rotation as q, interpolation still takes the shorter arc
+    assert!(approx_eq(
+        &p.slerp(&-q, 0.5),
+        &Quaternion::<f64>::from_axis_angle(&z, 0.8).unwrap()
+    ));
+
+    // Nearly equal rotations do not divide by sin(0)
+    let r = Quaternion::<f64>::from_axis_angle(&z, 0.2 + 1e-9).unwrap();
+    assert!((p.slerp(&r, 0.5).norm_sqr() - 1.).abs() < 1e-15);
+}
@@ -0,0 +1,332 @@
+use super::Rational;
+use crate::matrix::Matrix;
+
+/**
+    Reasons why exact elimination cannot produce a result
+*/
+enum Failure {
+    Singular,
+    Overflow,
+}
+
+/**
+    Fraction-free (Bareiss) determinant of an n x n integer matrix in row-major order.
+    Every division is exact, so intermediate values stay integers bounded by minors of the matrix.
+    Returns `None` on overflow.
+*/
+fn bareiss(mut a: Vec<i128>, n: usize) -> Option<i128> {
+    let mut sign = 1;
+    let mut prev = 1;
+
+    for k in 0..n - 1 {
+        if a[k * n + k] == 0 {
+            match ((k + 1)..n).find(|j| a[j * n + k] != 0) {
+                Some(pivot) => {
+                    (0..n).for_each(|i| a.swap(k * n + i, pivot * n + i));
+                    sign = -sign;
+                }
+                None => return Some(0),
+            }
+        }
+
+        for j in (k + 1)..n {
+            for i in (k + 1)..n {
+                // a_ji = (a_ji * a_kk - a_jk * a_ki) / a_(k-1)(k-1)
+                a[j * n + i] = a[j * n + i]
+                    .checked_mul(a[k * n + k])?
+                    .checked_sub(a[j * n + k].checked_mul(a[k * n + i])?)?
+                    .checked_div(prev)?;
+            }
+        }
+        prev = a[k * n + k];
+    }
+
+    a[n * n - 1].checked_mul(sign)
+}
+
+/**
+    Determinant of an n x n rational matrix in row-major order through Gaussian elimination
+*/
+fn gauss<T>(mut a: Vec<Rational<T>>, n: usize) -> Result<Rational<T>, Failure>
+where
+    T: super::Integer,
+{
+    use crate::scalar::Scalar;
+
+    let mut det = Rational::one();
+
+    for k in 0..n {
+        let pivot = match (k..n).find(|j| a[j * n + k] != Rational::zero()) {
+            Some(pivot) => pivot,
+            None => return Ok(Rational::zero()),
+        };
+
+        if pivot != k {
+            (0..n).for_each(|i| a.swap(k * n + i, pivot * n + i));
+            det = Rational::zero().checked_sub(det).ok_or(Failure::Overflow)?;
+        }
+        det = det.checked_mul(a[k * n + k]).ok_or(Failure::Overflow)?;
+
+        for j in (k + 1)..n {
+            let l = a[j * n + k]
+                .checked_div(a[k * n + k])
+                .ok_or(Failure::Overflow)?;
+
+            for i in (k + 1)..n {
+                a[j * n + i] = a[j * n + i]
+                    .checked_sub(l.checked_mul(a[k * n + i]).ok_or(Failure::Overflow)?)
+                    .ok_or(Failure::Overflow)?;
+            }
+        }
+    }
+
+    Ok(det)
+}
+
+/**
+    Inverse of an n x n rational matrix in row-major order through Gauss-Jordan elimination
+*/
+fn gauss_jordan<T>(mut a: Vec<Rational<T>>, n: usize) -> Result<Vec<Rational<T>>, Failure>
+where
+    T: super::Integer,
+{
+    use crate::scalar::Scalar;
+
+    let mut inv: Vec<Rational<T>> = (0..n * n)
+        .map(|k| {
+            if k / n == k % n {
+                Rational::one()
+            } else {
+                Rational::zero()
+            }
+        })
+        .collect();
+
+    for k in 0..n {
+        let pivot = (k..n)
+            .find(|j| a[j * n + k] != Rational::zero())
+            .ok_or(Failure::Singular)?;
+
+        if pivot != k {
+            (0..n).for_each(|i| {
+                a.swap(k * n + i, pivot * n + i);
+                inv.swap(k * n + i, pivot * n + i);
+            });
+        }
+
+        let a_kk = a[k * n + k];
+        for i in 0..n {
+            a[k * n + i] = a[k * n + i].checked_div(a_kk).ok_or(Failure::Overflow)?;
+            inv[k * n + i] = inv[k * n + i].checked_div(a_kk).ok_or(Failure::Overflow)?;
+        }
+
+        for j in (0..n).filter(|j| *j != k) {
+            let l = a[j * n + k];
+
+            if l == Rational::zero() {
+                continue;
+            }
+            for i in 0..n {
+                a[j * n + i] = a[j * n + i]
+                    .checked_sub(l.checked_mul(a[k * n + i]).ok_or(Failure::Overflow)?)
+                    .ok_or(Failure::Overflow)?;
+                inv[j * n + i] = inv[j * n + i]
+                    .checked_sub(l.checked_mul(inv[k * n + i]).ok_or(Failure::Overflow)?)
+                    .ok_or(Failure::Overflow)?;
+            }
+        }
+    }
+
+    Ok(inv)
+}
+
+/**
+    Reduced row echelon form of a rational matrix with size (n, m) in row-major order
+    through Gauss-Jordan elimination, with the indices of the pivot columns.
+    Returns `None` on overflow.
+*/
+fn row_reduce<T>(
+    mut a: Vec<Rational<T>>,
+    n: usize,
+    m: usize,
+) -> Option<(Vec<Rational<T>>, Vec<usize>)>
+where
+    T: super::Integer,
+{
+    use crate::scalar::Scalar;
+
+    let mut pivots: Vec<usize> = Vec::with_capacity(n.min(m));
+
+    for i in 0..n {
+        let row = pivots.len();
+
+        if row == m {
+            break;
+        }
+
+        let pivot = match (row..m).find(|j| a[j * n + i] != Rational::zero()) {
+            Some(pivot) => pivot,
+            None => continue,
+        };
+
+        if pivot != row {
+            (0..n).for_each(|k| a.swap(row * n + k, pivot * n + k));
+        }
+
+        let a_ri = a[row * n + i];
+        for k in 0..n {
+            a[row * n + k] = a[row * n + k].checked_div(a_ri)?;
+        }
+
+        for j in (0..m).filter(|j| *j != row) {
+            let l = a[j * n + i];
+
+            if l == Rational::zero() {
+                continue;
+            }
+            for k in 0..n {
+                a[j * n + k] = a[j * n + k].checked_sub(l.checked_mul(a[row * n + k])?)?;
+            }
+        }
+
+        pivots.push(i);
+    }
+
+    Some((a, pivots))
+}
+
+/**
+    Checks that matrix `m` is square and not empty
+*/
+fn check_square<T>(m: &Matrix<T>) -> crate::error::SlalErr<usize, T>
+where
+    T: crate::scalar::Scalar,
+{
+    use crate::error::SlalError;
+
+    let (x, y) = m.size();
+
+    if m.is_empty() {
+        return Err(SlalError::EmptyMatrix(format!("{:?}", *m)));
+    } else if x != y {
+        return Err(SlalError::NotSquareMatrix(
+            format!("{:?}", *m),
+            format!("{}", x),
+            format!("{}", y),
+        ));
+    }
+
+    Ok(x)
+}
+
+macro_rules! impl_exact_integer {
+    ($($t:ty)*) => ($(
+        impl crate::linear::ExactDeterminant<$t> for Matrix<$t> {
+            type Output = crate::error::SlalErr<i128, $t>;
+
+            fn det_exact(&self) -> Self::Output {
+                use crate::error::SlalError;
+
+                let n = check_square(self)?;
+
+                bareiss(self.as_slice().iter().map(|x| *x as i128).collect(), n).ok_or_else(|| {
+                    SlalError::ArithmeticOverflow(format!(
+                        "while computing determinant of matrix {:?}",
+                        *self
+                    ))
+                })
+            }
+        }
+
+        impl crate::linear::ExactInverse<$t> for Matrix<$t> {
+            type Output = crate::error::SlalErr<Matrix<Rational<i128>>, $t>;
+
+            fn inverse_exact(&self) -> Self::Output {
+                use crate::error::SlalError;
+
+                let n = check_square(self)?;
+
+                match gauss_jordan(
+                    self.as_slice().iter().map(|x| Rational::from(*x as i128)).collect(),
+                    n,
+                ) {
+                    Ok(inv) => Ok(Matrix::from_vec(inv, [n, n])),
+                    Err(Failure::Singular) => Err(SlalError::DeterminantZero(self.clone())),
+                    Err(Failure::Overflow) => Err(SlalError::ArithmeticOverflow(format!(
+                        "while computing inverse of matrix {:?}",
+                        *self
+                    ))),
+                }
+            }
+        }
+    )*)
+}
+
+impl_exact_integer! { i8 u8 i16 u16 i32 u32 i64 u64 i128 isize usize }
+
+impl<T> crate::linear::ExactDeterminant<Rational<T>> for Matrix<Rational<T>>
+where
+    T: super::Integer,
+{
+    type Output = crate::error::SlalErr<Rational<T>, Rational<T>>;
+
+    fn det_exact(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        let n = check_square(self)?;
+
+        gauss(self.as_slice().to_vec(), n).map_err(|_| {
+            SlalError::ArithmeticOverflow(format!(
+                "while computing determinant of matrix {:?}",
+                *self
+            ))
+        })
+    }
+}
+
+impl<T> crate::linear::ExactInverse<Rational<T>> for Matrix<Rational<T>>
+where
+    T: super::Integer,
+{
+    type Output = crate::error::SlalErr<Self, Rational<T>>;
+
+    fn inverse_exact(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        let n = check_square(self)?;
+
+        match gauss_jordan(self.as_slice().to_vec(), n) {
+            Ok(inv) => Ok(Matrix::from_vec(inv, [n, n])),
+            Err(Failure::Singular) => Err(SlalError::DeterminantZero(self.clone())),
+            Err(Failure::Overflow) => Err(SlalError::ArithmeticOverflow(format!(
+                "while computing inverse of matrix {:?}",
+                *self
+            ))),
+        }
+    }
+}
+
+impl<T> crate::linear::ReducedRowEchelon<Rational<T>> for Matrix<Rational<T>>
+where
+    T: super::Integer,
+{
+    type Output = crate::error::SlalErr<(Self, Vec<usize>), Rational<T>>;
+
+    fn rref(&self) -> Self::Output {
+        use crate::error::SlalError;
+
+        if self.is_empty() {
+            return Err(SlalError::EmptyMatrix(format!("{:?}", *self)));
+        }
+
+        let (n, m) = self.size();
+
+        match row_reduce(self.as_slice().to_vec(), n, m) {
+            Some((rref, pivots)) => Ok((Matrix::from_vec(rref, [n, m]), pivots)),
+            None => Err(SlalError::ArithmeticOverflow(format!(
+                "while computing reduced row echelon form of matrix {:?}",
+                *self
+            ))),
+        }
+    }
+}
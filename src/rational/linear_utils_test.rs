@@ -0,0 +1,154 @@
+use super::Rational;
+use crate::error::{SlalErr, SlalError};
+use crate::linear::{Dot, ExactDeterminant, ExactInverse};
+use crate::matrix::Matrix;
+
+/**
+    Builds a rational matrix from rows of (numerator, denominator) pairs
+*/
+fn rational_matrix(rows: &[&[(i64, i64)]]) -> Matrix<Rational<i64>> {
+    let rows: Vec<Vec<Rational<i64>>> = rows
+        .iter()
+        .map(|row| row.iter().map(|(p, q)| Rational::new(*p, *q)).collect())
+        .collect();
+    let rows: Vec<&[Rational<i64>]> = rows.iter().map(|row| row.as_slice()).collect();
+
+    Matrix::new(rows.as_slice()).unwrap()
+}
+
+#[test]
+fn det_exact_integer() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[2, -3, 1], &[2, 0, -1], &[1, 4, 5]])?;
+
+    assert_eq!(m.det_exact()?, 49);
+    assert_eq!(Matrix::<i32>::new(&[&[7]])?.det_exact()?, 7);
+
+    Ok(())
+}
+
+#[test]
+fn det_exact_large_entries() -> SlalErr<(), i64> {
+    // f64 cannot represent the products of these entries, the determinant is exactly 1
+    let m = Matrix::<i64>::new(&[&[3037000499, 3037000498], &[3037000500, 3037000499]])?;
+
+    assert_eq!(m.det_exact()?, 1);
+
+    Ok(())
+}
+
+#[test]
+fn det_exact_pivoting() -> SlalErr<(), u64> {
+    // Zero pivots need row swaps, each of them flips the sign
+    let m = Matrix::<u64>::new(&[&[0, 1, 2], &[0, 3, 4], &[5, 6, 8]])?;
+    let singular = Matrix::<u64>::new(&[&[0, 1, 2], &[0, 3, 4], &[0, 6, 8]])?;
+
+    assert_eq!(m.det_exact()?, -10);
+    assert_eq!(singular.det_exact()?, 0);
+
+    Ok(())
+}
+
+#[test]
+fn det_exact_overflow() {
+    let m = Matrix::<i128>::new(&[&[i128::MAX, 2], &[2, i128::MAX]]).unwrap();
+
+    assert!(matches!(
+        m.det_exact(),
+        Err(SlalError::ArithmeticOverflow(_))
+    ));
+}
+
+#[test]
+fn det_exact_overflow_in_division() {
+    // The last Bareiss step computes i128::MIN / -1, while the determinant 2^127 does not fit either
+    let m = Matrix::<i64>::new(&[
+        &[-1, 0, i64::MIN],
+        &[0, -1, i64::MIN],
+        &[i64::MIN, i64::MIN, 0],
+    ])
+    .unwrap();
+
+    assert!(matches!(
+        m.det_exact(),
+        Err(SlalError::ArithmeticOverflow(_))
+    ));
+}
+
+#[test]
+fn det_exact_not_square() {
+    let m = Matrix::<u8>::new(&[&[1, 2, 3], &[4, 5, 6]]).unwrap();
+
+    assert!(matches!(
+        m.det_exact(),
+        Err(SlalError::NotSquareMatrix(_, _, _))
+    ));
+    assert!(matches!(
+        m.inverse_exact(),
+        Err(SlalError::NotSquareMatrix(_, _, _))
+    ));
+}
+
+#[test]
+fn inverse_exact_integer() -> SlalErr<(), i64> {
+    let m = Matrix::<i64>::new(&[&[2, 0, 1], &[1, 1, 0], &[0, 1, 3]])?;
+    let inv = m.inverse_exact()?;
+    let expected = [[3, 1, -1], [-3, 6, 1], [1, -2, 2]];
+
+    (0..3)
+        .for_each(|j| (0..3).for_each(|i| assert_eq!(inv[j][i], Rational::new(expected[j][i], 7))));
+
+    Ok(())
+}
+
+#[test]
+fn inverse_exact_singular() -> SlalErr<(), i32> {
+    let m = Matrix::<i32>::new(&[&[1, 2], &[2, 4]])?;
+
+    assert_eq!(
+        m.inverse_exact(),
+        Err(SlalError::DeterminantZero(m.clone()))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn det_exact_rational() -> SlalErr<(), Rational<i64>> {
+    // Hilbert matrix of order 3
+    let h = rational_matrix(&[
+        &[(1, 1), (1, 2), (1, 3)],
+        &[(1, 2), (1, 3), (1, 4)],
+        &[(1, 3), (1, 4), (1, 5)],
+    ]);
+
+    assert_eq!(h.det_exact()?, Rational::new(1, 2160));
+
+    Ok(())
+}
+
+#[test]
+fn inverse_exact_rational() -> SlalErr<(), Rational<i64>> {
+    let h = rational_matrix(&[
+        &[(1, 1), (1, 2), (1, 3)],
+        &[(1, 2), (1, 3), (1, 4)],
+        &[(1, 3), (1, 4), (1, 5)],
+    ]);
+    let inv = h.inverse_exact()?;
+    let identity = rational_matrix(&[
+        &[(1, 1), (0, 1), (0, 1)],
+        &[(0, 1), (1, 1), (0, 1)],
+        &[(0, 1), (0, 1), (1, 1)],
+    ]);
+
+    assert_eq!(
+        inv,
+        rational_matrix(&[
+            &[(9, 1), (-36, 1), (30, 1)],
+            &[(-36, 1), (192, 1), (-180, 1)],
+            &[(30, 1), (-180, 1), (180, 1)],
+        ])
+    );
+    assert_eq!(h.dot(&inv)?, identity);
+
+    Ok(())
+}
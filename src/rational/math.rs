@@ -0,0 +1,170 @@
+impl<T> std::ops::Add for super::Rational<T>
+where
+    T: super::Integer,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(other).unwrap_or_else(|| {
+            panic!(
+                "Arithmetic overflow while computing {:?} + {:?}",
+                self, other
+            )
+        })
+    }
+}
+
+impl<T> std::ops::Sub for super::Rational<T>
+where
+    T: super::Integer,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other).unwrap_or_else(|| {
+            panic!(
+                "Arithmetic overflow while computing {:?} - {:?}",
+                self, other
+            )
+        })
+    }
+}
+
+impl<T> std::ops::Mul for super::Rational<T>
+where
+    T: super::Integer,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other).unwrap_or_else(|| {
+            panic!(
+                "Arithmetic overflow while computing {:?} * {:?}",
+                self, other
+            )
+        })
+    }
+}
+
+impl<T> std::ops::Div for super::Rational<T>
+where
+    T: super::Integer,
+{
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        if other.numer == T::zero() {
+            panic!("Attempt to divide {:?} by zero", self);
+        }
+
+        self.checked_div(other).unwrap_or_else(|| {
+            panic!(
+                "Arithmetic overflow while computing {:?} / {:?}",
+                self, other
+            )
+        })
+    }
+}
+
+impl<T> std::ops::Neg for super::Rational<T>
+where
+    T: super::Integer,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self.checked_neg()
+            .unwrap_or_else(|| panic!("Arithmetic overflow while computing -{:?}", self))
+    }
+}
+
+impl<T> std::ops::AddAssign for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T> std::ops::SubAssign for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T> std::ops::MulAssign for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> std::ops::DivAssign for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T> std::iter::Sum for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, q| acc + q)
+    }
+}
+
+impl<T> Ord for super::Rational<T>
+where
+    T: super::Integer,
+{
+    /**
+    Compares the continued fraction expansions of both numbers,
+    which never overflows unlike comparing cross products.
+     */
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let (mut a, mut b) = (self.numer, self.denom);
+        let (mut c, mut d) = (other.numer, other.denom);
+        let mut flipped = false;
+
+        loop {
+            let (q1, r1) = a.div_rem_euclid(b);
+            let (q2, r2) = c.div_rem_euclid(d);
+
+            let ordering = if q1 != q2 {
+                q1.cmp(&q2)
+            } else if r1 == T::zero() || r2 == T::zero() {
+                r1.cmp(&r2)
+            } else {
+                // a / b < c / d <=> b / r1 > d / r2 when the integer parts are equal
+                (a, b, c, d) = (b, r1, d, r2);
+                flipped = !flipped;
+                continue;
+            };
+
+            return if flipped {
+                ordering.reverse()
+            } else {
+                ordering
+            };
+        }
+    }
+}
+
+impl<T> PartialOrd for super::Rational<T>
+where
+    T: super::Integer,
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
@@ -0,0 +1,105 @@
+use super::Rational;
+use crate::scalar::RealScalar;
+
+#[test]
+fn normalize() {
+    let q = Rational::new(-6_i64, -4);
+
+    assert_eq!((q.numer(), q.denom()), (3, 2));
+    assert_eq!(Rational::new(0_i64, -5), Rational::default());
+    assert_eq!(Rational::new(i64::MIN, i64::MIN), Rational::from(1));
+    assert_eq!(Rational::checked_new(i64::MIN, -1), None);
+}
+
+#[test]
+#[should_panic]
+fn zero_denominator() {
+    let _ = Rational::new(1_i128, 0);
+}
+
+#[test]
+fn add_sub() {
+    let p = Rational::new(1_i64, 3);
+    let q = Rational::new(1_i64, 6);
+
+    assert_eq!(p + q, Rational::new(1, 2));
+    assert_eq!(q - p, Rational::new(-1, 6));
+    assert_eq!(-p, Rational::new(-1, 3));
+
+    let mut r = p;
+    r += q;
+    r -= Rational::from(1);
+    assert_eq!(r, Rational::new(-1, 2));
+}
+
+#[test]
+fn mul_div() {
+    let p = Rational::new(2_i128, 3);
+    let q = Rational::new(-9_i128, 4);
+
+    assert_eq!(p * q, Rational::new(-3, 2));
+    assert_eq!(p / q, Rational::new(-8, 27));
+
+    let mut r = p;
+    r *= q;
+    r /= Rational::from(3);
+    assert_eq!(r, Rational::new(-1, 2));
+}
+
+#[test]
+fn sum() {
+    // 1/2 + 1/6 + 1/12 + 1/20 = 4/5
+    let q: Rational<i64> = (1..5).map(|k| Rational::new(1, k * (k + 1))).sum();
+
+    assert_eq!(q, Rational::new(4, 5));
+}
+
+#[test]
+fn checked_overflow() {
+    let max = Rational::from(i64::MAX);
+
+    assert_eq!(max.checked_add(Rational::from(1)), None);
+    assert_eq!(max.checked_mul(Rational::from(2)), None);
+    assert_eq!(Rational::from(i64::MIN).checked_neg(), None);
+    assert_eq!(max.checked_div(Rational::default()), None);
+
+    // Cross-cancellation avoids overflow of intermediate products
+    assert_eq!(
+        max.checked_mul(Rational::new(1, i64::MAX)),
+        Some(Rational::from(1))
+    );
+}
+
+#[test]
+#[should_panic]
+fn add_overflow() {
+    let _ = Rational::from(i128::MAX) + Rational::new(1, 2);
+}
+
+#[test]
+#[should_panic]
+fn div_by_zero() {
+    let _ = Rational::new(1_i64, 2) / Rational::default();
+}
+
+#[test]
+fn ordering() {
+    assert!(Rational::new(1_i64, 3) < Rational::new(1, 2));
+    assert!(Rational::new(-1_i64, 2) < Rational::new(-1, 3));
+    assert!(Rational::new(5_i64, 3) > Rational::new(8, 5));
+    assert!(Rational::new(-7_i64, 3) < Rational::from(-2));
+    assert_eq!(
+        Rational::new(10_i64, 4).cmp(&Rational::new(5, 2)),
+        std::cmp::Ordering::Equal
+    );
+
+    // Cross products of these overflow i64
+    assert!(Rational::new(i64::MAX - 1, i64::MAX) > Rational::new(i64::MAX - 2, i64::MAX - 1));
+    assert!(Rational::new(i64::MIN, i64::MAX) < Rational::from(-1));
+}
+
+#[test]
+fn to_f64() {
+    assert_eq!(Rational::new(-3_i64, 4).to_f64(), -0.75);
+    assert_eq!(Rational::new(1_i128, 3).to_f64(), 1. / 3.);
+}
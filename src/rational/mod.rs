@@ -0,0 +1,356 @@
+mod linear_utils;
+mod math;
+
+#[cfg(test)]
+mod linear_utils_test;
+#[cfg(test)]
+mod math_test;
+
+pub use crate::linear::{ExactDeterminant, ExactInverse};
+
+/**
+    Exact rational number `numer / denom`.
+
+    Rational numbers are always kept in lowest terms with a positive denominator,
+    so two rational numbers are equal exactly when their numerators and denominators are equal.
+    Arithmetic is overflow-checked: the `checked_*` methods return `None` on overflow,
+    while the operators panic like integer arithmetic does in debug builds.
+    `Rational<i64>` and `Rational<i128>` are `RealScalar`s, so vertices and matrices of rational numbers
+    share the generic arithmetic.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational<T> {
+    numer: T,
+    denom: T,
+}
+
+impl<T> Rational<T>
+where
+    T: Copy,
+{
+    /**
+    Returns the numerator in lowest terms
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::new(4_i64, -6).numer() == -2);
+    ```
+     */
+    pub fn numer(&self) -> T {
+        self.numer
+    }
+
+    /**
+    Returns the denominator in lowest terms, which is always positive
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::new(4_i64, -6).denom() == 3);
+    ```
+     */
+    pub fn denom(&self) -> T {
+        self.denom
+    }
+}
+
+impl<T> Rational<T>
+where
+    T: Integer,
+{
+    /**
+    Creates new rational number `numer / denom` in lowest terms.
+    Panics if `denom` is zero or the normalized fraction overflows.
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    // 2 / 4 = 1 / 2
+    let q = Rational::new(2_i64, 4);
+
+    assert!(q == Rational::new(1, 2));
+    ```
+     */
+    pub fn new(numer: T, denom: T) -> Self {
+        if denom == T::zero() {
+            panic!(
+                "Denominator of rational number {} / {} is zero",
+                numer, denom
+            );
+        }
+
+        Self::checked_new(numer, denom).unwrap_or_else(|| {
+            panic!(
+                "Arithmetic overflow while creating rational number {} / {}",
+                numer, denom
+            )
+        })
+    }
+
+    /**
+    Creates new rational number `numer / denom` in lowest terms,
+    or returns `None` if `denom` is zero or the normalized fraction overflows
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::checked_new(3_i64, -9) == Some(Rational::new(-1, 3)));
+    assert!(Rational::checked_new(1_i64, 0) == None);
+    assert!(Rational::checked_new(1, i64::MIN) == None);
+    ```
+     */
+    pub fn checked_new(numer: T, denom: T) -> Option<Self> {
+        if denom == T::zero() {
+            return None;
+        }
+
+        let divisor = T::gcd(numer, denom);
+        let (numer, denom) = (numer / divisor, denom / divisor);
+
+        if denom < T::zero() {
+            Some(Self {
+                numer: numer.checked_neg()?,
+                denom: denom.checked_neg()?,
+            })
+        } else {
+            Some(Self { numer, denom })
+        }
+    }
+
+    /**
+    Returns `true` if the denominator is 1
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::new(6_i64, 3).is_integer());
+    assert!(!Rational::new(6_i64, 4).is_integer());
+    ```
+     */
+    pub fn is_integer(&self) -> bool {
+        self.denom == T::one()
+    }
+
+    /**
+    Returns the reciprocal `denom / numer`, or `None` if the number is zero or the reciprocal overflows
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::new(-2_i64, 3).checked_recip() == Some(Rational::new(-3, 2)));
+    assert!(Rational::new(0_i64, 3).checked_recip() == None);
+    ```
+     */
+    pub fn checked_recip(&self) -> Option<Self> {
+        Self::checked_new(self.denom, self.numer)
+    }
+
+    /**
+    Computes `self + other`, returning `None` on overflow
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    let q = Rational::new(1_i64, 6).checked_add(Rational::new(1, 3));
+
+    assert!(q == Some(Rational::new(1, 2)));
+    assert!(Rational::new(i64::MAX, 1).checked_add(Rational::new(1, 1)) == None);
+    ```
+     */
+    pub fn checked_add(&self, other: Self) -> Option<Self> {
+        // a / b + c / d = (a * (d / g) + c * (b / g)) / (b / g * d) with g = gcd(b, d)
+        let g = T::gcd(self.denom, other.denom);
+        let (b, d) = (self.denom / g, other.denom / g);
+
+        Self::checked_new(
+            self.numer
+                .checked_mul(d)?
+                .checked_add(other.numer.checked_mul(b)?)?,
+            b.checked_mul(other.denom)?,
+        )
+    }
+
+    /**
+    Computes `self - other`, returning `None` on overflow
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    let q = Rational::new(1_i64, 6).checked_sub(Rational::new(1, 3));
+
+    assert!(q == Some(Rational::new(-1, 6)));
+    ```
+     */
+    pub fn checked_sub(&self, other: Self) -> Option<Self> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    /**
+    Computes `self * other`, returning `None` on overflow
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    let q = Rational::new(2_i64, 3).checked_mul(Rational::new(9, 4));
+
+    assert!(q == Some(Rational::new(3, 2)));
+    ```
+     */
+    pub fn checked_mul(&self, other: Self) -> Option<Self> {
+        // Cross-cancel first so that intermediate products stay as small as possible
+        let g1 = T::gcd(self.numer, other.denom);
+        let g2 = T::gcd(other.numer, self.denom);
+
+        Self::checked_new(
+            (self.numer / g1).checked_mul(other.numer / g2)?,
+            (self.denom / g2).checked_mul(other.denom / g1)?,
+        )
+    }
+
+    /**
+    Computes `self / other`, returning `None` if `other` is zero or on overflow
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    let q = Rational::new(2_i64, 3).checked_div(Rational::new(4, 9));
+
+    assert!(q == Some(Rational::new(3, 2)));
+    assert!(Rational::new(2_i64, 3).checked_div(Rational::new(0, 1)) == None);
+    ```
+     */
+    pub fn checked_div(&self, other: Self) -> Option<Self> {
+        self.checked_mul(other.checked_recip()?)
+    }
+
+    /**
+    Computes `-self`, returning `None` on overflow
+
+    # Example
+    ```
+    use slal::rational::Rational;
+
+    assert!(Rational::new(1_i64, 2).checked_neg() == Some(Rational::new(-1, 2)));
+    assert!(Rational::new(i64::MIN, 1).checked_neg() == None);
+    ```
+     */
+    pub fn checked_neg(&self) -> Option<Self> {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom,
+        })
+    }
+}
+
+impl<T> Default for Rational<T>
+where
+    T: Integer,
+{
+    fn default() -> Self {
+        Self {
+            numer: T::zero(),
+            denom: T::one(),
+        }
+    }
+}
+
+impl<T> From<T> for Rational<T>
+where
+    T: Integer,
+{
+    fn from(numer: T) -> Self {
+        Self {
+            numer,
+            denom: T::one(),
+        }
+    }
+}
+
+/**
+    Signed integers which can be numerator and denominator of a rational number
+*/
+pub trait Integer: crate::scalar::RealScalar + Ord + std::hash::Hash + std::fmt::Display {
+    /**
+    Computes `self + other`, returning `None` on overflow
+     */
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /**
+    Computes `self * other`, returning `None` on overflow
+     */
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /**
+    Computes `-self`, returning `None` on overflow
+     */
+    fn checked_neg(self) -> Option<Self>;
+
+    /**
+    Euclidean division, where the remainder is never negative
+     */
+    fn div_rem_euclid(self, other: Self) -> (Self, Self);
+
+    /**
+    Greatest common divisor that is never zero, so that it can always be divided by.
+
+    # Example
+    ```
+    use slal::rational::Integer;
+
+    assert!(i64::gcd(-12, 18) == 6);
+    assert!(i64::gcd(0, 0) == 1);
+    ```
+     */
+    fn gcd(a: Self, b: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty)*) => ($(
+        impl Integer for $t {
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+
+            fn checked_mul(self, other: Self) -> Option<Self> {
+                <$t>::checked_mul(self, other)
+            }
+
+            fn checked_neg(self) -> Option<Self> {
+                <$t>::checked_neg(self)
+            }
+
+            fn div_rem_euclid(self, other: Self) -> (Self, Self) {
+                (self.div_euclid(other), self.rem_euclid(other))
+            }
+
+            fn gcd(a: Self, b: Self) -> Self {
+                // Computed on unsigned magnitudes so that MIN does not overflow
+                let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+
+                match <$t>::try_from(a) {
+                    Ok(0) => 1,
+                    Ok(g) => g,
+                    // Only gcd(MIN, MIN) and gcd(MIN, 0) do not fit, where dividing by MIN is exact
+                    Err(_) => <$t>::MIN,
+                }
+            }
+        }
+    )*)
+}
+
+impl_integer! { i64 i128 }
@@ -0,0 +1,243 @@
+use super::{EulerAngles, EulerOrder};
+use crate::matrix::Matrix;
+
+/**
+    Below this value of the cosine (Tait-Bryan) or sine (proper Euler) of the middle angle,
+    the first and the last rotation axis are treated as aligned
+*/
+const GIMBAL_LOCK_TOL: f64 = 1e-10;
+
+/**
+    Largest deviation of `R^T R` from the identity accepted for a rotation matrix
+*/
+const ORTHONORMAL_TOL: f64 = 1e-6;
+
+/**
+    Rotation by `angle` radians about axis `i`, where 0, 1 and 2 are X, Y and Z
+*/
+fn elementary(i: usize, angle: f64) -> [[f64; 3]; 3] {
+    let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+    let (s, c) = angle.sin_cos();
+    let mut r = [[0.; 3]; 3];
+
+    r[i][i] = 1.;
+    r[j][j] = c;
+    r[j][k] = -s;
+    r[k][j] = s;
+    r[k][k] = c;
+
+    r
+}
+
+fn mul(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let mut r = [[0.; 3]; 3];
+
+    (0..3).for_each(|j| (0..3).for_each(|i| r[j][i] = (0..3).map(|k| a[j][k] * b[k][i]).sum()));
+
+    r
+}
+
+fn to_matrix(r: [[f64; 3]; 3]) -> Matrix<f64> {
+    Matrix::new(&[&r[0], &r[1], &r[2]]).unwrap()
+}
+
+/**
+    Checks that matrix `m` is a 3 x 3 orthonormal matrix with determinant 1
+*/
+pub(crate) fn check_rotation(m: &Matrix<f64>) -> crate::error::SlalErr<[[f64; 3]; 3], f64> {
+    use crate::error::SlalError;
+
+    if m.size() != (3, 3) {
+        return Err(SlalError::NotRotationMatrix(format!("{:?}", *m)));
+    }
+
+    let r = [
+        [m[0][0], m[0][1], m[0][2]],
+        [m[1][0], m[1][1], m[1][2]],
+        [m[2][0], m[2][1], m[2][2]],
+    ];
+    let orthonormal = (0..3).all(|j| {
+        (0..3).all(|i| {
+            let dot: f64 = (0..3).map(|k| r[k][j] * r[k][i]).sum();
+
+            (dot - if i == j { 1. } else { 0. }).abs() <= ORTHONORMAL_TOL
+        })
+    });
+    let det = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+        - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+        + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+
+    if !orthonormal || det <= 0. {
+        return Err(SlalError::NotRotationMatrix(format!("{:?}", *m)));
+    }
+
+    Ok(r)
+}
+
+impl Matrix<f64> {
+    /**
+    Creates rotation matrix of `angle` radians about the X axis
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+
+    let m = Matrix::rotation_x(std::f64::consts::FRAC_PI_2);
+
+    assert!((m[2][1] - 1.).abs() < 1e-15 && (m[1][2] + 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn rotation_x(angle: f64) -> Self {
+        to_matrix(elementary(0, angle))
+    }
+
+    /**
+    Creates rotation matrix of `angle` radians about the Y axis
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+
+    let m = Matrix::rotation_y(std::f64::consts::FRAC_PI_2);
+
+    assert!((m[0][2] - 1.).abs() < 1e-15 && (m[2][0] + 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn rotation_y(angle: f64) -> Self {
+        to_matrix(elementary(1, angle))
+    }
+
+    /**
+    Creates rotation matrix of `angle` radians about the Z axis
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+
+    let m = Matrix::rotation_z(std::f64::consts::FRAC_PI_2);
+
+    assert!((m[1][0] - 1.).abs() < 1e-15 && (m[0][1] + 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn rotation_z(angle: f64) -> Self {
+        to_matrix(elementary(2, angle))
+    }
+
+    /**
+    Creates rotation matrix from three successive intrinsic rotations,
+    where `angles` are in radians and in the order of the rotation axes
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_euler(EulerOrder::Xyz, [0.1, 0.2, 0.3]);
+    let expected = Matrix::rotation_x(0.1) * Matrix::rotation_y(0.2) * Matrix::rotation_z(0.3);
+
+    assert!((0..3).all(|j| (0..3).all(|i| (m[j][i] - expected[j][i]).abs() < 1e-15)));
+    ```
+     */
+    pub fn from_euler(order: EulerOrder, angles: [f64; 3]) -> Self {
+        let [i, j, k] = order.axes();
+
+        to_matrix(mul(
+            &mul(&elementary(i, angles[0]), &elementary(j, angles[1])),
+            &elementary(k, angles[2]),
+        ))
+    }
+
+    /**
+    Creates rotation matrix from roll (about X), pitch (about Y) and yaw (about Z) in radians,
+    which is `Rz(yaw) Ry(pitch) Rx(roll)` as used for vehicles and robots
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_rpy(0.1, 0.2, 0.3);
+
+    assert!(m == Matrix::from_euler(EulerOrder::Zyx, [0.3, 0.2, 0.1]));
+    ```
+     */
+    pub fn from_rpy(roll: f64, pitch: f64, yaw: f64) -> Self {
+        Self::from_euler(EulerOrder::Zyx, [yaw, pitch, roll])
+    }
+
+    /**
+    Extracts the angles of three successive intrinsic rotations in `order` from a rotation matrix.
+    Returns `SlalError::NotRotationMatrix` unless the matrix is 3 x 3, orthonormal and has determinant 1.
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_euler(EulerOrder::Yxz, [0.4, -0.5, 0.6]);
+    let [a, b, c] = m.euler_angles(EulerOrder::Yxz).unwrap().angles();
+
+    assert!((a - 0.4).abs() < 1e-12 && (b + 0.5).abs() < 1e-12 && (c - 0.6).abs() < 1e-12);
+    ```
+     */
+    pub fn euler_angles(&self, order: EulerOrder) -> crate::error::SlalErr<EulerAngles, f64> {
+        let r = check_rotation(self)?;
+        let [i, j, _] = order.axes();
+        // Remaining axis, and the sign of the permutation (i, j, k)
+        let k = 3 - i - j;
+        let s = if (i + 1) % 3 == j { 1. } else { -1. };
+
+        let (b, locked) = if order.is_tait_bryan() {
+            // R = Ri(a) Rj(b) Rk(c), where R[i][k] = s * sin(b)
+            (
+                (s * r[i][k]).clamp(-1., 1.).asin(),
+                r[i][i].hypot(r[i][j]) < GIMBAL_LOCK_TOL,
+            )
+        } else {
+            // R = Ri(a) Rj(b) Ri(c), where R[i][i] = cos(b)
+            (
+                r[i][i].clamp(-1., 1.).acos(),
+                r[i][j].hypot(r[i][k]) < GIMBAL_LOCK_TOL,
+            )
+        };
+
+        let angles = if locked {
+            // Ri(a) Rj(b) maps axis j to cos(a) * e_j + s * sin(a) * e_k
+            [(s * r[k][j]).atan2(r[j][j]), b, 0.]
+        } else if order.is_tait_bryan() {
+            [
+                (-s * r[j][k]).atan2(r[k][k]),
+                b,
+                (-s * r[i][j]).atan2(r[i][i]),
+            ]
+        } else {
+            [r[j][i].atan2(-s * r[k][i]), b, r[i][j].atan2(s * r[i][k])]
+        };
+
+        Ok(EulerAngles {
+            angles,
+            order,
+            gimbal_lock: locked,
+        })
+    }
+
+    /**
+    Extracts roll (about X), pitch (about Y) and yaw (about Z) in radians from a rotation matrix
+    built by `Matrix::from_rpy`. At gimbal lock (pitch of ±π/2) roll is set to 0,
+    use `euler_angles(EulerOrder::Zyx)` to detect it.
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+
+    let (roll, pitch, yaw) = Matrix::from_rpy(0.1, -0.2, 0.3).rpy().unwrap();
+
+    assert!((roll - 0.1).abs() < 1e-12 && (pitch + 0.2).abs() < 1e-12 && (yaw - 0.3).abs() < 1e-12);
+    ```
+     */
+    pub fn rpy(&self) -> crate::error::SlalErr<(f64, f64, f64), f64> {
+        let [yaw, pitch, roll] = self.euler_angles(EulerOrder::Zyx)?.angles();
+
+        Ok((roll, pitch, yaw))
+    }
+}
@@ -0,0 +1,105 @@
+use super::EulerOrder;
+use crate::error::SlalError;
+use crate::matrix::Matrix;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+fn approx_eq(a: &Matrix<f64>, b: &Matrix<f64>) -> bool {
+    a.size() == b.size() && (0..3).all(|j| (0..3).all(|i| (a[j][i] - b[j][i]).abs() < 1e-12))
+}
+
+#[test]
+fn elementary_rotation() {
+    // Rotating a quarter turn about Z maps X onto Y
+    let m = Matrix::rotation_z(FRAC_PI_2);
+    let expected = Matrix::new(&[&[0., -1., 0.], &[1., 0., 0.], &[0., 0., 1.]]).unwrap();
+
+    assert!(approx_eq(&m, &expected));
+    assert!(approx_eq(
+        &Matrix::from_euler(EulerOrder::Zyx, [0.3, 0.2, 0.1]),
+        &(Matrix::rotation_z(0.3) * Matrix::rotation_y(0.2) * Matrix::rotation_x(0.1))
+    ));
+}
+
+#[test]
+fn euler_round_trip() {
+    let angles: [[f64; 3]; 4] = [
+        [0.1, 0.2, 0.3],
+        [-2.5, 1.2, 3.],
+        [3., -0.4, -1.7],
+        [0.7, 0.01, -0.2],
+    ];
+
+    for order in EulerOrder::all() {
+        for mut angle in angles {
+            // The middle angle of proper Euler orders is in [0, π]
+            if !order.is_tait_bryan() {
+                angle[1] = angle[1].abs() + 0.5;
+            }
+
+            let m = Matrix::from_euler(order, angle);
+            let extracted = m.euler_angles(order).unwrap();
+
+            assert!(!extracted.is_gimbal_lock());
+            extracted
+                .angles()
+                .iter()
+                .zip(angle.iter())
+                .for_each(|(a, b)| assert!((a - b).abs() < 1e-10, "{:?} {:?}", order, angle));
+        }
+    }
+}
+
+#[test]
+fn gimbal_lock() {
+    for order in EulerOrder::all() {
+        let middles = if order.is_tait_bryan() {
+            [FRAC_PI_2, -FRAC_PI_2]
+        } else {
+            [0., PI]
+        };
+
+        for b in middles {
+            let m = Matrix::from_euler(order, [0.4, b, -0.9]);
+            let extracted = m.euler_angles(order).unwrap();
+
+            assert!(extracted.is_gimbal_lock(), "{:?} {}", order, b);
+            assert_eq!(extracted.angles()[2], 0.);
+            assert!((extracted.angles()[1] - b).abs() < 1e-10);
+            assert!(approx_eq(
+                &Matrix::from_euler(order, extracted.angles()),
+                &m
+            ));
+        }
+    }
+}
+
+#[test]
+fn rpy() {
+    let (roll, pitch, yaw) = Matrix::from_rpy(-0.3, 0.2, 2.9).rpy().unwrap();
+
+    assert!((roll + 0.3).abs() < 1e-12);
+    assert!((pitch - 0.2).abs() < 1e-12);
+    assert!((yaw - 2.9).abs() < 1e-12);
+
+    // Pitching up by a quarter turn aligns roll with yaw
+    let m = Matrix::from_rpy(0.5, FRAC_PI_2, 0.2);
+    let (roll, pitch, yaw) = m.rpy().unwrap();
+
+    assert_eq!(roll, 0.);
+    assert!((pitch - FRAC_PI_2).abs() < 1e-12);
+    assert!(approx_eq(&Matrix::from_rpy(roll, pitch, yaw), &m));
+}
+
+#[test]
+fn not_rotation_matrix() {
+    let scaled = Matrix::rotation_x(0.3) * 2.;
+    let reflection = Matrix::new(&[&[1., 0., 0.], &[0., 1., 0.], &[0., 0., -1.]]).unwrap();
+    let small = Matrix::new(&[&[1., 0.], &[0., 1.]]).unwrap();
+
+    for m in [scaled, reflection, small] {
+        assert!(matches!(
+            m.euler_angles(EulerOrder::Xyz),
+            Err(SlalError::NotRotationMatrix(_))
+        ));
+    }
+}
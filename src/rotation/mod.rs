@@ -0,0 +1,165 @@
+mod euler;
+
+#[cfg(test)]
+mod euler_test;
+
+pub(crate) use euler::check_rotation;
+
+/**
+    Order of the axes of three successive rotations.
+
+    Tait-Bryan orders (`Xyz`, `Xzy`, `Yxz`, `Yzx`, `Zxy`, `Zyx`) rotate about three different axes,
+    proper Euler orders (`Xyx`, `Xzx`, `Yxy`, `Yzy`, `Zxz`, `Zyz`) rotate about the first axis again.
+    Rotations are intrinsic, so angles `[a, b, c]` in order `Zyx` build `Rz(a) Ry(b) Rx(c)`,
+    which is the same as extrinsic rotations about the axes in reverse order.
+*/
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EulerOrder {
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    Xyx,
+    Xzx,
+    Yxy,
+    Yzy,
+    Zxz,
+    Zyz,
+}
+
+impl EulerOrder {
+    /**
+    Returns every order, Tait-Bryan orders first
+
+    # Example
+    ```
+    use slal::rotation::EulerOrder;
+
+    assert!(EulerOrder::all().len() == 12);
+    ```
+     */
+    pub fn all() -> [Self; 12] {
+        use EulerOrder::*;
+
+        [Xyz, Xzy, Yxz, Yzx, Zxy, Zyx, Xyx, Xzx, Yxy, Yzy, Zxz, Zyz]
+    }
+
+    /**
+    Returns the indices of the rotation axes, where 0, 1 and 2 are X, Y and Z
+
+    # Example
+    ```
+    use slal::rotation::EulerOrder;
+
+    assert!(EulerOrder::Zyx.axes() == [2, 1, 0]);
+    ```
+     */
+    pub fn axes(&self) -> [usize; 3] {
+        use EulerOrder::*;
+
+        match self {
+            Xyz => [0, 1, 2],
+            Xzy => [0, 2, 1],
+            Yxz => [1, 0, 2],
+            Yzx => [1, 2, 0],
+            Zxy => [2, 0, 1],
+            Zyx => [2, 1, 0],
+            Xyx => [0, 1, 0],
+            Xzx => [0, 2, 0],
+            Yxy => [1, 0, 1],
+            Yzy => [1, 2, 1],
+            Zxz => [2, 0, 2],
+            Zyz => [2, 1, 2],
+        }
+    }
+
+    /**
+    Returns `true` if the order rotates about three different axes
+
+    # Example
+    ```
+    use slal::rotation::EulerOrder;
+
+    assert!(EulerOrder::Zyx.is_tait_bryan());
+    assert!(!EulerOrder::Zxz.is_tait_bryan());
+    ```
+     */
+    pub fn is_tait_bryan(&self) -> bool {
+        let [i, _, k] = self.axes();
+
+        i != k
+    }
+}
+
+/**
+    Angles of three successive rotations extracted from a rotation matrix.
+
+    At gimbal lock the first and the last axis line up, so only their sum (or difference) is determined.
+    The last angle is then set to 0 and the first angle holds the whole rotation about the aligned axes.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EulerAngles {
+    angles: [f64; 3],
+    order: EulerOrder,
+    gimbal_lock: bool,
+}
+
+impl EulerAngles {
+    /**
+    Returns the angles in radians in the order of the rotation axes.
+    The middle angle is in [-π/2, π/2] for Tait-Bryan orders and in [0, π] for proper Euler orders,
+    the others are in [-π, π].
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_euler(EulerOrder::Zxz, [0.1, 0.2, 0.3]);
+    let [a, b, c] = m.euler_angles(EulerOrder::Zxz).unwrap().angles();
+
+    assert!((a - 0.1).abs() < 1e-12 && (b - 0.2).abs() < 1e-12 && (c - 0.3).abs() < 1e-12);
+    ```
+     */
+    pub fn angles(&self) -> [f64; 3] {
+        self.angles
+    }
+
+    /**
+    Returns the order of the rotation axes
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_euler(EulerOrder::Yzy, [0.1, 0.2, 0.3]);
+
+    assert!(m.euler_angles(EulerOrder::Yzy).unwrap().order() == EulerOrder::Yzy);
+    ```
+     */
+    pub fn order(&self) -> EulerOrder {
+        self.order
+    }
+
+    /**
+    Returns `true` if the first and the last rotation axis line up,
+    in which case the last angle is set to 0
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::rotation::EulerOrder;
+
+    let m = Matrix::from_rpy(0.3, std::f64::consts::FRAC_PI_2, 0.1);
+    let angles = m.euler_angles(EulerOrder::Zyx).unwrap();
+
+    assert!(angles.is_gimbal_lock() && angles.angles()[2] == 0.);
+    ```
+     */
+    pub fn is_gimbal_lock(&self) -> bool {
+        self.gimbal_lock
+    }
+}
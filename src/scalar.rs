@@ -0,0 +1,247 @@
+/**
+    Numbers that can be stored and computed in vertices and matrices
+*/
+pub trait Scalar:
+    Copy
+    + std::fmt::Debug
+    + PartialEq
+    + Send
+    + Sync
+    + std::ops::Add<Output = Self>
+    + std::ops::Sub<Output = Self>
+    + std::ops::Mul<Output = Self>
+    + std::ops::Div<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::SubAssign
+    + std::ops::MulAssign
+    + std::iter::Sum
+{
+    /**
+    Additive identity
+
+    # Example
+    ```
+    use slal::scalar::Scalar;
+
+    assert!(i64::zero() == 0);
+    ```
+     */
+    fn zero() -> Self;
+
+    /**
+    Multiplicative identity
+
+    # Example
+    ```
+    use slal::scalar::Scalar;
+
+    assert!(f32::one() == 1.);
+    ```
+     */
+    fn one() -> Self;
+
+    /**
+    Divides the complex number `x.0 + x.1 * i` by `y.0 + y.1 * i`,
+    returning the real and imaginary part of the quotient.
+    Multiplies with the conjugate of the denominator by default, so integer division truncates both parts.
+    Floating point numbers use Smith's algorithm instead, which does not overflow or underflow
+    in the squared magnitude of the denominator.
+
+    # Example
+    ```
+    use slal::scalar::Scalar;
+
+    // (-5 + 10i) / (3 + 4i) = 1 + 2i
+    assert!(f64::complex_div((-5., 10.), (3., 4.)) == (1., 2.));
+    // (7 + i) / (1 + i) = 4 - 3i
+    assert!(i64::complex_div((7, 1), (1, 1)) == (4, -3));
+    ```
+     */
+    fn complex_div(x: (Self, Self), y: (Self, Self)) -> (Self, Self) {
+        let denominator = y.0 * y.0 + y.1 * y.1;
+
+        (
+            (x.0 * y.0 + x.1 * y.1) / denominator,
+            (x.1 * y.0 - x.0 * y.1) / denominator,
+        )
+    }
+}
+
+/**
+    Scalars on the real line, which are ordered and can be approximated with f64.
+    Algorithms that compute in floating point (determinant, decompositions, ...) are
+    available for matrices of any real scalar.
+*/
+pub trait RealScalar: Scalar + PartialOrd {
+    /**
+    Converts the scalar to the nearest f64
+
+    # Example
+    ```
+    use slal::scalar::RealScalar;
+
+    assert!(3_i64.to_f64() == 3.);
+    ```
+     */
+    fn to_f64(self) -> f64;
+}
+
+/**
+    Real scalars which are closed under division and square root
+*/
+pub trait RealField: RealScalar + std::ops::Neg<Output = Self> {
+    /**
+    Converts f64 to the nearest value of the field
+
+    # Example
+    ```
+    use slal::scalar::RealField;
+
+    assert!(f32::from_f64(0.5) == 0.5);
+    ```
+     */
+    fn from_f64(v: f64) -> Self;
+
+    /**
+    Absolute value
+     */
+    fn abs(self) -> Self;
+
+    /**
+    Square root
+     */
+    fn sqrt(self) -> Self;
+
+    /**
+    Rounds to the nearest integer, away from zero on ties
+     */
+    fn round(self) -> Self;
+}
+
+macro_rules! impl_scalar {
+    ($($t:ty)*) => ($(
+        impl Scalar for $t {
+            #[inline(always)]
+            fn zero() -> Self {
+                0 as $t
+            }
+
+            #[inline(always)]
+            fn one() -> Self {
+                1 as $t
+            }
+        }
+
+        impl RealScalar for $t {
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*)
+}
+
+impl_scalar! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize }
+
+macro_rules! impl_float_scalar {
+    ($($t:ty)*) => ($(
+        impl Scalar for $t {
+            #[inline(always)]
+            fn zero() -> Self {
+                0.
+            }
+
+            #[inline(always)]
+            fn one() -> Self {
+                1.
+            }
+
+            fn complex_div(x: (Self, Self), y: (Self, Self)) -> (Self, Self) {
+                // Smith's algorithm scales by the larger part of the denominator
+                if y.0.abs() > y.1.abs() {
+                    let r = y.1 / y.0;
+                    let d = y.0 + r * y.1;
+
+                    ((x.0 + r * x.1) / d, (x.1 - r * x.0) / d)
+                } else {
+                    let r = y.0 / y.1;
+                    let d = y.1 + r * y.0;
+
+                    ((r * x.0 + x.1) / d, (r * x.1 - x.0) / d)
+                }
+            }
+        }
+
+        impl RealScalar for $t {
+            #[inline(always)]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    )*)
+}
+
+impl_float_scalar! { f32 f64 }
+
+macro_rules! impl_real_field {
+    ($($t:ty)*) => ($(
+        impl RealField for $t {
+            #[inline(always)]
+            fn from_f64(v: f64) -> Self {
+                v as $t
+            }
+
+            #[inline(always)]
+            fn abs(self) -> Self {
+                <$t>::abs(self)
+            }
+
+            #[inline(always)]
+            fn sqrt(self) -> Self {
+                <$t>::sqrt(self)
+            }
+
+            #[inline(always)]
+            fn round(self) -> Self {
+                <$t>::round(self)
+            }
+        }
+    )*)
+}
+
+impl_real_field! { f32 f64 }
+
+impl<T> Scalar for crate::complex::Complex<T>
+where
+    T: Scalar + Default,
+{
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Scalar for crate::rational::Rational<T>
+where
+    T: crate::rational::Integer,
+{
+    fn zero() -> Self {
+        Self::from(T::zero())
+    }
+
+    fn one() -> Self {
+        Self::from(T::one())
+    }
+}
+
+impl<T> RealScalar for crate::rational::Rational<T>
+where
+    T: crate::rational::Integer,
+{
+    fn to_f64(self) -> f64 {
+        self.numer().to_f64() / self.denom().to_f64()
+    }
+}
@@ -0,0 +1,131 @@
+impl<T> From<super::Coo<T>> for super::Csr<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn from(m: super::Coo<T>) -> Self {
+        let (offsets, indices, values) = super::compress(m.size[1], &m.rows, &m.columns, &m.values);
+
+        Self {
+            offsets,
+            indices,
+            values,
+            size: m.size,
+        }
+    }
+}
+
+impl<T> From<super::Coo<T>> for super::Csc<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn from(m: super::Coo<T>) -> Self {
+        let (offsets, indices, values) = super::compress(m.size[0], &m.columns, &m.rows, &m.values);
+
+        Self {
+            offsets,
+            indices,
+            values,
+            size: m.size,
+        }
+    }
+}
+
+impl<T> From<super::Csr<T>> for super::Csc<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn from(m: super::Csr<T>) -> Self {
+        let rows = super::expand(&m.offsets);
+        let (offsets, indices, values) = super::compress(m.size[0], &m.indices, &rows, &m.values);
+
+        Self {
+            offsets,
+            indices,
+            values,
+            size: m.size,
+        }
+    }
+}
+
+impl<T> From<super::Csc<T>> for super::Csr<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    fn from(m: super::Csc<T>) -> Self {
+        let columns = super::expand(&m.offsets);
+        let (offsets, indices, values) =
+            super::compress(m.size[1], &m.indices, &columns, &m.values);
+
+        Self {
+            offsets,
+            indices,
+            values,
+            size: m.size,
+        }
+    }
+}
+
+impl<T> From<crate::matrix::Matrix<T>> for super::Csr<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: crate::matrix::Matrix<T>) -> Self {
+        let (x, y) = m.size();
+        let mut offsets = Vec::with_capacity(y + 1);
+        let mut indices = vec![];
+        let mut values = vec![];
+
+        offsets.push(0);
+        (0..y).for_each(|j| {
+            m[j].iter()
+                .enumerate()
+                .filter(|(_, m_ji)| **m_ji != T::zero())
+                .for_each(|(i, m_ji)| {
+                    indices.push(i);
+                    values.push(*m_ji);
+                });
+            offsets.push(indices.len());
+        });
+
+        Self {
+            offsets,
+            indices,
+            values,
+            size: [x, y],
+        }
+    }
+}
+
+impl<T> From<crate::matrix::Matrix<T>> for super::Csc<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: crate::matrix::Matrix<T>) -> Self {
+        Self::from(super::Csr::from(m))
+    }
+}
+
+impl<T> From<super::Csr<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Csr<T>) -> Self {
+        let [x, y] = m.size;
+        let mut dense = vec![T::zero(); x * y];
+
+        m.offsets.windows(2).enumerate().for_each(|(j, range)| {
+            (range[0]..range[1]).for_each(|k| dense[j * x + m.indices[k]] = m.values[k])
+        });
+
+        Self::from_vec(dense, m.size)
+    }
+}
+
+impl<T> From<super::Csc<T>> for crate::matrix::Matrix<T>
+where
+    T: crate::scalar::Scalar,
+{
+    fn from(m: super::Csc<T>) -> Self {
+        Self::from(super::Csr::from(m))
+    }
+}
@@ -0,0 +1,169 @@
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Csr<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of sparse matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.size[0] != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of sparse matrix and vertex"),
+            ));
+        }
+
+        let rv: Vec<T> = crate::parallel::map_collect(self.offsets.len() - 1, |j| {
+            (self.offsets[j]..self.offsets[j + 1])
+                .map(|k| self.values[k] * other[self.indices[k]])
+                .sum()
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::matrix::Matrix<T>> for super::Csr<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::matrix::Matrix<T>, T>;
+
+    fn dot(&self, other: &crate::matrix::Matrix<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::matrix::Matrix;
+
+        let (x, y) = other.size();
+        if self.size[0] != y {
+            return Err(SlalError::UnmatchingMatrixSize(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+            ));
+        }
+
+        let mut rv: Vec<T> = vec![T::zero(); x * self.size[1]];
+        if x > 0 {
+            crate::parallel::for_each_chunk_mut(&mut rv, x, |j, row| {
+                (self.offsets[j]..self.offsets[j + 1]).for_each(|k| {
+                    row.iter_mut()
+                        .zip(other[self.indices[k]].iter())
+                        .for_each(|(val, other_ki)| *val += self.values[k] * *other_ki)
+                })
+            });
+        }
+
+        Ok(Matrix::from_vec(rv, [x, self.size[1]]))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::vertex::Vertex<T>> for super::Csc<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::vertex::Vertex<T>, T>;
+
+    fn dot(&self, other: &crate::vertex::Vertex<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::vertex::Vertex;
+
+        if !other.is_transposed() {
+            return Err(SlalError::VertexStateError(format!(
+                "Vertex must be transposed when computing product of sparse matrix {:?} and vertex {:?}",
+                *self,
+                *other,
+            )));
+        } else if self.size[0] != other.len() {
+            return Err(SlalError::VertexLengthAndMatrixWidthNotMatch(
+                format!("{:?}", *other),
+                format!("{:?}", *self),
+                String::from("while computing product of sparse matrix and vertex"),
+            ));
+        }
+
+        // Scatter every column scaled by the matching element of the vertex
+        let mut rv: Vec<T> = vec![T::zero(); self.size[1]];
+        self.offsets.windows(2).enumerate().for_each(|(i, range)| {
+            (range[0]..range[1]).for_each(|k| rv[self.indices[k]] += self.values[k] * other[i])
+        });
+
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    }
+}
+
+impl<T> crate::linear::Dot<crate::matrix::Matrix<T>> for super::Csc<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = crate::error::SlalErr<crate::matrix::Matrix<T>, T>;
+
+    fn dot(&self, other: &crate::matrix::Matrix<T>) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::matrix::Matrix;
+
+        let (x, y) = other.size();
+        if self.size[0] != y {
+            return Err(SlalError::UnmatchingMatrixSize(
+                format!("{:?}", *self),
+                format!("{:?}", *other),
+            ));
+        }
+
+        let mut rv: Vec<T> = vec![T::zero(); x * self.size[1]];
+        self.offsets.windows(2).enumerate().for_each(|(i, range)| {
+            (range[0]..range[1]).for_each(|k| {
+                let j = self.indices[k];
+
+                rv[j * x..(j + 1) * x]
+                    .iter_mut()
+                    .zip(other[i].iter())
+                    .for_each(|(val, other_ij)| *val += self.values[k] * *other_ij)
+            })
+        });
+
+        Ok(Matrix::from_vec(rv, [x, self.size[1]]))
+    }
+}
+
+impl crate::linear::LinearOperator for super::Csr<f64> {
+    fn rows(&self) -> usize {
+        self.size[1]
+    }
+
+    fn columns(&self) -> usize {
+        self.size[0]
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        crate::parallel::for_each_mut(y, |j, y_j| {
+            *y_j = (self.offsets[j]..self.offsets[j + 1])
+                .map(|k| self.values[k] * x[self.indices[k]])
+                .sum();
+        });
+    }
+}
+
+impl crate::linear::LinearOperator for super::Csc<f64> {
+    fn rows(&self) -> usize {
+        self.size[1]
+    }
+
+    fn columns(&self) -> usize {
+        self.size[0]
+    }
+
+    fn apply(&self, x: &[f64], y: &mut [f64]) {
+        y.iter_mut().for_each(|y_j| *y_j = 0.);
+        self.offsets.windows(2).enumerate().for_each(|(i, range)| {
+            (range[0]..range[1]).for_each(|k| y[self.indices[k]] += self.values[k] * x[i])
+        });
+    }
+}
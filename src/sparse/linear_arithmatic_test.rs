@@ -0,0 +1,87 @@
+use super::{Coo, Csc, Csr};
+use crate::error::SlalError;
+use crate::linear::Dot;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+fn dense() -> Matrix<i32> {
+    Matrix::new(&[&[0, 2, 0, 0], &[1, 0, 0, 3], &[0, 0, -1, 0]]).unwrap()
+}
+
+#[test]
+fn dot_vertex_csr() {
+    let m = Csr::from(dense());
+    let v = Vertex::new_transposed(&[1, 2, 3, 4]);
+
+    assert_eq!(m.dot(&v), Ok(Vertex::new_transposed(&[4, 13, -3])));
+}
+
+#[test]
+fn dot_vertex_csc() {
+    let m = Csc::from(dense());
+    let v = Vertex::new_transposed(&[1, 2, 3, 4]);
+
+    assert_eq!(m.dot(&v), Ok(Vertex::new_transposed(&[4, 13, -3])));
+}
+
+#[test]
+fn dot_vertex_not_transposed() {
+    let m = Csr::from(dense());
+    let v = Vertex::new(&[1, 2, 3, 4]);
+
+    assert!(matches!(m.dot(&v), Err(SlalError::VertexStateError(_))));
+}
+
+#[test]
+fn dot_vertex_length_not_match() {
+    let m = Csc::from(dense());
+    let v = Vertex::new_transposed(&[1, 2, 3]);
+
+    assert!(matches!(
+        m.dot(&v),
+        Err(SlalError::VertexLengthAndMatrixWidthNotMatch(_, _, _))
+    ));
+}
+
+#[test]
+fn dot_matrix() {
+    let other = Matrix::new(&[&[1, -1], &[2, 0], &[0, 5], &[3, 1]]).unwrap();
+    let expected = dense().dot(&other).unwrap();
+
+    assert_eq!(Csr::from(dense()).dot(&other), Ok(expected.clone()));
+    assert_eq!(Csc::from(dense()).dot(&other), Ok(expected));
+}
+
+#[test]
+fn dot_matrix_size_not_match() {
+    let other = Matrix::new(&[&[1, -1], &[2, 0], &[0, 5]]).unwrap();
+
+    assert!(matches!(
+        Csr::from(dense()).dot(&other),
+        Err(SlalError::UnmatchingMatrixSize(_, _))
+    ));
+}
+
+#[test]
+fn cg_with_csr() {
+    use crate::iterative::{cg, Config};
+
+    // 1D Poisson matrix
+    let n = 20;
+    let mut coo = Coo::new((n, n));
+    (0..n).for_each(|i| {
+        coo.push(i, i, 2.).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.).unwrap();
+            coo.push(i - 1, i, -1.).unwrap();
+        }
+    });
+    let a = Csr::from(coo);
+    let b = Vertex::new_transposed(&vec![1.; n]);
+
+    let report = cg(&a, &b, &Config::default(), None).unwrap();
+    let r = a.dot(report.x()).unwrap();
+
+    assert!(report.converged());
+    assert!((0..n).all(|i| (r[i] - 1.).abs() < 1e-8));
+}
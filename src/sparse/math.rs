@@ -0,0 +1,105 @@
+/**
+    Merges two compressed matrices of the same size slice by slice, summing values at the same position
+*/
+fn merge<T>(
+    a: (&[usize], &[usize], &[T]),
+    b: (&[usize], &[usize], &[T]),
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    let ((a_offsets, a_indices, a_values), (b_offsets, b_indices, b_values)) = (a, b);
+    let mut offsets = Vec::with_capacity(a_offsets.len());
+    let mut indices = Vec::with_capacity(a_indices.len() + b_indices.len());
+    let mut values = Vec::with_capacity(a_values.len() + b_values.len());
+
+    offsets.push(0);
+    for j in 0..(a_offsets.len() - 1) {
+        let (mut k, mut l) = (a_offsets[j], b_offsets[j]);
+
+        while k < a_offsets[j + 1] || l < b_offsets[j + 1] {
+            let a_index = if k < a_offsets[j + 1] {
+                a_indices[k]
+            } else {
+                usize::MAX
+            };
+            let b_index = if l < b_offsets[j + 1] {
+                b_indices[l]
+            } else {
+                usize::MAX
+            };
+
+            match a_index.cmp(&b_index) {
+                std::cmp::Ordering::Less => {
+                    indices.push(a_index);
+                    values.push(a_values[k]);
+                    k += 1;
+                }
+                std::cmp::Ordering::Greater => {
+                    indices.push(b_index);
+                    values.push(b_values[l]);
+                    l += 1;
+                }
+                std::cmp::Ordering::Equal => {
+                    indices.push(a_index);
+                    values.push(a_values[k] + b_values[l]);
+                    k += 1;
+                    l += 1;
+                }
+            }
+        }
+        offsets.push(indices.len());
+    }
+
+    (offsets, indices, values)
+}
+
+impl<T> std::ops::Add for super::Csr<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    type Output = super::Csr<T>;
+
+    fn add(self, other: super::Csr<T>) -> Self::Output {
+        if self.size != other.size {
+            panic!("Failed to add two sparse matrices with differing size");
+        }
+
+        let (offsets, indices, values) = merge(
+            (&self.offsets, &self.indices, &self.values),
+            (&other.offsets, &other.indices, &other.values),
+        );
+
+        Self::Output {
+            offsets,
+            indices,
+            values,
+            size: self.size,
+        }
+    }
+}
+
+impl<T> std::ops::Add for super::Csc<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    type Output = super::Csc<T>;
+
+    fn add(self, other: super::Csc<T>) -> Self::Output {
+        if self.size != other.size {
+            panic!("Failed to add two sparse matrices with differing size");
+        }
+
+        let (offsets, indices, values) = merge(
+            (&self.offsets, &self.indices, &self.values),
+            (&other.offsets, &other.indices, &other.values),
+        );
+
+        Self::Output {
+            offsets,
+            indices,
+            values,
+            size: self.size,
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::{Csc, Csr};
+use crate::matrix::Matrix;
+
+fn lhs() -> Matrix<i32> {
+    Matrix::new(&[&[0, 2, 0, 0], &[1, 0, 0, 3], &[0, 0, 0, 0]]).unwrap()
+}
+
+fn rhs() -> Matrix<i32> {
+    Matrix::new(&[&[4, -2, 0, 0], &[0, 0, 5, 1], &[0, 0, 0, 7]]).unwrap()
+}
+
+#[test]
+fn add_csr() {
+    let m = Csr::from(lhs()) + Csr::from(rhs());
+
+    assert_eq!(m.nnz(), 6);
+    assert_eq!(Matrix::from(m), lhs() + rhs());
+}
+
+#[test]
+fn add_csc() {
+    let m = Csc::from(lhs()) + Csc::from(rhs());
+
+    assert_eq!(m.nnz(), 6);
+    assert_eq!(Matrix::from(m), lhs() + rhs());
+}
+
+#[test]
+#[should_panic]
+fn add_size_not_match() {
+    let _ = Csr::from(lhs()) + Csr::from(Matrix::<i32>::new(&[&[1, 2], &[3, 4]]).unwrap());
+}
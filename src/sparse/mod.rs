@@ -0,0 +1,627 @@
+mod convert;
+mod linear_arithmatic;
+mod math;
+
+#[cfg(test)]
+mod linear_arithmatic_test;
+#[cfg(test)]
+mod math_test;
+
+pub use crate::linear::Dot;
+
+/**
+    Sparse matrix in coordinate (COO) format.
+
+    Holds (row, column, value) triplets in insertion order and is meant for construction.
+    Duplicate entries are summed when converted into `Csr` or `Csc`.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Coo<T> {
+    rows: Vec<usize>,
+    columns: Vec<usize>,
+    values: Vec<T>,
+    size: [usize; 2],
+}
+
+/**
+    Sparse matrix in compressed sparse row (CSR) format.
+
+    Column indices of row `j` are `column_indices()[row_offsets()[j]..row_offsets()[j + 1]]` in ascending order.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csr<T> {
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+    size: [usize; 2],
+}
+
+/**
+    Sparse matrix in compressed sparse column (CSC) format.
+
+    Row indices of column `i` are `row_indices()[column_offsets()[i]..column_offsets()[i + 1]]` in ascending order.
+*/
+#[derive(Debug, Clone, PartialEq)]
+pub struct Csc<T> {
+    offsets: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<T>,
+    size: [usize; 2],
+}
+
+/**
+    Compresses entries given by major and minor indices into offsets of every major index,
+    minor indices sorted in ascending order and values with duplicates summed
+*/
+fn compress<T>(
+    major_len: usize,
+    major: &[usize],
+    minor: &[usize],
+    values: &[T],
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    // Counting sort of entries by major index
+    let mut offsets = vec![0; major_len + 1];
+    major.iter().for_each(|j| offsets[j + 1] += 1);
+    (0..major_len).for_each(|j| offsets[j + 1] += offsets[j]);
+
+    let mut order = vec![0; major.len()];
+    let mut next = offsets.clone();
+    major.iter().enumerate().for_each(|(k, j)| {
+        order[next[*j]] = k;
+        next[*j] += 1;
+    });
+
+    let mut rv_offsets = Vec::with_capacity(major_len + 1);
+    let mut rv_indices = Vec::with_capacity(major.len());
+    let mut rv_values: Vec<T> = Vec::with_capacity(major.len());
+
+    rv_offsets.push(0);
+    for j in 0..major_len {
+        let entries = &mut order[offsets[j]..offsets[j + 1]];
+
+        entries.sort_by_key(|k| minor[*k]);
+        for k in entries.iter() {
+            let last = rv_indices.len();
+
+            if last > rv_offsets[j] && rv_indices[last - 1] == minor[*k] {
+                rv_values[last - 1] = rv_values[last - 1] + values[*k];
+            } else {
+                rv_indices.push(minor[*k]);
+                rv_values.push(values[*k]);
+            }
+        }
+        rv_offsets.push(rv_indices.len());
+    }
+
+    (rv_offsets, rv_indices, rv_values)
+}
+
+/**
+    Major index of every stored entry of a compressed matrix
+*/
+fn expand(offsets: &[usize]) -> Vec<usize> {
+    offsets
+        .windows(2)
+        .enumerate()
+        .flat_map(|(j, range)| std::iter::repeat_n(j, range[1] - range[0]))
+        .collect()
+}
+
+/**
+    Searches for the stored value at minor index `i` in major slice `j`
+*/
+fn find<'a, T>(
+    offsets: &[usize],
+    indices: &[usize],
+    values: &'a [T],
+    j: usize,
+    i: usize,
+) -> Option<&'a T> {
+    if j + 1 >= offsets.len() {
+        return None;
+    }
+
+    let (start, end) = (offsets[j], offsets[j + 1]);
+
+    indices[start..end]
+        .binary_search(&i)
+        .ok()
+        .map(|k| &values[start + k])
+}
+
+impl<T> Coo<T>
+where
+    T: Copy,
+{
+    /**
+    Creates new sparse matrix of size (x, y) without entries
+
+    # Example
+    ```
+    use slal::sparse::Coo;
+
+    // 3x2 matrix of zeros
+    let m = Coo::<f64>::new((3, 2));
+
+    assert!(m.size() == (3, 2) && m.nnz() == 0);
+    ```
+     */
+    pub fn new(size: (usize, usize)) -> Self {
+        Self {
+            rows: vec![],
+            columns: vec![],
+            values: vec![],
+            size: [size.0, size.1],
+        }
+    }
+
+    /**
+    Creates new sparse matrix of size (x, y) from (row, column, value) triplets
+
+    # Example
+    ```
+    use slal::sparse::Coo;
+
+    //  | 1 0 0 |
+    //  | 0 0 2 |
+    let m = Coo::from_triplets((3, 2), &[(0, 0, 1), (1, 2, 2)]).unwrap();
+
+    assert!(m.nnz() == 2);
+    assert!(Coo::from_triplets((3, 2), &[(2, 0, 1)]).is_err());
+    ```
+     */
+    pub fn from_triplets(
+        size: (usize, usize),
+        triplets: &[(usize, usize, T)],
+    ) -> crate::error::SlalErr<Self, T> {
+        let mut m = Self::new(size);
+
+        triplets
+            .iter()
+            .try_for_each(|(row, column, value)| m.push(*row, *column, *value))?;
+
+        Ok(m)
+    }
+
+    /**
+    Appends an entry at (row, column).
+    Entries at the same position are summed on compression.
+
+    # Example
+    ```
+    use slal::sparse::Coo;
+
+    let mut m = Coo::new((2, 2));
+
+    m.push(0, 1, 1.5).unwrap();
+    m.push(0, 1, 0.5).unwrap();
+
+    assert!(m.nnz() == 2);
+    assert!(m.push(2, 0, 1.).is_err());
+    ```
+     */
+    pub fn push(&mut self, row: usize, column: usize, value: T) -> crate::error::SlalErr<(), T> {
+        use crate::error::SlalError;
+
+        if row >= self.size[1] || column >= self.size[0] {
+            return Err(SlalError::IndexOutOfBounds(
+                format!("({}, {})", row, column),
+                format!("({}, {})", self.size[0], self.size[1]),
+            ));
+        }
+
+        self.rows.push(row);
+        self.columns.push(column);
+        self.values.push(value);
+
+        Ok(())
+    }
+
+    /**
+    Returns the size of sparse matrix
+
+    # Example
+    ```
+    use slal::sparse::Coo;
+
+    let m = Coo::<i32>::new((4, 3));
+    let (m_hor, m_vert) = m.size();     // returns (4, 3)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.size[0], self.size[1])
+    }
+
+    /**
+    Returns the number of stored entries, including duplicates
+
+    # Example
+    ```
+    use slal::sparse::Coo;
+
+    let m = Coo::from_triplets((2, 2), &[(0, 0, 1), (0, 0, 2)]).unwrap();
+
+    assert!(m.nnz() == 2);
+    ```
+     */
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+}
+
+impl<T> Csr<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    /**
+    Returns the size of sparse matrix
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::<f32>::new((4, 3)));
+    let (m_hor, m_vert) = m.size();     // returns (4, 3)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.size[0], self.size[1])
+    }
+
+    /**
+    Returns the number of stored entries
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::from_triplets((2, 2), &[(0, 0, 1), (0, 0, 2), (1, 0, 3)]).unwrap());
+
+    assert!(m.nnz() == 2);
+    ```
+     */
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /**
+    Returns the stored value at (row, column), or `None` if the entry is not stored
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::from_triplets((2, 2), &[(0, 1, 2), (0, 1, 3)]).unwrap());
+
+    assert!(m.get(0, 1) == Some(&5));
+    assert!(m.get(1, 1) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        find(&self.offsets, &self.indices, &self.values, row, column)
+    }
+
+    /**
+    Returns the offsets of every row into column indices and values, followed by the number of entries
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::from_triplets((2, 3), &[(0, 1, 1), (2, 0, 2), (2, 1, 3)]).unwrap());
+
+    assert!(m.row_offsets() == &[0, 1, 1, 3]);
+    ```
+     */
+    pub fn row_offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /**
+    Returns the column index of every stored value
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::from_triplets((2, 3), &[(2, 1, 3), (0, 1, 1), (2, 0, 2)]).unwrap());
+
+    assert!(m.column_indices() == &[1, 0, 1]);
+    ```
+     */
+    pub fn column_indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /**
+    Returns the stored values row by row
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    let m = Csr::from(Coo::from_triplets((2, 3), &[(2, 1, 3), (0, 1, 1), (2, 0, 2)]).unwrap());
+
+    assert!(m.values() == &[1, 2, 3]);
+    ```
+     */
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /**
+    Transposes sparse matrix (x, y) into (y, x)
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csr};
+
+    //  | 0 1 |
+    //  | 2 0 |
+    //  | 0 3 |
+    let mut m = Csr::from(Coo::from_triplets((2, 3), &[(0, 1, 1), (1, 0, 2), (2, 1, 3)]).unwrap());
+
+    m.t();
+
+    assert!(m.size() == (3, 2));
+    assert!(m.get(1, 0) == Some(&1) && m.get(0, 1) == Some(&2) && m.get(1, 2) == Some(&3));
+    ```
+     */
+    pub fn t(&mut self) {
+        let rows = expand(&self.offsets);
+        let (offsets, indices, values) = compress(self.size[0], &self.indices, &rows, &self.values);
+
+        *self = Self {
+            offsets,
+            indices,
+            values,
+            size: [self.size[1], self.size[0]],
+        };
+    }
+}
+
+impl<T> Csc<T>
+where
+    T: Copy + std::ops::Add<Output = T>,
+{
+    /**
+    Returns the size of sparse matrix
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::<f32>::new((4, 3)));
+    let (m_hor, m_vert) = m.size();     // returns (4, 3)
+    ```
+     */
+    pub fn size(&self) -> (usize, usize) {
+        (self.size[0], self.size[1])
+    }
+
+    /**
+    Returns the number of stored entries
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::from_triplets((2, 2), &[(0, 0, 1), (0, 0, 2), (1, 0, 3)]).unwrap());
+
+    assert!(m.nnz() == 2);
+    ```
+     */
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /**
+    Returns the stored value at (row, column), or `None` if the entry is not stored
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::from_triplets((2, 2), &[(0, 1, 2), (0, 1, 3)]).unwrap());
+
+    assert!(m.get(0, 1) == Some(&5));
+    assert!(m.get(1, 1) == None);
+    ```
+     */
+    pub fn get(&self, row: usize, column: usize) -> Option<&T> {
+        find(&self.offsets, &self.indices, &self.values, column, row)
+    }
+
+    /**
+    Returns the offsets of every column into row indices and values, followed by the number of entries
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::from_triplets((3, 2), &[(1, 0, 1), (0, 2, 2), (1, 2, 3)]).unwrap());
+
+    assert!(m.column_offsets() == &[0, 1, 1, 3]);
+    ```
+     */
+    pub fn column_offsets(&self) -> &[usize] {
+        &self.offsets
+    }
+
+    /**
+    Returns the row index of every stored value
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::from_triplets((3, 2), &[(1, 2, 3), (1, 0, 1), (0, 2, 2)]).unwrap());
+
+    assert!(m.row_indices() == &[1, 0, 1]);
+    ```
+     */
+    pub fn row_indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /**
+    Returns the stored values column by column
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    let m = Csc::from(Coo::from_triplets((3, 2), &[(1, 2, 3), (1, 0, 1), (0, 2, 2)]).unwrap());
+
+    assert!(m.values() == &[1, 2, 3]);
+    ```
+     */
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /**
+    Transposes sparse matrix (x, y) into (y, x)
+
+    # Example
+    ```
+    use slal::sparse::{Coo, Csc};
+
+    //  | 0 1 |
+    //  | 2 0 |
+    //  | 0 3 |
+    let mut m = Csc::from(Coo::from_triplets((2, 3), &[(0, 1, 1), (1, 0, 2), (2, 1, 3)]).unwrap());
+
+    m.t();
+
+    assert!(m.size() == (3, 2));
+    assert!(m.get(1, 0) == Some(&1) && m.get(0, 1) == Some(&2) && m.get(1, 2) == Some(&3));
+    ```
+     */
+    pub fn t(&mut self) {
+        let columns = expand(&self.offsets);
+        let (offsets, indices, values) =
+            compress(self.size[1], &self.indices, &columns, &self.values);
+
+        *self = Self {
+            offsets,
+            indices,
+            values,
+            size: [self.size[1], self.size[0]],
+        };
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Coo, Csc, Csr};
+    use crate::error::SlalError;
+    use crate::matrix::Matrix;
+
+    fn dense() -> Matrix<i32> {
+        Matrix::new(&[&[0, 2, 0, 0], &[1, 0, 0, 3], &[0, 0, 0, 0]]).unwrap()
+    }
+
+    #[test]
+    fn from_triplets() {
+        let m = Coo::from_triplets((4, 3), &[(1, 3, 3), (0, 1, 2), (1, 0, 1)]).unwrap();
+
+        assert_eq!(
+            m,
+            Coo {
+                rows: vec![1, 0, 1],
+                columns: vec![3, 1, 0],
+                values: vec![3, 2, 1],
+                size: [4, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn push_out_of_bounds() {
+        let mut m = Coo::<f64>::new((4, 3));
+
+        assert_eq!(
+            m.push(1, 4, 1.),
+            Err(SlalError::IndexOutOfBounds(
+                String::from("(1, 4)"),
+                String::from("(4, 3)")
+            ))
+        );
+    }
+
+    #[test]
+    fn csr_from_coo() {
+        let m = Csr::from(
+            Coo::from_triplets((4, 3), &[(1, 3, 3), (0, 1, 2), (1, 0, 1), (1, 3, -1)]).unwrap(),
+        );
+
+        assert_eq!(
+            m,
+            Csr {
+                offsets: vec![0, 1, 3, 3],
+                indices: vec![1, 0, 3],
+                values: vec![2, 1, 2],
+                size: [4, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn csc_from_coo() {
+        let m = Csc::from(
+            Coo::from_triplets((4, 3), &[(1, 3, 3), (0, 1, 2), (1, 0, 1), (1, 3, -1)]).unwrap(),
+        );
+
+        assert_eq!(
+            m,
+            Csc {
+                offsets: vec![0, 1, 2, 2, 3],
+                indices: vec![1, 0, 1],
+                values: vec![1, 2, 2],
+                size: [4, 3],
+            }
+        );
+    }
+
+    #[test]
+    fn dense_round_trip() {
+        assert_eq!(Matrix::from(Csr::from(dense())), dense());
+        assert_eq!(Matrix::from(Csc::from(dense())), dense());
+        assert_eq!(Csr::from(dense()).nnz(), 3);
+    }
+
+    #[test]
+    fn csr_csc_conversion() {
+        let csr = Csr::from(dense());
+        let csc = Csc::from(dense());
+
+        assert_eq!(Csc::from(csr.clone()), csc);
+        assert_eq!(Csr::from(csc), csr);
+    }
+
+    #[test]
+    fn empty() {
+        let m = Csr::from(Coo::<u8>::new((0, 0)));
+
+        assert_eq!(m.row_offsets(), &[0]);
+        assert_eq!(Matrix::from(m), Matrix::empty());
+    }
+
+    #[test]
+    fn transpose() {
+        let mut m = Csr::from(dense());
+        let mut n = Csc::from(dense());
+        let mut expected = dense();
+
+        m.t();
+        n.t();
+        expected.t();
+
+        assert_eq!(Matrix::from(m), expected);
+        assert_eq!(Matrix::from(n), expected);
+    }
+}
@@ -0,0 +1,214 @@
+mod transform2;
+mod transform3;
+
+#[cfg(test)]
+mod transform2_test;
+#[cfg(test)]
+mod transform3_test;
+
+/**
+    Homogeneous 4 x 4 transform of 3 dimensional space.
+
+    Points are extended with w = 1 and directions with w = 0, so translations only move points.
+    Composition follows matrix multiplication: `a * b` applies `b` first, then `a`.
+    Rigid transforms (rotation and translation) are inverted cheaply with `rigid_inverse`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform3 {
+    m: [[f64; 4]; 4],
+}
+
+/**
+    Homogeneous 3 x 3 transform of the plane.
+
+    Points are extended with w = 1 and directions with w = 0, so translations only move points.
+    Composition follows matrix multiplication: `a * b` applies `b` first, then `a`.
+    Rigid transforms (rotation and translation) are inverted cheaply with `rigid_inverse`.
+*/
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transform2 {
+    m: [[f64; 3]; 3],
+}
+
+fn identity<const N: usize>() -> [[f64; N]; N] {
+    let mut m = [[0.; N]; N];
+
+    (0..N).for_each(|i| m[i][i] = 1.);
+
+    m
+}
+
+fn mul<const N: usize>(a: &[[f64; N]; N], b: &[[f64; N]; N]) -> [[f64; N]; N] {
+    let mut m = [[0.; N]; N];
+
+    (0..N).for_each(|j| (0..N).for_each(|i| m[j][i] = (0..N).map(|k| a[j][k] * b[k][i]).sum()));
+
+    m
+}
+
+/**
+    Returns `true` if the last row is (0, ..., 0, 1), so the transform does not project
+*/
+fn is_affine<const N: usize>(m: &[[f64; N]; N]) -> bool {
+    (0..N).all(|i| m[N - 1][i] == if i == N - 1 { 1. } else { 0. })
+}
+
+/**
+    Inverts the upper left n x n block of `m` in place with Gauss-Jordan elimination and partial pivoting.
+    Returns `false` if the block is singular, that is a pivot is negligible relative to the largest entry.
+*/
+fn invert_block<const N: usize>(m: &mut [[f64; N]; N], n: usize) -> bool {
+    let mut inv = identity::<N>();
+    let tol = crate::matrix::pivot_tolerance(
+        n,
+        m[..n]
+            .iter()
+            .flat_map(|row| row[..n].iter())
+            .fold(0., |max: f64, m_ji| max.max(m_ji.abs())),
+    );
+
+    for k in 0..n {
+        let pivot = (k..n).fold(k, |pivot, j| {
+            if m[j][k].abs() > m[pivot][k].abs() {
+                j
+            } else {
+                pivot
+            }
+        });
+
+        if m[pivot][k].abs() <= tol {
+            return false;
+        }
+        m.swap(k, pivot);
+        inv.swap(k, pivot);
+
+        let m_kk = m[k][k];
+        (0..n).for_each(|i| {
+            m[k][i] /= m_kk;
+            inv[k][i] /= m_kk;
+        });
+
+        for j in (0..n).filter(|j| *j != k) {
+            let l = m[j][k];
+
+            (0..n).for_each(|i| {
+                m[j][i] -= l * m[k][i];
+                inv[j][i] -= l * inv[k][i];
+            });
+        }
+    }
+
+    (0..n).for_each(|j| m[j][..n].copy_from_slice(&inv[j][..n]));
+
+    true
+}
+
+/**
+    Inverts a homogeneous transform. Affine transforms only invert their linear block,
+    `[A t; 0 1]^-1 = [A^-1  -A^-1 t; 0 1]`, projections invert the whole matrix.
+*/
+fn inverse<const N: usize>(m: &[[f64; N]; N]) -> Option<[[f64; N]; N]> {
+    let mut rv = *m;
+
+    if !is_affine(m) {
+        return if invert_block(&mut rv, N) {
+            Some(rv)
+        } else {
+            None
+        };
+    }
+
+    if !invert_block(&mut rv, N - 1) {
+        return None;
+    }
+    (0..N - 1)
+        .for_each(|j| rv[j][N - 1] = -(0..N - 1).map(|k| rv[j][k] * m[k][N - 1]).sum::<f64>());
+
+    Some(rv)
+}
+
+/**
+    Inverts a rigid transform `[R t; 0 1]` as `[R^T  -R^T t; 0 1]`
+*/
+fn rigid_inverse<const N: usize>(m: &[[f64; N]; N]) -> [[f64; N]; N] {
+    let mut rv = identity::<N>();
+
+    (0..N - 1).for_each(|j| {
+        (0..N - 1).for_each(|i| rv[j][i] = m[i][j]);
+        rv[j][N - 1] = -(0..N - 1).map(|k| m[k][j] * m[k][N - 1]).sum::<f64>();
+    });
+
+    rv
+}
+
+/**
+    Applies transform `m` to the homogeneous coordinates of vertex `v` with the given w,
+    and divides by the resulting w for projected points.
+    Returns `SlalError::PointAtInfinity` if the resulting w of a point is zero or not finite.
+*/
+fn apply<T, const N: usize>(
+    m: &[[f64; N]; N],
+    v: &crate::vertex::Vertex<T>,
+    w: f64,
+) -> crate::error::SlalErr<crate::vertex::Vertex<f64>, T>
+where
+    T: crate::scalar::RealScalar,
+{
+    use crate::error::SlalError;
+    use crate::vertex::Vertex;
+
+    if v.len() != N - 1 {
+        return Err(SlalError::VertexStateError(format!(
+            "Vertex {:?} must have length {} to be transformed",
+            *v,
+            N - 1
+        )));
+    }
+
+    let mut h = [w; N];
+    (0..N - 1).for_each(|i| h[i] = v[i].to_f64());
+
+    let rv: Vec<f64> = (0..N)
+        .map(|j| (0..N).map(|k| m[j][k] * h[k]).sum())
+        .collect();
+
+    if w != 0. && (rv[N - 1] == 0. || !rv[N - 1].is_finite()) {
+        return Err(SlalError::PointAtInfinity(format!("{:?}", *v)));
+    }
+
+    let rv: Vec<f64> = if w != 0. && rv[N - 1] != 1. {
+        rv[..N - 1].iter().map(|x| x / rv[N - 1]).collect()
+    } else {
+        rv[..N - 1].to_vec()
+    };
+
+    if v.is_transposed() {
+        Ok(Vertex::new_transposed(rv.as_slice()))
+    } else {
+        Ok(Vertex::new(rv.as_slice()))
+    }
+}
+
+fn to_matrix<const N: usize>(m: &[[f64; N]; N]) -> crate::matrix::Matrix<f64> {
+    let rows: Vec<&[f64]> = m.iter().map(|row| row.as_slice()).collect();
+
+    crate::matrix::Matrix::new(rows.as_slice()).unwrap()
+}
+
+fn from_matrix<const N: usize>(
+    m: &crate::matrix::Matrix<f64>,
+) -> crate::error::SlalErr<[[f64; N]; N], f64> {
+    use crate::error::SlalError;
+
+    if m.size() != (N, N) {
+        return Err(SlalError::UnmatchingMatrixSize(
+            format!("{:?}", *m),
+            format!("({}, {})", N, N),
+        ));
+    }
+
+    let mut rv = [[0.; N]; N];
+    (0..N).for_each(|j| (0..N).for_each(|i| rv[j][i] = m[j][i]));
+
+    Ok(rv)
+}
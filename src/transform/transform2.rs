@@ -0,0 +1,279 @@
+use super::Transform2;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+impl Transform2 {
+    /**
+    Creates the identity transform
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let p = Vertex::new(&[1., 2.]);
+
+    assert!(Transform2::identity().transform_point(&p).unwrap() == p);
+    ```
+     */
+    pub fn identity() -> Self {
+        Self {
+            m: super::identity(),
+        }
+    }
+
+    /**
+    Creates transform that translates points by (x, y)
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::translation(1., 2.);
+
+    assert!(t.transform_point(&Vertex::new(&[1, 1])).unwrap() == Vertex::new(&[2., 3.]));
+    ```
+     */
+    pub fn translation(x: f64, y: f64) -> Self {
+        let mut m = super::identity();
+
+        m[0][2] = x;
+        m[1][2] = y;
+
+        Self { m }
+    }
+
+    /**
+    Creates transform that scales along each axis by (x, y)
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::scale(2., 3.);
+
+    assert!(t.transform_point(&Vertex::new(&[1, 1])).unwrap() == Vertex::new(&[2., 3.]));
+    ```
+     */
+    pub fn scale(x: f64, y: f64) -> Self {
+        let mut m = super::identity();
+
+        m[0][0] = x;
+        m[1][1] = y;
+
+        Self { m }
+    }
+
+    /**
+    Creates transform that rotates counterclockwise by `angle` radians about the origin
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::rotation(std::f64::consts::FRAC_PI_2);
+    let p = t.transform_point(&Vertex::new(&[1., 0.])).unwrap();
+
+    assert!(p[0].abs() < 1e-15 && (p[1] - 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn rotation(angle: f64) -> Self {
+        let (s, c) = angle.sin_cos();
+
+        Self {
+            m: [[c, -s, 0.], [s, c, 0.], [0., 0., 1.]],
+        }
+    }
+
+    /**
+    Creates transform from a 3 x 3 homogeneous matrix
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::transform::Transform2;
+
+    let m = Matrix::new(&[&[1., 0., 5.], &[0., 1., 0.], &[0., 0., 1.]]).unwrap();
+
+    assert!(Transform2::from_matrix(&m).unwrap() == Transform2::translation(5., 0.));
+    ```
+     */
+    pub fn from_matrix(m: &Matrix<f64>) -> crate::error::SlalErr<Self, f64> {
+        Ok(Self {
+            m: super::from_matrix(m)?,
+        })
+    }
+
+    /**
+    Returns the 3 x 3 homogeneous matrix of the transform
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+
+    let m = Transform2::translation(1., 2.).matrix();
+
+    assert!(m.size() == (3, 3) && m[1][2] == 2.);
+    ```
+     */
+    pub fn matrix(&self) -> Matrix<f64> {
+        super::to_matrix(&self.m)
+    }
+
+    /**
+    Creates orthographic projection mapping the rectangle [left, right] x [bottom, top] to the square [-1, 1]^2.
+    Returns `SlalError::InvalidProjection` if the rectangle is empty along any axis.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let p = Transform2::orthographic(0., 640., 480., 0.).unwrap();
+
+    assert!(p.transform_point(&Vertex::new(&[640., 0.])).unwrap() == Vertex::new(&[1., 1.]));
+    ```
+     */
+    pub fn orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+    ) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        if left == right || bottom == top {
+            return Err(SlalError::InvalidProjection(format!(
+                "orthographic with rectangle [{}, {}] x [{}, {}]",
+                left, right, bottom, top
+            )));
+        }
+
+        Ok(Self {
+            m: [
+                [2. / (right - left), 0., -(right + left) / (right - left)],
+                [0., 2. / (top - bottom), -(top + bottom) / (top - bottom)],
+                [0., 0., 1.],
+            ],
+        })
+    }
+
+    /**
+    Composes two transforms, so that `other` is applied first and `self` second.
+    Same as `*self * *other`.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::translation(1., 0.).compose(&Transform2::scale(2., 2.));
+
+    assert!(t.transform_point(&Vertex::new(&[1., 1.])).unwrap() == Vertex::new(&[3., 2.]));
+    ```
+     */
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            m: super::mul(&self.m, &other.m),
+        }
+    }
+
+    /**
+    Inverts the transform. Affine transforms only invert their 2 x 2 linear part,
+    projections invert the whole 3 x 3 matrix.
+    Returns `SlalError::DeterminantZero` if the transform is singular.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+
+    let t = Transform2::scale(2., 4.).compose(&Transform2::translation(1., 2.));
+
+    assert!(t.inverse().unwrap().compose(&t) == Transform2::identity());
+    assert!(Transform2::scale(0., 1.).inverse().is_err());
+    ```
+     */
+    pub fn inverse(&self) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        match super::inverse(&self.m) {
+            Some(m) => Ok(Self { m }),
+            None => Err(SlalError::DeterminantZero(self.matrix())),
+        }
+    }
+
+    /**
+    Inverts a rigid transform (rotation and translation only) by transposing the rotation.
+    The result is wrong for transforms that scale, shear or project, use `inverse` for them.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+
+    let t = Transform2::translation(1., 2.).compose(&Transform2::rotation(0.5));
+    let inv = t.rigid_inverse().compose(&t).matrix();
+
+    assert!((0..3).all(|j| (0..3).all(|i| (inv[j][i] - if i == j { 1. } else { 0. }).abs() < 1e-15)));
+    ```
+     */
+    pub fn rigid_inverse(&self) -> Self {
+        Self {
+            m: super::rigid_inverse(&self.m),
+        }
+    }
+
+    /**
+    Transforms a point of length 2, which is moved by translations.
+    Projected points are divided by their homogeneous coordinate w,
+    and `SlalError::PointAtInfinity` is returned if w is zero or not finite.
+    Orientation of the vertex is kept.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::translation(0., 1.);
+
+    assert!(t.transform_point(&Vertex::new(&[1., 2.])).unwrap() == Vertex::new(&[1., 3.]));
+    ```
+     */
+    pub fn transform_point<T>(&self, p: &Vertex<T>) -> crate::error::SlalErr<Vertex<f64>, T>
+    where
+        T: crate::scalar::RealScalar,
+    {
+        super::apply(&self.m, p, 1.)
+    }
+
+    /**
+    Transforms a direction of length 2, which is not moved by translations.
+    Orientation of the vertex is kept.
+
+    # Example
+    ```
+    use slal::transform::Transform2;
+    use slal::vertex::Vertex;
+
+    let t = Transform2::translation(0., 1.).compose(&Transform2::scale(2., 1.));
+
+    assert!(t.transform_direction(&Vertex::new(&[1., 2.])).unwrap() == Vertex::new(&[2., 2.]));
+    ```
+     */
+    pub fn transform_direction<T>(&self, d: &Vertex<T>) -> crate::error::SlalErr<Vertex<f64>, T>
+    where
+        T: crate::scalar::RealScalar,
+    {
+        super::apply(&self.m, d, 0.)
+    }
+}
+
+impl std::ops::Mul for Transform2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
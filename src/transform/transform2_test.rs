@@ -0,0 +1,92 @@
+use super::Transform2;
+use crate::error::SlalError;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+use std::f64::consts::FRAC_PI_2;
+
+fn approx_eq(a: &Vertex<f64>, b: &Vertex<f64>) -> bool {
+    a.len() == b.len() && (0..a.len()).all(|i| (a[i] - b[i]).abs() < 1e-12)
+}
+
+fn approx_identity(t: &Transform2) -> bool {
+    let m = t.matrix();
+
+    (0..3).all(|j| (0..3).all(|i| (m[j][i] - if i == j { 1. } else { 0. }).abs() < 1e-12))
+}
+
+#[test]
+fn compose_order() {
+    let r = Transform2::rotation(FRAC_PI_2);
+    let t = Transform2::translation(1., 0.);
+    let p = Vertex::new(&[1., 0.]);
+
+    assert!(approx_eq(
+        &(t * r).transform_point(&p).unwrap(),
+        &Vertex::new(&[1., 1.])
+    ));
+    assert!(approx_eq(
+        &(r * t).transform_point(&p).unwrap(),
+        &Vertex::new(&[0., 2.])
+    ));
+}
+
+#[test]
+fn point_and_direction() {
+    let t = Transform2::translation(1., 2.) * Transform2::scale(3., -1.);
+    let v = Vertex::new(&[1_i32, 1]);
+
+    assert_eq!(t.transform_point(&v).unwrap(), Vertex::new(&[4., 1.]));
+    assert_eq!(t.transform_direction(&v).unwrap(), Vertex::new(&[3., -1.]));
+    assert!(matches!(
+        t.transform_direction(&Vertex::new(&[1, 2, 3])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn inverse() {
+    // Shear is neither rigid nor a scale
+    let shear = Transform2::from_matrix(
+        &Matrix::new(&[&[1., 2., 0.], &[0., 1., 0.], &[0., 0., 1.]]).unwrap(),
+    )
+    .unwrap();
+    let t = Transform2::translation(-3., 4.) * Transform2::rotation(1.2) * shear;
+
+    assert!(approx_identity(&(t.inverse().unwrap() * t)));
+    assert!(approx_identity(&(t * t.inverse().unwrap())));
+    assert!(matches!(
+        Transform2::scale(2., 0.).inverse(),
+        Err(SlalError::DeterminantZero(_))
+    ));
+
+    // Projective transforms are inverted as a whole
+    let projective = Transform2::from_matrix(
+        &Matrix::new(&[&[1., 0., 0.], &[0., 1., 0.], &[0.5, 0., 1.]]).unwrap(),
+    )
+    .unwrap();
+    assert!(approx_identity(
+        &(projective.inverse().unwrap() * projective)
+    ));
+}
+
+#[test]
+fn rigid_inverse() {
+    let t = Transform2::translation(-3., 4.) * Transform2::rotation(1.2);
+
+    assert!(approx_identity(&(t.rigid_inverse() * t)));
+    assert!(approx_identity(&(t * t.rigid_inverse())));
+}
+
+#[test]
+fn orthographic() {
+    let p = Transform2::orthographic(-4., 4., -3., 3.).unwrap();
+
+    assert!(approx_eq(
+        &p.transform_point(&Vertex::new(&[2., -3.])).unwrap(),
+        &Vertex::new(&[0.5, -1.])
+    ));
+    assert!(matches!(
+        Transform2::orthographic(0., 1., 2., 2.),
+        Err(SlalError::InvalidProjection(_))
+    ));
+}
@@ -0,0 +1,465 @@
+use super::Transform3;
+use crate::matrix::Matrix;
+use crate::vertex::Vertex;
+
+/**
+    Checks that vertex `v` has length 3 and copies it into an array
+*/
+fn to_array(v: &Vertex<f64>) -> crate::error::SlalErr<[f64; 3], f64> {
+    use crate::error::SlalError;
+
+    if v.len() != 3 {
+        return Err(SlalError::VertexStateError(format!(
+            "Vertex {:?} must have length 3",
+            *v
+        )));
+    }
+
+    Ok([v[0], v[1], v[2]])
+}
+
+fn cross(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl Transform3 {
+    /**
+    Creates the identity transform
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let p = Vertex::new(&[1., 2., 3.]);
+
+    assert!(Transform3::identity().transform_point(&p).unwrap() == p);
+    ```
+     */
+    pub fn identity() -> Self {
+        Self {
+            m: super::identity(),
+        }
+    }
+
+    /**
+    Creates transform that translates points by (x, y, z)
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let t = Transform3::translation(1., 2., 3.);
+
+    assert!(t.transform_point(&Vertex::new(&[1, 1, 1])).unwrap() == Vertex::new(&[2., 3., 4.]));
+    ```
+     */
+    pub fn translation(x: f64, y: f64, z: f64) -> Self {
+        let mut m = super::identity();
+
+        m[0][3] = x;
+        m[1][3] = y;
+        m[2][3] = z;
+
+        Self { m }
+    }
+
+    /**
+    Creates transform that scales along each axis by (x, y, z)
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let t = Transform3::scale(2., 3., 4.);
+
+    assert!(t.transform_point(&Vertex::new(&[1, 1, 1])).unwrap() == Vertex::new(&[2., 3., 4.]));
+    ```
+     */
+    pub fn scale(x: f64, y: f64, z: f64) -> Self {
+        let mut m = super::identity();
+
+        m[0][0] = x;
+        m[1][1] = y;
+        m[2][2] = z;
+
+        Self { m }
+    }
+
+    /**
+    Creates transform that rotates by a 3 x 3 rotation matrix.
+    Returns `SlalError::NotRotationMatrix` unless the matrix is orthonormal and has determinant 1.
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::transform::Transform3;
+
+    let t = Transform3::from_rotation(&Matrix::from_rpy(0.1, 0.2, 0.3)).unwrap();
+    ```
+     */
+    pub fn from_rotation(r: &Matrix<f64>) -> crate::error::SlalErr<Self, f64> {
+        let r = crate::rotation::check_rotation(r)?;
+        let mut m = super::identity();
+
+        (0..3).for_each(|j| m[j][..3].copy_from_slice(&r[j]));
+
+        Ok(Self { m })
+    }
+
+    /**
+    Creates transform that rotates by a quaternion
+
+    # Example
+    ```
+    use slal::quaternion::Quaternion;
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let z = Vertex::new(&[0., 0., 1.]);
+    let q = Quaternion::<f64>::from_axis_angle(&z, std::f64::consts::FRAC_PI_2).unwrap();
+    let p = Transform3::from_quaternion(&q).transform_point(&Vertex::new(&[1., 0., 0.])).unwrap();
+
+    assert!(p[0].abs() < 1e-15 && (p[1] - 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn from_quaternion(q: &crate::quaternion::Quaternion<f64>) -> Self {
+        let r = q.rotation_matrix();
+        let mut m = super::identity();
+
+        (0..3).for_each(|j| (0..3).for_each(|i| m[j][i] = r[j][i]));
+
+        Self { m }
+    }
+
+    /**
+    Creates transform from a 4 x 4 homogeneous matrix
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::transform::Transform3;
+
+    let m = Matrix::new(&[
+        &[1., 0., 0., 5.],
+        &[0., 1., 0., 0.],
+        &[0., 0., 1., 0.],
+        &[0., 0., 0., 1.],
+    ])
+    .unwrap();
+
+    assert!(Transform3::from_matrix(&m).unwrap() == Transform3::translation(5., 0., 0.));
+    ```
+     */
+    pub fn from_matrix(m: &Matrix<f64>) -> crate::error::SlalErr<Self, f64> {
+        Ok(Self {
+            m: super::from_matrix(m)?,
+        })
+    }
+
+    /**
+    Returns the 4 x 4 homogeneous matrix of the transform
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::transform::Transform3;
+
+    let m = Transform3::translation(1., 2., 3.).matrix();
+
+    assert!(m.size() == (4, 4) && m[2][3] == 3.);
+    ```
+     */
+    pub fn matrix(&self) -> Matrix<f64> {
+        super::to_matrix(&self.m)
+    }
+
+    /**
+    Creates view transform of a camera at `eye` looking at `target`, where `up` points upwards.
+    The camera looks down its -Z axis with +Y up and +X to the right (right-handed, as in OpenGL).
+    Returns `SlalError::VertexStateError` if `eye` and `target` coincide or `up` is parallel to the view direction.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let eye = Vertex::new(&[0., 0., 5.]);
+    let view = Transform3::look_at(&eye, &Vertex::new(&[0., 0., 0.]), &Vertex::new(&[0., 1., 0.])).unwrap();
+
+    // The origin is 5 units in front of the camera
+    assert!(view.transform_point(&Vertex::new(&[0., 0., 0.])).unwrap() == Vertex::new(&[0., 0., -5.]));
+    ```
+     */
+    pub fn look_at(
+        eye: &Vertex<f64>,
+        target: &Vertex<f64>,
+        up: &Vertex<f64>,
+    ) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        let (eye, target, up) = (to_array(eye)?, to_array(target)?, to_array(up)?);
+        let f = [target[0] - eye[0], target[1] - eye[1], target[2] - eye[2]];
+        let s = cross(&f, &up);
+        let (f_norm, s_norm) = (dot(&f, &f).sqrt(), dot(&s, &s).sqrt());
+
+        if f_norm == 0. || s_norm <= f64::EPSILON * f_norm * dot(&up, &up).sqrt() {
+            return Err(SlalError::VertexStateError(format!(
+                "Cannot look from {:?} at {:?} with up direction {:?}",
+                eye, target, up
+            )));
+        }
+
+        let f = f.map(|x| x / f_norm);
+        let s = s.map(|x| x / s_norm);
+        let u = cross(&s, &f);
+
+        Ok(Self {
+            m: [
+                [s[0], s[1], s[2], -dot(&s, &eye)],
+                [u[0], u[1], u[2], -dot(&u, &eye)],
+                [-f[0], -f[1], -f[2], dot(&f, &eye)],
+                [0., 0., 0., 1.],
+            ],
+        })
+    }
+
+    /**
+    Creates perspective projection with vertical field of view `fov_y` in radians,
+    mapping the view frustum between `near` and `far` in front of the camera (along -Z)
+    to the cube [-1, 1]^3, as in OpenGL.
+    Returns `SlalError::InvalidProjection` unless 0 < `fov_y` < π, `aspect` > 0 and 0 < `near` < `far`.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let p = Transform3::perspective(std::f64::consts::FRAC_PI_2, 1., 1., 10.).unwrap();
+
+    // Points on the near plane are mapped to z = -1
+    let v = p.transform_point(&Vertex::new(&[1., 1., -1.])).unwrap();
+
+    assert!((v[0] - 1.).abs() < 1e-15 && (v[1] - 1.).abs() < 1e-15 && (v[2] + 1.).abs() < 1e-15);
+    ```
+     */
+    pub fn perspective(
+        fov_y: f64,
+        aspect: f64,
+        near: f64,
+        far: f64,
+    ) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        let valid = fov_y > 0.
+            && fov_y < std::f64::consts::PI
+            && aspect > 0.
+            && near > 0.
+            && far > near
+            && far.is_finite();
+        if !valid {
+            return Err(SlalError::InvalidProjection(format!(
+                "perspective with field of view {}, aspect ratio {}, near plane {} and far plane {}",
+                fov_y, aspect, near, far
+            )));
+        }
+
+        let f = 1. / (fov_y / 2.).tan();
+
+        Ok(Self {
+            m: [
+                [f / aspect, 0., 0., 0.],
+                [0., f, 0., 0.],
+                [
+                    0.,
+                    0.,
+                    (far + near) / (near - far),
+                    2. * far * near / (near - far),
+                ],
+                [0., 0., -1., 0.],
+            ],
+        })
+    }
+
+    /**
+    Creates orthographic projection mapping the box [left, right] x [bottom, top] x [-near, -far]
+    to the cube [-1, 1]^3, as in OpenGL.
+    Returns `SlalError::InvalidProjection` if the box is empty along any axis.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let p = Transform3::orthographic(0., 4., 0., 2., 1., 3.).unwrap();
+    let v = p.transform_point(&Vertex::new(&[4., 1., -3.])).unwrap();
+
+    assert!(v == Vertex::new(&[1., 0., 1.]));
+    ```
+     */
+    pub fn orthographic(
+        left: f64,
+        right: f64,
+        bottom: f64,
+        top: f64,
+        near: f64,
+        far: f64,
+    ) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        if left == right || bottom == top || near == far {
+            return Err(SlalError::InvalidProjection(format!(
+                "orthographic with box [{}, {}] x [{}, {}] x [{}, {}]",
+                left, right, bottom, top, near, far
+            )));
+        }
+
+        Ok(Self {
+            m: [
+                [
+                    2. / (right - left),
+                    0.,
+                    0.,
+                    -(right + left) / (right - left),
+                ],
+                [
+                    0.,
+                    2. / (top - bottom),
+                    0.,
+                    -(top + bottom) / (top - bottom),
+                ],
+                [0., 0., -2. / (far - near), -(far + near) / (far - near)],
+                [0., 0., 0., 1.],
+            ],
+        })
+    }
+
+    /**
+    Composes two transforms, so that `other` is applied first and `self` second.
+    Same as `*self * *other`.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let t = Transform3::translation(1., 0., 0.).compose(&Transform3::scale(2., 2., 2.));
+
+    assert!(t.transform_point(&Vertex::new(&[1., 1., 1.])).unwrap() == Vertex::new(&[3., 2., 2.]));
+    ```
+     */
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            m: super::mul(&self.m, &other.m),
+        }
+    }
+
+    /**
+    Inverts the transform. Affine transforms only invert their 3 x 3 linear part,
+    projections invert the whole 4 x 4 matrix.
+    Returns `SlalError::DeterminantZero` if the transform is singular.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+
+    let t = Transform3::scale(2., 4., 8.).compose(&Transform3::translation(1., 2., 3.));
+    let inv = t.inverse().unwrap();
+
+    assert!(inv.compose(&t) == Transform3::identity());
+    assert!(Transform3::scale(1., 0., 1.).inverse().is_err());
+    ```
+     */
+    pub fn inverse(&self) -> crate::error::SlalErr<Self, f64> {
+        use crate::error::SlalError;
+
+        match super::inverse(&self.m) {
+            Some(m) => Ok(Self { m }),
+            None => Err(SlalError::DeterminantZero(self.matrix())),
+        }
+    }
+
+    /**
+    Inverts a rigid transform (rotation and translation only) by transposing the rotation.
+    The result is wrong for transforms that scale, shear or project, use `inverse` for them.
+
+    # Example
+    ```
+    use slal::matrix::Matrix;
+    use slal::transform::Transform3;
+
+    let t = Transform3::translation(1., 2., 3.)
+        .compose(&Transform3::from_rotation(&Matrix::rotation_z(0.5)).unwrap());
+    let inv = t.rigid_inverse().compose(&t).matrix();
+
+    assert!((0..4).all(|j| (0..4).all(|i| (inv[j][i] - if i == j { 1. } else { 0. }).abs() < 1e-15)));
+    ```
+     */
+    pub fn rigid_inverse(&self) -> Self {
+        Self {
+            m: super::rigid_inverse(&self.m),
+        }
+    }
+
+    /**
+    Transforms a point of length 3, which is moved by translations.
+    Projected points are divided by their homogeneous coordinate w,
+    and `SlalError::PointAtInfinity` is returned if w is zero or not finite.
+    Orientation of the vertex is kept.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let t = Transform3::translation(0., 0., 1.);
+
+    assert!(t.transform_point(&Vertex::new(&[1., 2., 3.])).unwrap() == Vertex::new(&[1., 2., 4.]));
+    ```
+     */
+    pub fn transform_point<T>(&self, p: &Vertex<T>) -> crate::error::SlalErr<Vertex<f64>, T>
+    where
+        T: crate::scalar::RealScalar,
+    {
+        super::apply(&self.m, p, 1.)
+    }
+
+    /**
+    Transforms a direction of length 3, which is not moved by translations.
+    Orientation of the vertex is kept.
+
+    # Example
+    ```
+    use slal::transform::Transform3;
+    use slal::vertex::Vertex;
+
+    let t = Transform3::translation(0., 0., 1.).compose(&Transform3::scale(2., 1., 1.));
+
+    assert!(t.transform_direction(&Vertex::new(&[1., 2., 3.])).unwrap() == Vertex::new(&[2., 2., 3.]));
+    ```
+     */
+    pub fn transform_direction<T>(&self, d: &Vertex<T>) -> crate::error::SlalErr<Vertex<f64>, T>
+    where
+        T: crate::scalar::RealScalar,
+    {
+        super::apply(&self.m, d, 0.)
+    }
+}
+
+impl std::ops::Mul for Transform3 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.compose(&other)
+    }
+}
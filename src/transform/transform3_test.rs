@@ -0,0 +1,221 @@
+use super::Transform3;
+use crate::error::SlalError;
+use crate::matrix::Matrix;
+use crate::quaternion::Quaternion;
+use crate::vertex::Vertex;
+use std::f64::consts::FRAC_PI_2;
+
+fn approx_eq(a: &Vertex<f64>, b: &Vertex<f64>) -> bool {
+    a.len() == b.len() && (0..a.len()).all(|i| (a[i] - b[i]).abs() < 1e-12)
+}
+
+fn approx_identity(t: &Transform3) -> bool {
+    let m = t.matrix();
+
+    (0..4).all(|j| (0..4).all(|i| (m[j][i] - if i == j { 1. } else { 0. }).abs() < 1e-12))
+}
+
+#[test]
+fn compose_order() {
+    let r = Transform3::from_rotation(&Matrix::rotation_z(FRAC_PI_2)).unwrap();
+    let t = Transform3::translation(1., 0., 0.);
+    let p = Vertex::new(&[1., 0., 0.]);
+
+    // Rotate first, then translate
+    assert!(approx_eq(
+        &(t * r).transform_point(&p).unwrap(),
+        &Vertex::new(&[1., 1., 0.])
+    ));
+    // Translate first, then rotate
+    assert!(approx_eq(
+        &r.compose(&t).transform_point(&p).unwrap(),
+        &Vertex::new(&[0., 2., 0.])
+    ));
+}
+
+#[test]
+fn point_and_direction() {
+    let t = Transform3::translation(1., 2., 3.) * Transform3::scale(2., 2., 2.);
+    let v = Vertex::new_transposed(&[1, -1, 0]);
+
+    let p = t.transform_point(&v).unwrap();
+    let d = t.transform_direction(&v).unwrap();
+
+    assert!(p.is_transposed() && d.is_transposed());
+    assert_eq!(p, Vertex::new_transposed(&[3., 0., 3.]));
+    assert_eq!(d, Vertex::new_transposed(&[2., -2., 0.]));
+    assert!(matches!(
+        t.transform_point(&Vertex::new(&[1., 2.])),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn rotation_from_quaternion() {
+    let m = Matrix::from_rpy(0.3, -0.2, 1.1);
+    let q = Quaternion::<f64>::from_rotation_matrix(&m).unwrap();
+    let p = Vertex::new(&[0.5, -1., 2.]);
+
+    assert!(approx_eq(
+        &Transform3::from_quaternion(&q).transform_point(&p).unwrap(),
+        &Transform3::from_rotation(&m)
+            .unwrap()
+            .transform_point(&p)
+            .unwrap()
+    ));
+    assert!(matches!(
+        Transform3::from_rotation(&(m * 2.)),
+        Err(SlalError::NotRotationMatrix(_))
+    ));
+}
+
+#[test]
+fn inverse() {
+    let t = Transform3::translation(1., -2., 3.)
+        * Transform3::from_rotation(&Matrix::from_rpy(0.4, 0.5, 0.6)).unwrap()
+        * Transform3::scale(2., 0.5, 3.);
+
+    assert!(approx_identity(&(t.inverse().unwrap() * t)));
+    assert!(approx_identity(&(t * t.inverse().unwrap())));
+
+    let singular = Transform3::scale(1., 1., 0.);
+    assert_eq!(
+        singular.inverse(),
+        Err(SlalError::DeterminantZero(singular.matrix()))
+    );
+
+    // The second pivot is rounding noise relative to the entries
+    let nearly_singular = Transform3::from_matrix(
+        &Matrix::new(&[
+            &[1., 1., 0., 0.],
+            &[1., 1. + f64::EPSILON, 0., 0.],
+            &[0., 0., 1., 0.],
+            &[0., 0., 0., 1.],
+        ])
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(matches!(
+        nearly_singular.inverse(),
+        Err(SlalError::DeterminantZero(_))
+    ));
+}
+
+#[test]
+fn rigid_inverse() {
+    let t = Transform3::translation(1., -2., 3.)
+        * Transform3::from_rotation(&Matrix::from_rpy(0.4, 0.5, 0.6)).unwrap();
+    let inv = t.rigid_inverse();
+
+    assert!(approx_identity(&(inv * t)));
+
+    let expected = t.inverse().unwrap().matrix();
+    (0..4).for_each(|j| {
+        (0..4).for_each(|i| assert!((inv.matrix()[j][i] - expected[j][i]).abs() < 1e-12))
+    });
+}
+
+#[test]
+fn look_at() {
+    let eye = Vertex::new(&[1., 2., 3.]);
+    let target = Vertex::new(&[4., 2., -1.]);
+    let view = Transform3::look_at(&eye, &target, &Vertex::new(&[0., 1., 0.])).unwrap();
+
+    // The eye is at the origin, the target is 5 units down -Z
+    assert!(approx_eq(
+        &view.transform_point(&eye).unwrap(),
+        &Vertex::new(&[0., 0., 0.])
+    ));
+    assert!(approx_eq(
+        &view.transform_point(&target).unwrap(),
+        &Vertex::new(&[0., 0., -5.])
+    ));
+    assert!(approx_eq(
+        &view
+            .transform_direction(&Vertex::new(&[0., 1., 0.]))
+            .unwrap(),
+        &Vertex::new(&[0., 1., 0.])
+    ));
+    assert!(approx_identity(&(view.rigid_inverse() * view)));
+
+    assert!(matches!(
+        Transform3::look_at(&eye, &eye, &Vertex::new(&[0., 1., 0.])),
+        Err(SlalError::VertexStateError(_))
+    ));
+    assert!(matches!(
+        Transform3::look_at(
+            &eye,
+            &Vertex::new(&[1., 5., 3.]),
+            &Vertex::new(&[0., 1., 0.])
+        ),
+        Err(SlalError::VertexStateError(_))
+    ));
+}
+
+#[test]
+fn perspective() {
+    let p = Transform3::perspective(FRAC_PI_2, 2., 1., 10.).unwrap();
+
+    // Corners of the far plane
+    assert!(approx_eq(
+        &p.transform_point(&Vertex::new(&[20., -10., -10.])).unwrap(),
+        &Vertex::new(&[1., -1., 1.])
+    ));
+
+    // Projections are inverted as a whole
+    let q = Vertex::new(&[0.3, -0.2, -4.]);
+    let projected = p.transform_point(&q).unwrap();
+    assert!(approx_eq(
+        &p.inverse().unwrap().transform_point(&projected).unwrap(),
+        &q
+    ));
+
+    // Points on the plane of the camera have w = 0
+    assert!(matches!(
+        p.transform_point(&Vertex::new(&[1., 2., 0.])),
+        Err(SlalError::PointAtInfinity(_))
+    ));
+    assert!(matches!(
+        p.transform_point(&Vertex::new(&[f64::INFINITY, 0., -1.])),
+        Err(SlalError::PointAtInfinity(_))
+    ));
+
+    assert!(matches!(
+        Transform3::perspective(FRAC_PI_2, 1., 0., 10.),
+        Err(SlalError::InvalidProjection(_))
+    ));
+    assert!(matches!(
+        Transform3::perspective(0., 1., 1., 10.),
+        Err(SlalError::InvalidProjection(_))
+    ));
+}
+
+#[test]
+fn orthographic() {
+    let p = Transform3::orthographic(-2., 2., -1., 1., 0.5, 4.5).unwrap();
+
+    assert!(approx_eq(
+        &p.transform_point(&Vertex::new(&[-2., 1., -0.5])).unwrap(),
+        &Vertex::new(&[-1., 1., -1.])
+    ));
+    assert!(approx_eq(
+        &p.transform_point(&Vertex::new(&[0., 0., -2.5])).unwrap(),
+        &Vertex::new(&[0., 0., 0.])
+    ));
+    assert!(matches!(
+        Transform3::orthographic(1., 1., -1., 1., 0.5, 4.5),
+        Err(SlalError::InvalidProjection(_))
+    ));
+}
+
+#[test]
+fn matrix_round_trip() {
+    let t =
+        Transform3::perspective(1., 1.5, 0.1, 100.).unwrap() * Transform3::translation(1., 2., 3.);
+
+    assert_eq!(Transform3::from_matrix(&t.matrix()).unwrap(), t);
+    assert!(matches!(
+        Transform3::from_matrix(&Matrix::rotation_x(1.)),
+        Err(SlalError::UnmatchingMatrixSize(_, _))
+    ));
+}
@@ -1,50 +1,47 @@
-macro_rules! impl_mul_scala {
-    ($t:ty) => {
-        impl std::ops::Mul<$t> for super::Vertex<$t> {
-            type Output = Self;
-
-            fn mul(self, other: $t) -> super::Vertex<$t> {
-                use rayon::prelude::*;
-
-                let rv_vec: Vec<$t> = (0..self.len())
-                    .into_par_iter()
-                    .map(|i| self[i] * other)
-                    .collect();
-
-                Self::Output {
-                    v: rv_vec,
-                    vertical: self.vertical,
-                }
-            }
+impl<T> std::ops::Mul<T> for super::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = Self;
+
+    fn mul(self, other: T) -> super::Vertex<T> {
+        let rv_vec: Vec<T> = crate::parallel::map_collect(self.len(), |i| self[i] * other);
+
+        Self::Output {
+            v: rv_vec,
+            vertical: self.vertical,
         }
+    }
+}
 
-        impl crate::linear::Dot<$t> for super::Vertex<$t> {
-            type Output = super::Vertex<$t>;
+impl<T> crate::linear::Dot<T> for super::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Vertex<T>;
 
-            fn dot(&self, other: &$t) -> <Self as crate::linear::Dot<$t>>::Output {
-                self.clone() * *other
-            }
-        }
-    };
+    fn dot(&self, other: &T) -> <Self as crate::linear::Dot<T>>::Output {
+        self.clone() * *other
+    }
 }
 
-impl_mul_scala! { i8 }
-impl_mul_scala! { u8 }
-impl_mul_scala! { i16 }
-impl_mul_scala! { u16 }
-impl_mul_scala! { i32 }
-impl_mul_scala! { u32 }
-impl_mul_scala! { i64 }
-impl_mul_scala! { u64 }
-impl_mul_scala! { i128 }
-impl_mul_scala! { u128 }
-impl_mul_scala! { isize }
-impl_mul_scala! { usize }
-impl_mul_scala! { f32 }
-impl_mul_scala! { f64 }
+impl<T> crate::linear::Dot<super::Vertex<T>> for T
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Vertex<T>;
+
+    fn dot(
+        &self,
+        other: &super::Vertex<T>,
+    ) -> <Self as crate::linear::Dot<super::Vertex<T>>>::Output {
+        other.dot(self)
+    }
+}
 
+// `impl<T> Mul<Vertex<T>> for T` is rejected by the orphan rule, so this stays per primitive type
 macro_rules! impl_mul_with_scala {
-    ($t:ty) => {
+    ($($t:ty)*) => ($(
         impl std::ops::Mul<super::Vertex<$t>> for $t {
             type Output = super::Vertex<$t>;
 
@@ -52,31 +49,7 @@ macro_rules! impl_mul_with_scala {
                 other * self
             }
         }
-
-        impl crate::linear::Dot<super::Vertex<$t>> for $t {
-            type Output = super::Vertex<$t>;
-
-            fn dot(
-                &self,
-                other: &super::Vertex<$t>,
-            ) -> <Self as crate::linear::Dot<super::Vertex<$t>>>::Output {
-                other.dot(self)
-            }
-        }
-    };
+    )*)
 }
 
-impl_mul_with_scala! { i8 }
-impl_mul_with_scala! { u8 }
-impl_mul_with_scala! { i16 }
-impl_mul_with_scala! { u16 }
-impl_mul_with_scala! { i32 }
-impl_mul_with_scala! { u32 }
-impl_mul_with_scala! { i64 }
-impl_mul_with_scala! { u64 }
-impl_mul_with_scala! { i128 }
-impl_mul_with_scala! { u128 }
-impl_mul_with_scala! { isize }
-impl_mul_with_scala! { usize }
-impl_mul_with_scala! { f32 }
-impl_mul_with_scala! { f64 }
+impl_mul_with_scala! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
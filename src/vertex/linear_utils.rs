@@ -1,35 +1,71 @@
-macro_rules! impl_magnitude_vertex {
-    ($t:ty) => {
-        impl crate::linear::Magnitude for super::Vertex<$t> {
-            type Output = f64;
+impl<T> crate::linear::Magnitude for super::Vertex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn magnitude(&self) -> Self::Output {
+        crate::parallel::map_sum(self.len(), |idx| (self[idx] * self[idx]).to_f64()).sqrt()
+    }
+}
 
-            fn magnitude(&self) -> Self::Output {
-                use rayon::prelude::*;
+impl<T> crate::linear::Norm<T> for super::Vertex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = f64;
+
+    fn norm_1(&self) -> Self::Output {
+        crate::parallel::map_sum(self.len(), |idx| self.v[idx].to_f64().abs())
+    }
+
+    fn norm_2(&self) -> Self::Output {
+        crate::parallel::map_sum(self.len(), |idx| self.v[idx].to_f64().powi(2)).sqrt()
+    }
+
+    fn norm_inf(&self) -> Self::Output {
+        crate::parallel::map_reduce(
+            self.len(),
+            |idx| self.v[idx].to_f64().abs(),
+            || 0.,
+            f64::max,
+        )
+    }
+
+    fn norm_fro(&self) -> Self::Output {
+        self.norm_2()
+    }
+
+    fn norm_max(&self) -> Self::Output {
+        self.norm_inf()
+    }
+}
 
-                (0..self.len())
-                    .into_par_iter()
-                    .map(|idx| (self[idx] * self[idx]) as f64)
-                    .sum::<f64>()
-                    .sqrt()
-            }
+impl<T> crate::linear::PNorm for super::Vertex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = crate::error::SlalErr<f64, T>;
+
+    fn norm_p(&self, p: f64) -> Self::Output {
+        use crate::error::SlalError;
+        use crate::linear::Norm;
+
+        // Below 1 the triangle inequality does not hold, so the result is not a norm
+        if p.is_nan() || p < 1. {
+            return Err(SlalError::InvalidNormOrder(format!("{}", p)));
+        }
+
+        if p == f64::INFINITY {
+            return Ok(self.norm_inf());
         }
-    };
-}
 
-impl_magnitude_vertex! { i8 }
-impl_magnitude_vertex! { u8 }
-impl_magnitude_vertex! { i16 }
-impl_magnitude_vertex! { u16 }
-impl_magnitude_vertex! { i32 }
-impl_magnitude_vertex! { u32 }
-impl_magnitude_vertex! { i64 }
-impl_magnitude_vertex! { u64 }
-impl_magnitude_vertex! { i128 }
-impl_magnitude_vertex! { u128 }
-impl_magnitude_vertex! { isize }
-impl_magnitude_vertex! { usize }
-impl_magnitude_vertex! { f32 }
-impl_magnitude_vertex! { f64 }
+        Ok(
+            crate::parallel::map_sum(self.len(), |idx| self.v[idx].to_f64().abs().powf(p))
+                .powf(1. / p),
+        )
+    }
+}
 
 macro_rules! impl_random_signed {
     ($t:ty) => {
@@ -39,12 +75,11 @@ macro_rules! impl_random_signed {
 
             fn rand(size: Self::Size) -> Self::Output {
                 use rand::{self, Rng};
-                use rayon::prelude::*;
 
                 const DELTA: f64 = 1e-6;
 
                 let mut v = vec![0 as $t; size];
-                v.par_iter_mut().for_each(|v_i| {
+                crate::parallel::for_each_mut(&mut v, |_, v_i| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -66,12 +101,11 @@ macro_rules! impl_random_signed {
 
             fn rand_transposed(size: Self::Size) -> Self::Output {
                 use rand::{self, Rng};
-                use rayon::prelude::*;
 
                 const DELTA: f64 = 1e-6;
 
                 let mut v = vec![0 as $t; size];
-                v.par_iter_mut().for_each(|v_i| {
+                crate::parallel::for_each_mut(&mut v, |_, v_i| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -111,10 +145,9 @@ macro_rules! impl_random_unsigned {
 
             fn rand(size: Self::Size) -> Self::Output {
                 use rand::{self, Rng};
-                use rayon::prelude::*;
 
                 let mut v = vec![0 as $t; size];
-                v.par_iter_mut().for_each(|v_i| {
+                crate::parallel::for_each_mut(&mut v, |_, v_i| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -131,10 +164,9 @@ macro_rules! impl_random_unsigned {
 
             fn rand_transposed(size: Self::Size) -> Self::Output {
                 use rand::{self, Rng};
-                use rayon::prelude::*;
 
                 let mut v = vec![0 as $t; size];
-                v.par_iter_mut().for_each(|v_i| {
+                crate::parallel::for_each_mut(&mut v, |_, v_i| {
                     let mut thread_rng = rand::thread_rng();
 
                     loop {
@@ -159,59 +191,35 @@ impl_random_unsigned! { u64 }
 impl_random_unsigned! { u128 }
 impl_random_unsigned! { usize }
 
-macro_rules! impl_normalize {
-    ($t:ty) => {
-        impl crate::linear::Normalize for super::Vertex<$t> {
-            type Output = super::Vertex<f64>;
-
-            fn norm(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                let norm_scala = self
-                    .v
-                    .par_iter()
-                    .map(|v_i| (*v_i as f64).powi(2))
-                    .sum::<f64>()
-                    .sqrt();
-
-                let mut v = vec![0.; self.len()];
-                v.par_iter_mut().enumerate().for_each(|(idx, v_i)| {
-                    *v_i = self[idx] as f64 / norm_scala;
-                });
+impl<T> crate::linear::Normalize for super::Vertex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    type Output = super::Vertex<f64>;
 
-                Self::Output {
-                    v,
-                    vertical: self.vertical,
-                }
-            }
+    fn norm(&self) -> Self::Output {
+        let norm_scala =
+            crate::parallel::map_sum(self.len(), |idx| self.v[idx].to_f64().powi(2)).sqrt();
+
+        let mut v = vec![0.; self.len()];
+        crate::parallel::for_each_mut(&mut v, |idx, v_i| {
+            *v_i = self[idx].to_f64() / norm_scala;
+        });
+
+        Self::Output {
+            v,
+            vertical: self.vertical,
         }
-    };
+    }
 }
 
-impl_normalize! { i8 }
-impl_normalize! { u8 }
-impl_normalize! { i16 }
-impl_normalize! { u16 }
-impl_normalize! { i32 }
-impl_normalize! { u32 }
-impl_normalize! { i64 }
-impl_normalize! { u64 }
-impl_normalize! { i128 }
-impl_normalize! { u128 }
-impl_normalize! { isize }
-impl_normalize! { usize }
-impl_normalize! { f32 }
-impl_normalize! { f64 }
-
 macro_rules! impl_inner_prod_float {
     ($t:ty) => {
         impl crate::linear::InnerProduct for super::Vertex<$t> {
             type Output = f64;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| (*v_i as f64).powi(2)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| (self.v[idx] as f64).powi(2))
             }
         }
     };
@@ -226,9 +234,7 @@ macro_rules! impl_inner_prod_i32_or_smaller {
             type Output = i32;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| (*v_i as i32).pow(2)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| (self.v[idx] as i32).pow(2))
             }
         }
     };
@@ -244,9 +250,7 @@ macro_rules! impl_inner_prod_u32_or_smaller {
             type Output = u32;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| (*v_i as u32)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| self.v[idx] as u32)
             }
         }
     };
@@ -262,9 +266,7 @@ macro_rules! impl_inner_prod_large_signed_int {
             type Output = i128;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| (*v_i as i128).pow(2)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| (self.v[idx] as i128).pow(2))
             }
         }
     };
@@ -279,9 +281,7 @@ macro_rules! impl_inner_prod_large_unsigned_int {
             type Output = u128;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| (*v_i as u128).pow(2)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| (self.v[idx] as u128).pow(2))
             }
         }
     };
@@ -296,9 +296,7 @@ macro_rules! impl_inner_prod_size {
             type Output = $t;
 
             fn inner(&self) -> Self::Output {
-                use rayon::prelude::*;
-
-                self.v.par_iter().map(|v_i| v_i.pow(2)).sum()
+                crate::parallel::map_sum(self.v.len(), |idx| self.v[idx].pow(2))
             }
         }
     };
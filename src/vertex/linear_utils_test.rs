@@ -1,5 +1,6 @@
 use super::Vertex;
-use crate::linear::{InnerProduct, Magnitude, Normalize};
+use crate::error::{SlalErr, SlalError};
+use crate::linear::{InnerProduct, Magnitude, Norm, Normalize, PNorm};
 use crate::utils::Round;
 
 #[test]
@@ -9,6 +10,50 @@ fn magnitude() {
     assert!(v.magnitude() == f64::from(1.0 + 4.0 + 9.0).sqrt())
 }
 
+#[test]
+fn norms() {
+    let v = Vertex::<i32>::new(&[1, -2, 2]);
+
+    assert!(v.norm_1() == 5.);
+    assert!(v.norm_2() == 3.);
+    assert!(v.norm_inf() == 2.);
+    assert!(v.norm_fro() == v.norm_2());
+    assert!(v.norm_max() == v.norm_inf());
+}
+
+#[test]
+fn norm_p() -> SlalErr<(), f64> {
+    let v = Vertex::<f64>::new(&[3., -4.]);
+
+    assert!(v.norm_p(1.)? == 7.);
+    assert!((v.norm_p(2.)? - 5.).abs() < 1e-10);
+    assert!((v.norm_p(3.)? - 91f64.powf(1. / 3.)).abs() < 1e-10);
+    assert!(v.norm_p(f64::INFINITY)? == 4.);
+
+    Ok(())
+}
+
+#[test]
+fn norm_p_invalid_order() {
+    let v = Vertex::<f64>::new(&[3., -4.]);
+
+    assert_eq!(
+        v.norm_p(0.5),
+        Err(SlalError::InvalidNormOrder(String::from("0.5")))
+    );
+    assert!(v.norm_p(0.).is_err());
+    assert!(v.norm_p(-1.).is_err());
+    assert!(v.norm_p(f64::NAN).is_err());
+}
+
+#[test]
+fn norm_empty() {
+    let v = Vertex::<u8>::new(&[]);
+
+    assert!(v.norm_1() == 0.);
+    assert!(v.norm_inf() == 0.);
+}
+
 #[test]
 fn round() {
     let mut v = Vertex::<f32> {
@@ -1,61 +1,43 @@
-macro_rules! impl_add {
-    ($($t:ty)*) => ($(
-        impl std::ops::Add for super::Vertex<$t> {
-            type Output = super::Vertex<$t>;
-
-            fn add(self, other: super::Vertex<$t>) -> super::Vertex<$t> {
-                use super::Vertex;
-                use rayon::prelude::*;
-
-                if self.len() != other.len()
-                    || self.is_transposed() != other.is_transposed() {
-                    panic!("Cannot add {:?} and {:?}.", self, other);
-
-                }
-
-                let retval = (0..self.len())
-                    .into_par_iter()
-                    .map(|idx| self[idx] + other[idx])
-                    .collect();
-
-                Vertex::<$t> {
-                    v: retval,
-                    vertical: self.vertical,
-                }
-            }
+impl<T> std::ops::Add for super::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Vertex<T>;
+
+    fn add(self, other: super::Vertex<T>) -> super::Vertex<T> {
+        use super::Vertex;
+
+        if self.len() != other.len() || self.is_transposed() != other.is_transposed() {
+            panic!("Cannot add {:?} and {:?}.", self, other);
         }
-    )*)
-}
 
-impl_add! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
-
-macro_rules! impl_sub {
-    ($($t:ty)*) => ($(
-        impl std::ops::Sub for super::Vertex<$t>
-        {
-            type Output = super::Vertex<$t>;
-
-            fn sub(self, other: super::Vertex<$t>) -> Self {
-                use super::Vertex;
-                use rayon::prelude::*;
-
-                if self.len() != other.len()
-                    || self.is_transposed() != other.is_transposed() {
-                    panic!("Cannot substract {:?} from, {:?}.", other, self);
-                }
-
-                let retval: Vec<$t> = (0..self.len())
-                    .into_par_iter()
-                    .map(|idx| self[idx] - other[idx])
-                    .collect();
-
-                Vertex::<$t> {
-                    v: retval,
-                    vertical: self.vertical,
-                }
-            }
+        let retval = crate::parallel::map_collect(self.len(), |idx| self[idx] + other[idx]);
+
+        Vertex::<T> {
+            v: retval,
+            vertical: self.vertical,
         }
-    )*)
+    }
 }
 
-impl_sub! { i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64 }
+impl<T> std::ops::Sub for super::Vertex<T>
+where
+    T: crate::scalar::Scalar,
+{
+    type Output = super::Vertex<T>;
+
+    fn sub(self, other: super::Vertex<T>) -> Self {
+        use super::Vertex;
+
+        if self.len() != other.len() || self.is_transposed() != other.is_transposed() {
+            panic!("Cannot substract {:?} from, {:?}.", other, self);
+        }
+
+        let retval: Vec<T> = crate::parallel::map_collect(self.len(), |idx| self[idx] - other[idx]);
+
+        Vertex::<T> {
+            v: retval,
+            vertical: self.vertical,
+        }
+    }
+}
@@ -169,6 +169,30 @@ where
     }
 }
 
+impl<T> Vertex<T>
+where
+    T: crate::scalar::RealScalar,
+{
+    /**
+    Converts every element of the vertex to f64
+
+    # Examples
+    ```
+    use slal::vertex::Vertex;
+
+    let v = Vertex::<u64>::new(&[1, 2, 3]);
+
+    assert!(v.to_f64() == Vertex::new(&[1., 2., 3.]));
+    ```
+     */
+    pub fn to_f64(&self) -> Vertex<f64> {
+        Vertex {
+            v: crate::parallel::map_collect(self.v.len(), |idx| self.v[idx].to_f64()),
+            vertical: self.vertical,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::Vertex;
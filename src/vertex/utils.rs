@@ -1,58 +1,46 @@
-macro_rules! vertex_index {
-    ($($t:ty)*) => ($(
-        impl std::ops::Index<usize> for super::Vertex<$t> {
-            type Output = $t;
-
-            fn index(&self, index: usize) -> &Self::Output {
-                &self.v[index]
-            }
-        }
-
-        impl std::ops::IndexMut<usize> for super::Vertex<$t> {
-            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                &mut self.v[index]
-            }
-        }
-
-        impl std::ops::Index<usize> for &super::Vertex<$t> {
-            type Output = $t;
-
-            fn index(&self, index: usize) -> &Self::Output {
-                &self.v[index]
-            }
-        }
-
-        impl std::ops::Index<usize> for &mut super::Vertex<$t> {
-            type Output = $t;
-
-            fn index(&self, index: usize) -> &Self::Output {
-                &self.v[index]
-            }
-        }
-
-        impl std::ops::IndexMut<usize> for &mut super::Vertex<$t> {
-            fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-                &mut self.v[index]
-            }
-        }
-    )*)
+impl<T> std::ops::Index<usize> for super::Vertex<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.v[index]
+    }
+}
+
+impl<T> std::ops::IndexMut<usize> for super::Vertex<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.v[index]
+    }
+}
+
+impl<T> std::ops::Index<usize> for &super::Vertex<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.v[index]
+    }
 }
 
-vertex_index! {i8 u8 i16 u16 i32 u32 i64 u64 i128 u128 isize usize f32 f64}
+impl<T> std::ops::Index<usize> for &mut super::Vertex<T> {
+    type Output = T;
 
-macro_rules! impl_round {
-    ($t:ty) => {
-        impl crate::utils::Round for super::Vertex<$t> {
-            fn round(&mut self) {
-                use rayon::prelude::*;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.v[index]
+    }
+}
 
-                self.v.par_iter_mut().for_each(|val| {
-                    *val = val.round();
-                });
-            }
-        }
-    };
+impl<T> std::ops::IndexMut<usize> for &mut super::Vertex<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.v[index]
+    }
 }
 
-impl_round! { f32 }
-impl_round! { f64 }
+impl<T> crate::utils::Round for super::Vertex<T>
+where
+    T: crate::scalar::RealField,
+{
+    fn round(&mut self) {
+        crate::parallel::for_each_mut(&mut self.v, |_, val| {
+            *val = val.round();
+        });
+    }
+}
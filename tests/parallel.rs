@@ -0,0 +1,47 @@
+// Changes the process-wide threshold and thread pool, so it runs in its own test binary
+// and never races with the unit tests that read those settings
+use slal::linear::{Cofactor, Norm, Power};
+use slal::matrix::Matrix;
+use slal::parallel;
+use slal::vertex::Vertex;
+
+#[test]
+fn serial_and_parallel_match() {
+    let v = Vertex::<i64>::new(&(0..10_000).map(|i| i % 97 - 48).collect::<Vec<i64>>());
+    let m =
+        Matrix::<i64>::new(&[&[1, 1, 0, 2], &[1, 0, 3, 1], &[0, 2, 1, 0], &[1, 0, 0, 1]]).unwrap();
+    let run = || {
+        (
+            v.clone() * 3,
+            v.norm_1(),
+            v.norm_inf(),
+            m.pow(7).unwrap(),
+            m.cofactor().unwrap(),
+        )
+    };
+
+    parallel::set_threshold(usize::MAX);
+    let serial = run();
+
+    parallel::set_threshold(0);
+    assert!(run() == serial);
+
+    #[cfg(feature = "parallel")]
+    {
+        let pool = std::sync::Arc::new(
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(2)
+                .build()
+                .unwrap(),
+        );
+
+        parallel::set_thread_pool(Some(pool.clone()));
+        assert!(run() == serial);
+        assert!(pool.install(run) == serial);
+        parallel::set_thread_pool(None);
+    }
+
+    parallel::set_threshold(parallel::DEFAULT_THRESHOLD);
+    assert!(parallel::threshold() == parallel::DEFAULT_THRESHOLD);
+    assert!(run() == serial);
+}